@@ -0,0 +1,92 @@
+use criterion::black_box;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::Criterion;
+
+use ris_data::ecs::decl::GameObjectHandle;
+use ris_data::ecs::registry::Registry;
+use ris_data::ecs::scene::Scene;
+use ris_data::ecs::scene::SceneCreateInfo;
+use ris_math::quaternion::Quat;
+use ris_math::vector::Vec3;
+
+const GAME_OBJECTS: usize = 10_000;
+const CHILDREN_PER_GAME_OBJECT: usize = 4;
+
+fn world_transform(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!("world_transform_{}", GAME_OBJECTS));
+
+    let (scene, game_objects) = create_hierarchy();
+    let root = game_objects[0];
+
+    group.bench_function("unchanged", |b| {
+        b.iter(|| {
+            for game_object in game_objects.iter() {
+                let model = game_object.model(&scene);
+                let _ = black_box(model);
+            }
+        })
+    });
+
+    let mut i = 0;
+    group.bench_function("root_changed", |b| {
+        b.iter(|| {
+            i += 1;
+            let position = Vec3(i as f32, 0.0, 0.0);
+            root.set_local_position(&scene, position).unwrap();
+
+            for game_object in game_objects.iter() {
+                let model = game_object.model(&scene);
+                let _ = black_box(model);
+            }
+        })
+    });
+
+    let leaf = game_objects[GAME_OBJECTS - 1];
+    group.bench_function("leaf_changed", |b| {
+        b.iter(|| {
+            i += 1;
+            let position = Vec3(i as f32, 0.0, 0.0);
+            leaf.set_local_position(&scene, position).unwrap();
+
+            for game_object in game_objects.iter() {
+                let model = game_object.model(&scene);
+                let _ = black_box(model);
+            }
+        })
+    });
+
+    group.finish();
+}
+
+fn create_hierarchy() -> (Scene, Vec<GameObjectHandle>) {
+    let mut info = SceneCreateInfo::empty();
+    info.dynamic_game_objects = GAME_OBJECTS;
+    info.registry = Some(Registry::new(Vec::new()).unwrap());
+    let scene = Scene::new(info).unwrap();
+
+    let mut game_objects = Vec::with_capacity(GAME_OBJECTS);
+    for i in 0..GAME_OBJECTS {
+        let game_object = GameObjectHandle::new(&scene).unwrap();
+        game_object
+            .set_local_position(&scene, Vec3(1.0, 0.0, 0.0))
+            .unwrap();
+        game_object
+            .set_local_rotation(&scene, Quat::angle_axis(0.1, Vec3::up()))
+            .unwrap();
+
+        if i > 0 {
+            let parent = game_objects[(i - 1) / CHILDREN_PER_GAME_OBJECT];
+            game_object
+                .set_parent(&scene, Some(parent), usize::MAX, false)
+                .unwrap();
+        }
+
+        game_objects.push(game_object);
+    }
+
+    (scene, game_objects)
+}
+
+criterion_group!(benches, world_transform);
+criterion_main!(benches);
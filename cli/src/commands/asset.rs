@@ -2,7 +2,9 @@ use std::path::PathBuf;
 
 use ris_asset::asset_compiler;
 use ris_asset::asset_compiler::CompileOptions;
+use ris_asset::asset_graph::AssetGraph;
 use ris_asset::asset_importer;
+use ris_asset::asset_verifier;
 use ris_core::log_appenders::console_appender::ConsoleAppender;
 use ris_error::RisResult;
 use ris_log::log::IAppender;
@@ -15,12 +17,16 @@ const LOG_LEVEL: LogLevel = LogLevel::Trace;
 
 pub const COMPILE: &str = "compile";
 pub const DECOMPILE: &str = "decompile";
+pub const DEPS: &str = "deps";
 pub const IMPORT: &str = "import";
+pub const VERIFY: &str = "verify";
 
 pub enum AssetCommand {
     Compile,
     Decompile,
+    Deps,
     Import,
+    Verify,
 }
 
 pub struct Asset;
@@ -32,7 +38,9 @@ impl ICommand for Asset {
 
     fn explanation(level: ExplanationLevel) -> String {
         match level {
-            ExplanationLevel::Short => String::from("Compile, decompile or import assets."),
+            ExplanationLevel::Short => {
+                String::from("Compile, decompile, import, verify or list dependencies of assets.")
+            }
             ExplanationLevel::Detailed => {
                 let mut explanation = String::new();
                 let short_explanation = Self::explanation(ExplanationLevel::Short);
@@ -64,6 +72,16 @@ impl ICommand for Asset {
                     asset_compiler::DEFAULT_DECOMPILED_DIRECTORY
                 ));
                 explanation.push('\n');
+                explanation.push_str(&format!("{}\n", DEPS));
+                explanation.push_str(
+                    "Prints which files in the directory <source> depend on which. <target> may be omitted.\n",
+                );
+                explanation.push_str(&format!(
+                    "default source: {} and {}\n",
+                    asset_importer::DEFAULT_SOURCE_DIRECTORY,
+                    asset_compiler::DEFAULT_ASSET_DIRECTORY,
+                ));
+                explanation.push('\n');
                 explanation.push_str(&format!("{}\n", IMPORT));
                 explanation.push_str("Recursively imports ALL files in directory <source> into the directory <target>.\n");
                 explanation.push_str(&format!(
@@ -74,6 +92,14 @@ impl ICommand for Asset {
                     "default target: {}\n",
                     asset_importer::DEFAULT_IMPORT_DIRECTORY
                 ));
+                explanation.push('\n');
+                explanation.push_str(&format!("{}\n", VERIFY));
+                explanation.push_str("Verifies that all assets in <source> can be decoded, and that all references resolve. <source> may be an asset directory or a compiled asset file. Fails if any problem is found, except for assets which are not reachable from the god asset. <target> may be omitted.\n");
+                explanation.push_str(&format!(
+                    "default source: {} and {}, if it exists\n",
+                    asset_compiler::DEFAULT_ASSET_DIRECTORY,
+                    asset_compiler::DEFAULT_COMPILED_FILE,
+                ));
                 explanation
             }
         }
@@ -84,6 +110,9 @@ impl ICommand for Asset {
             0 | 1 => Err(String::from("too few args")),
             2 => Err(String::from("no args provided")),
             3 => Ok((&args[2], None)),
+            4 if [DEPS, VERIFY].contains(&args[2].to_lowercase().as_str()) => {
+                Ok((&args[2], Some((args[3].as_str(), ""))))
+            }
             4 => Err(String::from("no target provided")),
             5 => Ok((&args[2], Some((args[3].as_str(), args[4].as_str())))),
             _ => Err(String::from("too many args")),
@@ -94,7 +123,9 @@ impl ICommand for Asset {
                 let asset_command = match command.to_lowercase().as_str() {
                     COMPILE => AssetCommand::Compile,
                     DECOMPILE => AssetCommand::Decompile,
+                    DEPS => AssetCommand::Deps,
                     IMPORT => AssetCommand::Import,
+                    VERIFY => AssetCommand::Verify,
                     command => {
                         return crate::util::command_error(
                             &format!("unkown command: {}", command),
@@ -151,6 +182,13 @@ impl Asset {
                     asset_compiler::DEFAULT_DECOMPILED_DIRECTORY,
                 ),
             },
+            AssetCommand::Deps => match source_target {
+                Some((source, _)) => print_deps(source),
+                None => {
+                    print_deps(asset_importer::DEFAULT_SOURCE_DIRECTORY)?;
+                    print_deps(asset_compiler::DEFAULT_ASSET_DIRECTORY)
+                }
+            },
             AssetCommand::Import => match source_target {
                 Some((source, target)) => asset_importer::import_all(
                     source,
@@ -165,6 +203,76 @@ impl Asset {
                     None,
                 ),
             },
+            AssetCommand::Verify => {
+                let sources = match source_target {
+                    Some((source, _)) => vec![source],
+                    None => {
+                        let mut sources = vec![asset_compiler::DEFAULT_ASSET_DIRECTORY];
+                        if PathBuf::from(asset_compiler::DEFAULT_COMPILED_FILE).exists() {
+                            sources.push(asset_compiler::DEFAULT_COMPILED_FILE);
+                        }
+                        sources
+                    }
+                };
+
+                let mut error_count = 0;
+                for source in sources {
+                    error_count += print_verify(source)?;
+                }
+
+                if error_count > 0 {
+                    return ris_error::new_result!(
+                        "verification failed with {} errors",
+                        error_count
+                    );
+                }
+
+                Ok(())
+            }
         }
     }
 }
+
+fn print_deps(directory: &str) -> RisResult<()> {
+    let graph = AssetGraph::scan(&PathBuf::from(directory))?;
+
+    println!("{}:", directory);
+    for (id, node) in graph.nodes.iter() {
+        let dependents = graph.dependents(id);
+        if node.dependencies.is_empty() && dependents.is_empty() {
+            continue;
+        }
+
+        println!("    {}", id);
+        for dependency in node.dependencies.iter() {
+            println!("        depends on {}", dependency);
+        }
+        for dependent in dependents {
+            println!("        required by {}", dependent);
+        }
+    }
+
+    Ok(())
+}
+
+fn print_verify(source: &str) -> RisResult<usize> {
+    let report = asset_verifier::verify(&PathBuf::from(source))?;
+
+    println!("{}:", source);
+    for problem in report.problems.iter() {
+        let severity = if problem.is_error() {
+            "error"
+        } else {
+            "warning"
+        };
+        println!("    {}: {}", severity, problem);
+    }
+    println!(
+        "    verified {} assets. {} errors, {} warnings",
+        report.asset_count,
+        report.error_count(),
+        report.warning_count(),
+    );
+
+    Ok(report.error_count())
+}
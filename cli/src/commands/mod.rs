@@ -6,3 +6,4 @@ pub mod god_asset;
 pub mod pipeline;
 pub mod profiler_html;
 pub mod repeat;
+pub mod scene;
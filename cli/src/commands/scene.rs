@@ -0,0 +1,73 @@
+use std::path::PathBuf;
+
+use ris_asset::assets::ris_scene;
+use ris_data::ecs::registry::Registry;
+use ris_error::RisResult;
+
+use crate::ExplanationLevel;
+use crate::ICommand;
+
+pub struct Scene;
+
+impl ICommand for Scene {
+    fn args() -> String {
+        String::from("<source> [<target>]")
+    }
+
+    fn explanation(level: ExplanationLevel) -> String {
+        match level {
+            ExplanationLevel::Short => {
+                String::from("Prints a ris_scene as a human readable tree of game objects.")
+            }
+            ExplanationLevel::Detailed => {
+                let mut explanation = String::new();
+                let short_explanation = Self::explanation(ExplanationLevel::Short);
+                explanation.push_str(&format!("{}\n", short_explanation));
+                explanation.push('\n');
+                explanation.push_str("Decompresses the ris_scene file <source> and prints its references, game objects, transforms and component types. If <target> is provided, the tree is written to that file instead. Useful to diff scenes in code review.\n");
+                explanation.push('\n');
+                explanation.push_str("Components and scripts are named by the builtin registry. Scripts of the game are printed by their id.\n");
+                explanation
+            }
+        }
+    }
+
+    fn run(args: Vec<String>, _target_dir: PathBuf) -> RisResult<()> {
+        let (source, target) = match args.len() {
+            0..=2 => {
+                return crate::util::command_error(
+                    "no source provided",
+                    "scene",
+                    Self::args(),
+                    Self::explanation(ExplanationLevel::Detailed),
+                )
+            }
+            3 => (PathBuf::from(&args[2]), None),
+            4 => (PathBuf::from(&args[2]), Some(PathBuf::from(&args[3]))),
+            _ => {
+                return crate::util::command_error(
+                    "too many args",
+                    "scene",
+                    Self::args(),
+                    Self::explanation(ExplanationLevel::Detailed),
+                )
+            }
+        };
+
+        eprintln!("reading... \"{}\"", source.display());
+        let bytes = std::fs::read(&source)?;
+
+        let registry = Registry::new(Vec::new())?;
+        let dump = ris_scene::dump(&bytes, &registry)?;
+
+        match target {
+            Some(target) => {
+                eprintln!("writing... \"{}\"", target.display());
+                std::fs::write(target, dump)?;
+            }
+            None => print!("{}", dump),
+        }
+
+        Ok(())
+    }
+}
@@ -20,6 +20,7 @@ pub use commands::god_asset::GodAsset;
 pub use commands::pipeline::Pipeline;
 pub use commands::profiler_html::ProfilerHtml;
 pub use commands::repeat::Repeat;
+pub use commands::scene::Scene;
 
 fn main() -> Result<(), String> {
     let start = std::time::SystemTime::now();
@@ -47,6 +48,7 @@ fn main() -> Result<(), String> {
         Pipeline,
         ProfilerHtml,
         Repeat,
+        Scene,
     );
 
     // check if no arguments provided
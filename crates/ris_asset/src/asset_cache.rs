@@ -0,0 +1,237 @@
+use std::any::Any;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use std::sync::Weak;
+
+use ris_data::asset_id::AssetId;
+use ris_data::ecs::mesh::Mesh;
+use ris_error::RisResult;
+use ris_jobs::job_future::JobFuture;
+use ris_jobs::job_system;
+
+use crate::asset_loader::LoadError;
+use crate::assets::ris_mesh;
+use crate::codecs::qoi;
+use crate::codecs::qoi::QoiDesc;
+
+pub type LoadBytes = Box<dyn Fn(AssetId) -> JobFuture<Result<Vec<u8>, LoadError>> + Send + Sync>;
+
+/// decodes the bytes of an asset. every asset type has its own loader, and the cache shares the
+/// decoded assets per loader and asset id.
+pub trait IAssetLoader: 'static {
+    type Asset: Send + Sync + 'static;
+
+    fn decode(bytes: Vec<u8>) -> RisResult<Self::Asset>;
+}
+
+enum SlotState<T> {
+    Loading(Option<JobFuture<Result<Vec<u8>, LoadError>>>),
+    Loaded(Arc<T>),
+    Failed(String),
+}
+
+struct AssetSlot<L: IAssetLoader> {
+    id: AssetId,
+    state: Mutex<SlotState<L::Asset>>,
+}
+
+/// a shared reference to an asset in an `AssetCache`. the decoded asset is kept alive as long as
+/// at least one handle to it exists.
+pub struct AssetHandle<L: IAssetLoader> {
+    slot: Arc<AssetSlot<L>>,
+}
+
+type Entries = HashMap<(TypeId, AssetId), Weak<dyn Any + Send + Sync>>;
+
+/// loads and decodes assets once, no matter how often they are requested
+pub struct AssetCache {
+    load_bytes: LoadBytes,
+    entries: Mutex<Entries>,
+}
+
+impl<L: IAssetLoader> Clone for AssetHandle<L> {
+    fn clone(&self) -> Self {
+        Self {
+            slot: self.slot.clone(),
+        }
+    }
+}
+
+impl<L: IAssetLoader> AssetHandle<L> {
+    pub fn id(&self) -> &AssetId {
+        &self.slot.id
+    }
+
+    /// returns `None` while the asset is still loading
+    pub fn try_get(&self) -> Option<RisResult<Arc<L::Asset>>> {
+        let mut state = job_system::lock(&self.slot.state);
+
+        if let SlotState::Loading(future) = &mut *state {
+            let ready = future.take().map(|x| x.try_take());
+            match ready {
+                Some(Ok(result)) => *state = decode::<L>(&self.slot.id, result),
+                Some(Err(future)) => {
+                    *state = SlotState::Loading(Some(future));
+                    return None;
+                }
+                None => return None,
+            }
+        }
+
+        Some(self.result(&state))
+    }
+
+    /// blocks until the asset is loaded
+    pub fn wait(&self) -> RisResult<Arc<L::Asset>> {
+        let mut state = job_system::lock(&self.slot.state);
+
+        if let SlotState::Loading(future) = &mut *state {
+            if let Some(future) = future.take() {
+                let result = future.wait(None)?;
+                *state = decode::<L>(&self.slot.id, result);
+            }
+        }
+
+        self.result(&state)
+    }
+
+    fn result(&self, state: &SlotState<L::Asset>) -> RisResult<Arc<L::Asset>> {
+        match state {
+            SlotState::Loading(_) => {
+                ris_error::new_result!("asset {:?} is still loading", self.slot.id)
+            }
+            SlotState::Loaded(asset) => Ok(asset.clone()),
+            SlotState::Failed(message) => {
+                ris_error::new_result!("failed to load {:?}: {}", self.slot.id, message)
+            }
+        }
+    }
+}
+
+impl AssetCache {
+    /// `load_bytes` requests the bytes of an asset. usually this is `asset_loader::load_async`
+    pub fn new(load_bytes: LoadBytes) -> Self {
+        Self {
+            load_bytes,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// returns a handle to the asset. if the asset is loaded or currently loading, the handle
+    /// shares it. otherwise a new load is started.
+    pub fn get<L: IAssetLoader>(&self, id: AssetId) -> AssetHandle<L> {
+        let key = (TypeId::of::<L>(), id.clone());
+        let mut entries = job_system::lock(&self.entries);
+
+        if let Some(slot) = entries.get(&key).and_then(|x| x.upgrade()) {
+            if let Ok(slot) = slot.downcast::<AssetSlot<L>>() {
+                return AssetHandle { slot };
+            }
+        }
+
+        // evict the assets of which all handles were dropped
+        entries.retain(|_, x| x.strong_count() > 0);
+
+        let future = (self.load_bytes)(id.clone());
+        let slot = Arc::new(AssetSlot::<L> {
+            id,
+            state: Mutex::new(SlotState::Loading(Some(future))),
+        });
+
+        let any_slot: Arc<dyn Any + Send + Sync> = slot.clone();
+        entries.insert(key, Arc::downgrade(&any_slot));
+
+        AssetHandle { slot }
+    }
+
+    /// forgets the asset, such that the next `get` loads it again. existing handles keep the old
+    /// asset. use this when an asset changed on disk.
+    pub fn invalidate(&self, id: &AssetId) {
+        let mut entries = job_system::lock(&self.entries);
+        entries.retain(|(_, key_id), _| key_id != id);
+    }
+
+    /// the number of assets that are referenced by at least one handle
+    pub fn len(&self) -> usize {
+        let entries = job_system::lock(&self.entries);
+        entries.values().filter(|x| x.strong_count() > 0).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// the cache used by the engine, which loads assets with `asset_loader::load_async`
+pub fn global() -> &'static AssetCache {
+    static CACHE: OnceLock<AssetCache> = OnceLock::new();
+    CACHE.get_or_init(|| AssetCache::new(Box::new(crate::asset_loader::load_async)))
+}
+
+fn decode<L: IAssetLoader>(
+    id: &AssetId,
+    result: Result<Vec<u8>, LoadError>,
+) -> SlotState<L::Asset> {
+    let decoded = match result {
+        Ok(bytes) => L::decode(bytes).map_err(|e| e.to_string()),
+        Err(e) => Err(e.to_string()),
+    };
+
+    match decoded {
+        Ok(asset) => SlotState::Loaded(Arc::new(asset)),
+        Err(message) => {
+            ris_log::error!("failed to load {:?}: {}", id, message);
+            SlotState::Failed(message)
+        }
+    }
+}
+
+// loaders
+
+/// the undecoded bytes, for example of spirv shaders
+pub struct RawLoader;
+
+/// decodes a qoi image
+pub struct ImageLoader;
+
+pub struct MeshLoader;
+
+/// pixels of a decoded image, always with an alpha channel
+pub struct Image {
+    pub desc: QoiDesc,
+    pub pixels_rgba: Vec<u8>,
+}
+
+impl IAssetLoader for RawLoader {
+    type Asset = Vec<u8>;
+
+    fn decode(bytes: Vec<u8>) -> RisResult<Self::Asset> {
+        Ok(bytes)
+    }
+}
+
+impl IAssetLoader for ImageLoader {
+    type Asset = Image;
+
+    fn decode(bytes: Vec<u8>) -> RisResult<Self::Asset> {
+        let (pixels, desc) = qoi::decode(&bytes, None)?;
+
+        let pixels_rgba = match desc.channels {
+            qoi::Channels::RGB => crate::util::add_alpha_channel(&pixels)?,
+            qoi::Channels::RGBA => pixels,
+        };
+
+        Ok(Image { desc, pixels_rgba })
+    }
+}
+
+impl IAssetLoader for MeshLoader {
+    type Asset = Mesh;
+
+    fn decode(bytes: Vec<u8>) -> RisResult<Self::Asset> {
+        ris_mesh::load(&bytes)
+    }
+}
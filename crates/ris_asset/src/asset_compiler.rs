@@ -10,23 +10,33 @@ use ris_error::Extensions;
 use ris_error::RisResult;
 use ris_io::FatPtr;
 
+use crate::asset_graph;
+use crate::asset_graph::AssetGraph;
 use crate::RisHeader;
 
 // # File Format
 //
 // encoding: little-endian
 //
-// - [u8; 16]: magic `ris_assets\0\0\0\0\0\0"`
+// - [u8; 16]: magic `ris_assets\0\0\0\0\0"` followed by the version
 // - FatPtr: p_original_asset_names
 // - u32: asset_lookup_count
 // - [u64; asset_lookup_count]: asset_lookup
-// - [u8; ?]: assets
+// - [entry; asset_lookup_count]: assets
+//   - version 0:
+//     - [u8; ?]: asset
+//   - version 1:
+//     - u8: compression
+//     - u32: crc32 of the stored asset
+//     - [u8; ?]: stored asset, compressed according to compression
 // - [u8; ?]: original names (utf8 encoded strings, seperated by `\0`)
 
 pub const MAGIC: [u8; 16] = [
-    0x72, 0x69, 0x73, 0x5F, 0x61, 0x73, 0x73, 0x65, 0x74, 0x73, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x72, 0x69, 0x73, 0x5F, 0x61, 0x73, 0x73, 0x65, 0x74, 0x73, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
 ];
 
+const ENTRY_HEADER_LEN: usize = 5;
+
 pub const DEFAULT_ASSET_DIRECTORY: &str = "assets/in_use";
 pub const DEFAULT_COMPILED_FILE: &str = "ris_assets";
 pub const DEFAULT_DECOMPILED_DIRECTORY: &str = "decompiled_assets";
@@ -36,11 +46,52 @@ pub struct CompileOptions {
     pub include_original_paths: bool,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    None,
+    Deflate,
+}
+
+impl Compression {
+    fn to_u8(self) -> u8 {
+        match self {
+            Self::None => 0,
+            Self::Deflate => 1,
+        }
+    }
+
+    fn from_u8(value: u8) -> RisResult<Self> {
+        match value {
+            0 => Ok(Self::None),
+            1 => Ok(Self::Deflate),
+            value => ris_error::new_result!("unknown compression {}", value),
+        }
+    }
+}
+
 /// compiles a directory to a ris_asset file
 /// - `source`: the directory to be compiled
 /// - `target`: the path to the final compiled file. if this file exists already, it will be
 /// overwritten
+///
+/// the hashes and references of the compiled assets are stored next to `target`. if no asset
+/// changed since the last compilation, the compilation is skipped. otherwise all assets are
+/// compiled, because the compiled ids of the references depend on all assets, such that adding
+/// or removing a single asset may change them.
 pub fn compile(source: &str, target: &str, options: CompileOptions) -> RisResult<()> {
+    let graph_path = PathBuf::from(format!("{}.{}", target, asset_graph::EXTENSION));
+    let graph = AssetGraph::scan(Path::new(source))?;
+    let previous_graph = AssetGraph::load(&graph_path)?;
+    if graph == previous_graph && is_compiled_with(target, options)? {
+        ris_log::info!("skip compilation, no asset changed since \"{}\"", target);
+        return Ok(());
+    }
+
+    // the graph is outdated until the compilation succeeds
+    if graph_path.exists() {
+        std::fs::remove_file(&graph_path)?;
+    }
+
     let mut assets = Vec::new();
     let mut asset_lookup_hashmap = HashMap::new();
     let mut directories = std::collections::VecDeque::new();
@@ -160,9 +211,10 @@ pub fn compile(source: &str, target: &str, options: CompileOptions) -> RisResult
         };
 
         // write to compiled file
+        let entry = encode_entry(&modified_file_content)?;
         let asset_addr = ris_io::seek(target_file, SeekFrom::Current(0))?;
         asset_lookup[i] = asset_addr;
-        ris_io::write(target_file, &modified_file_content)?;
+        ris_io::write(target_file, &entry)?;
     }
 
     // all assets are compiled, compile original paths
@@ -209,9 +261,29 @@ pub fn compile(source: &str, target: &str, options: CompileOptions) -> RisResult
         ris_io::write_u64(target_file, *asset_lookup_entry)?;
     }
 
+    graph.save(&graph_path)?;
+
     Ok(())
 }
 
+fn is_compiled_with(target: &str, options: CompileOptions) -> RisResult<bool> {
+    let target_path = Path::new(target);
+    if !target_path.exists() {
+        return Ok(false);
+    }
+
+    let mut file = File::open(target_path)?;
+    let mut magic = [0; 16];
+    ris_io::read(&mut file, &mut magic)?;
+    if magic != MAGIC {
+        return Ok(false);
+    }
+
+    let p_original_asset_names = ris_io::read_fat_ptr(&mut file)?;
+    let includes_original_paths = p_original_asset_names.len != 0;
+    Ok(includes_original_paths == options.include_original_paths)
+}
+
 /// decompiles a .ris_asset file to a directory.
 /// - `source`: the path to the compiled file
 /// - `target`: the path to the final directory. if this directory exists already, it will be
@@ -231,9 +303,7 @@ pub fn decompile(source: &str, target: &str) -> RisResult<()> {
     // read magic
     let mut magic = [0; 16];
     ris_io::read(source, &mut magic)?;
-    if !ris_util::testing::bytes_eq(&magic, &MAGIC) {
-        return ris_error::new_result!("expected magic to be {:?} but was {:?}", magic, MAGIC);
-    }
+    let version = read_version(magic)?;
 
     // get original paths addr
     let p_original_asset_names = ris_io::read_fat_ptr(source)?;
@@ -284,7 +354,8 @@ pub fn decompile(source: &str, target: &str) -> RisResult<()> {
         };
 
         let p_asset = FatPtr::begin_end(asset_begin, asset_end)?;
-        let file_content = ris_io::read_at(source, p_asset)?;
+        let entry = ris_io::read_at(source, p_asset)?;
+        let file_content = decode_entry(&entry, version)?;
 
         // reassign ids
         let modified_file_content = match RisHeader::load(&file_content)? {
@@ -356,3 +427,66 @@ pub fn decompile(source: &str, target: &str) -> RisResult<()> {
 
     Ok(())
 }
+
+/// returns the version of the archive, or an error if `magic` is not the magic of a supported
+/// archive
+pub fn read_version(magic: [u8; 16]) -> RisResult<u8> {
+    let version = magic[15];
+    if magic[..15] != MAGIC[..15] || version > MAGIC[15] {
+        return ris_error::new_result!("unknown magic value: {:?}", magic);
+    }
+
+    Ok(version)
+}
+
+/// deflates the asset, if this makes it smaller, and prepends the compression and a checksum
+pub fn encode_entry(asset: &[u8]) -> RisResult<Vec<u8>> {
+    let compressed =
+        miniz_oxide::deflate::compress_to_vec(asset, crate::assets::ris_scene::COMPRESSION_LEVEL);
+
+    let (compression, stored) = if compressed.len() < asset.len() {
+        (Compression::Deflate, compressed.as_slice())
+    } else {
+        (Compression::None, asset)
+    };
+
+    let mut stream = Cursor::new(Vec::with_capacity(ENTRY_HEADER_LEN + stored.len()));
+    let f = &mut stream;
+    ris_io::write_u8(f, compression.to_u8())?;
+    ris_io::write_uint(f, crate::util::crc32(stored) as usize)?;
+    ris_io::write(f, stored)?;
+
+    Ok(stream.into_inner())
+}
+
+/// verifies the checksum of an entry and returns the decompressed asset. fails if the entry is
+/// corrupt or truncated.
+pub fn decode_entry(entry: &[u8], version: u8) -> RisResult<Vec<u8>> {
+    if version == 0 {
+        return Ok(entry.to_vec());
+    }
+
+    if entry.len() < ENTRY_HEADER_LEN {
+        return ris_error::new_result!("entry is truncated. len: {}", entry.len());
+    }
+
+    let f = &mut Cursor::new(entry);
+    let compression = Compression::from_u8(ris_io::read_u8(f)?)?;
+    let checksum = ris_io::read_uint(f)? as u32;
+    let stored = &entry[ENTRY_HEADER_LEN..];
+
+    let actual_checksum = crate::util::crc32(stored);
+    if actual_checksum != checksum {
+        return ris_error::new_result!(
+            "checksum mismatch. expected {:#010x} but was {:#010x}",
+            checksum,
+            actual_checksum,
+        );
+    }
+
+    match compression {
+        Compression::None => Ok(stored.to_vec()),
+        Compression::Deflate => miniz_oxide::inflate::decompress_to_vec(stored)
+            .map_err(|e| ris_error::new!("failed to decompress: {:?}", e)),
+    }
+}
@@ -0,0 +1,288 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::Cursor;
+use std::io::SeekFrom;
+use std::path::Path;
+use std::path::PathBuf;
+
+use ris_data::asset_id::AssetId;
+use ris_error::Extensions;
+use ris_error::RisResult;
+
+use crate::RisHeader;
+
+// # File Format
+//
+// encoding: little-endian
+//
+// - [u8; 16]: magic `ris_asset_graph\0`
+// - u32: node_count
+// - [node; node_count]: nodes
+//   - sized String: id
+//   - u64: hash
+//   - u32: dependency_count
+//   - [sized String; dependency_count]: dependencies
+
+pub const MAGIC: [u8; 16] = [
+    0x72, 0x69, 0x73, 0x5F, 0x61, 0x73, 0x73, 0x65, 0x74, 0x5F, 0x67, 0x72, 0x61, 0x70, 0x68, 0x00,
+];
+
+pub const EXTENSION: &str = "ris_asset_graph";
+
+const GLSL_EXTENSION: &str = "glsl";
+const GLSL_HEADER: &str = "#ris_glsl header";
+const GLSL_INCLUDE: &str = "#include";
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AssetNode {
+    pub hash: u64,
+    /// ids of the assets this asset references. when one of them changes, this asset must be
+    /// processed again
+    pub dependencies: Vec<String>,
+}
+
+/// the content hashes of all files in a directory, and which files reference each other. ids are
+/// paths relative to the scanned directory, seperated by `/`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AssetGraph {
+    pub nodes: BTreeMap<String, AssetNode>,
+}
+
+impl AssetGraph {
+    /// hashes every file in `directory` and collects its references. references are the
+    /// `#include`s of glsl shaders and the directory ids in the `RisHeader` of ris assets.
+    pub fn scan(directory: &Path) -> RisResult<Self> {
+        let mut nodes = BTreeMap::new();
+        let mut directories = VecDeque::new();
+        directories.push_back(directory.to_path_buf());
+
+        while let Some(current) = directories.pop_front() {
+            let entries = std::fs::read_dir(&current)?;
+
+            for entry in entries {
+                let entry = entry?;
+                let metadata = entry.metadata()?;
+                let entry_path = entry.path();
+
+                if metadata.is_file() {
+                    let id = to_id(directory, &entry_path)?;
+                    let bytes = std::fs::read(&entry_path)?;
+                    let hash = hash(&bytes);
+                    let dependencies = find_dependencies(directory, &entry_path, &bytes)?;
+
+                    let node = AssetNode { hash, dependencies };
+                    nodes.insert(id, node);
+                } else if metadata.is_dir() {
+                    directories.push_back(entry_path);
+                } else {
+                    return ris_error::new_result!(
+                        "entry \"{}\" is neither a file nor a directory",
+                        entry_path.display(),
+                    );
+                }
+            }
+        }
+
+        Ok(Self { nodes })
+    }
+
+    /// loads a graph that was previously saved. returns an empty graph if the file does not
+    /// exist, such that everything counts as changed.
+    pub fn load(path: &Path) -> RisResult<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let mut file = File::open(path)?;
+        let f = &mut file;
+
+        let mut magic = [0; 16];
+        ris_io::read(f, &mut magic)?;
+        if magic != MAGIC {
+            return ris_error::new_result!("expected magic to be {:?} but was {:?}", MAGIC, magic);
+        }
+
+        let mut nodes = BTreeMap::new();
+        let node_count = ris_io::read_uint(f)?;
+        for _ in 0..node_count {
+            let id = ris_io::read_string(f)?;
+            let hash = ris_io::read_u64(f)?;
+            let dependency_count = ris_io::read_uint(f)?;
+            let mut dependencies = Vec::with_capacity(dependency_count);
+            for _ in 0..dependency_count {
+                let dependency = ris_io::read_string(f)?;
+                dependencies.push(dependency);
+            }
+
+            let node = AssetNode { hash, dependencies };
+            nodes.insert(id, node);
+        }
+
+        Ok(Self { nodes })
+    }
+
+    pub fn save(&self, path: &Path) -> RisResult<()> {
+        let mut stream = Cursor::new(Vec::new());
+        let f = &mut stream;
+
+        ris_io::write(f, &MAGIC)?;
+        ris_io::write_uint(f, self.nodes.len())?;
+        for (id, node) in self.nodes.iter() {
+            ris_io::write_string(f, id)?;
+            ris_io::write_u64(f, node.hash)?;
+            ris_io::write_uint(f, node.dependencies.len())?;
+            for dependency in node.dependencies.iter() {
+                ris_io::write_string(f, dependency)?;
+            }
+        }
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let mut file = File::create(path)?;
+        ris_io::seek(&mut file, SeekFrom::Start(0))?;
+        ris_io::write(&mut file, &stream.into_inner())?;
+
+        Ok(())
+    }
+
+    /// returns the ids of all assets that directly reference `id`
+    pub fn dependents(&self, id: &str) -> Vec<&str> {
+        self.nodes
+            .iter()
+            .filter(|(_, node)| node.dependencies.iter().any(|x| x == id))
+            .map(|(dependent, _)| dependent.as_str())
+            .collect()
+    }
+
+    /// returns the ids of all assets in this graph that changed since `previous`, including the
+    /// assets that directly or indirectly depend on a changed, created or deleted asset
+    pub fn changed(&self, previous: &AssetGraph) -> BTreeSet<String> {
+        let mut queue = VecDeque::new();
+
+        for (id, node) in self.nodes.iter() {
+            if previous.nodes.get(id) != Some(node) {
+                queue.push_back(id.clone());
+            }
+        }
+
+        for id in previous.nodes.keys() {
+            if !self.nodes.contains_key(id) {
+                queue.push_back(id.clone());
+            }
+        }
+
+        let mut visited = BTreeSet::new();
+        while let Some(id) = queue.pop_front() {
+            if !visited.insert(id.clone()) {
+                continue;
+            }
+
+            for dependent in self.dependents(&id) {
+                queue.push_back(dependent.to_string());
+            }
+        }
+
+        visited.retain(|x| self.nodes.contains_key(x));
+        visited
+    }
+}
+
+/// 64 bit fnv-1a
+pub fn hash(bytes: &[u8]) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS;
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+
+    hash
+}
+
+/// returns the path of `file` relative to `directory`, seperated by `/`
+pub fn to_id(directory: &Path, file: &Path) -> RisResult<String> {
+    let relative_path = file.strip_prefix(directory)?;
+    let id = relative_path.to_str().into_ris_error()?.replace('\\', "/");
+    Ok(id)
+}
+
+fn find_dependencies(directory: &Path, file: &Path, bytes: &[u8]) -> RisResult<Vec<String>> {
+    let is_glsl = file
+        .extension()
+        .and_then(|x| x.to_str())
+        .is_some_and(|x| x.eq_ignore_ascii_case(GLSL_EXTENSION));
+
+    if is_glsl {
+        return find_glsl_includes(directory, file, bytes);
+    }
+
+    // ris assets are at least as large as their magic
+    if bytes.len() < 16 {
+        return Ok(Vec::new());
+    }
+
+    let Some(ris_header) = RisHeader::load(bytes)? else {
+        return Ok(Vec::new());
+    };
+
+    let mut dependencies = Vec::new();
+    for reference in ris_header.references {
+        if let AssetId::Path(id) = reference {
+            if !dependencies.contains(&id) {
+                dependencies.push(id);
+            }
+        }
+    }
+
+    Ok(dependencies)
+}
+
+fn find_glsl_includes(directory: &Path, file: &Path, bytes: &[u8]) -> RisResult<Vec<String>> {
+    let Ok(source) = std::str::from_utf8(bytes) else {
+        return Ok(Vec::new());
+    };
+
+    // includes are resolved relative to the shader that is imported, even includes of headers.
+    // thus headers do not resolve their own includes, and shaders depend on all headers they
+    // include directly or indirectly
+    if source.lines().next() == Some(GLSL_HEADER) {
+        return Ok(Vec::new());
+    }
+
+    let root_dir = file.parent().into_ris_error()?;
+
+    let mut dependencies = Vec::new();
+    let mut to_scan = vec![source.to_string()];
+    while let Some(source) = to_scan.pop() {
+        for line in source.lines() {
+            let splits = line.split(' ').collect::<Vec<_>>();
+            if splits.len() != 2 || splits[0] != GLSL_INCLUDE {
+                continue;
+            }
+
+            let include_path = PathBuf::from(root_dir).join(splits[1]);
+            let Ok(id) = to_id(directory, &include_path) else {
+                continue;
+            };
+
+            if dependencies.contains(&id) {
+                continue;
+            }
+
+            dependencies.push(id);
+
+            // missing includes are still recorded as dependency, the importer reports them
+            if let Ok(include_source) = std::fs::read_to_string(&include_path) {
+                to_scan.push(include_source);
+            }
+        }
+    }
+
+    Ok(dependencies)
+}
@@ -7,6 +7,7 @@ use ris_data::ris_yaml::RisYaml;
 use ris_error::Extensions;
 use ris_error::RisResult;
 
+use crate::asset_graph::AssetGraph;
 use crate::importer::*;
 
 pub const DEFAULT_SOURCE_DIRECTORY: &str = "assets/source_files";
@@ -14,10 +15,13 @@ pub const DEFAULT_IMPORT_DIRECTORY: &str = "assets/imported";
 pub const DEFAULT_IN_USE_DIRECTORY: &str = "assets/in_use";
 pub const META_EXTENSION: &str = "ris_meta";
 pub const META_COPY_TO: &str = "copy_to";
+pub const GRAPH_FILE: &str = "import.ris_asset_graph";
 
 pub enum ImporterKind {
     GLSL,
     PNG,
+    OBJ,
+    GLTF,
 }
 
 pub struct SpecificImporterInfo {
@@ -36,6 +40,9 @@ pub enum ImporterInfo {
     DeduceFromFileName(DeduceImporterInfo),
 }
 
+/// imports all files in `source_directory`, which changed since the last import, or which
+/// reference a file that changed. the state of the last import is stored in `GRAPH_FILE` inside
+/// `import_directory`. delete it to import everything again.
 pub fn import_all(
     source_directory: &str,
     import_directory: &str,
@@ -51,8 +58,20 @@ pub fn import_all(
     //    std::fs::remove_dir_all(target_directory_path)?;
     //}
 
-    // import source files
+    // find changed source files
     let source_path = PathBuf::from(source_directory);
+    let graph_path = PathBuf::from(import_directory).join(GRAPH_FILE);
+    let graph = AssetGraph::scan(&source_path)?;
+    let previous_graph = AssetGraph::load(&graph_path)?;
+    let changed = graph.changed(&previous_graph);
+
+    ris_log::debug!(
+        "{} of {} source files changed",
+        changed.len(),
+        graph.nodes.len(),
+    );
+
+    // import source files
     directories.push_back(source_path);
 
     while let Some(current) = directories.pop_front() {
@@ -79,6 +98,11 @@ pub fn import_all(
                 let mut target_path_part = source_path.replace('\\', "/");
                 target_path_part.replace_range(0..source_directory.len(), "");
 
+                if !changed.contains(&target_path_part) {
+                    ris_log::trace!("skip unchanged \"{}\"", entry_path.display());
+                    continue;
+                }
+
                 let mut target_path = PathBuf::new();
                 target_path.push(target_directory.clone());
                 target_path.push(&target_path_part);
@@ -108,6 +132,8 @@ pub fn import_all(
         }
     }
 
+    graph.save(&graph_path)?;
+
     // copy imported files
     let import_path = PathBuf::from(import_directory);
     let in_use_path = PathBuf::from(in_use_directory);
@@ -234,6 +260,12 @@ fn import(info: ImporterInfo, temp_directory: Option<&Path>) -> RisResult<()> {
                     (ImporterKind::GLSL, glsl_to_spirv_importer::OUT_EXT)
                 }
                 png_to_qoi_importer::IN_EXT => (ImporterKind::PNG, png_to_qoi_importer::OUT_EXT),
+                obj_to_ris_mesh_importer::IN_EXT => {
+                    (ImporterKind::OBJ, obj_to_ris_mesh_importer::OUT_EXT)
+                }
+                gltf_to_ris_mesh_importer::IN_EXT => {
+                    (ImporterKind::GLTF, gltf_to_ris_mesh_importer::OUT_EXT)
+                }
                 // insert new inporter here...
                 _ => {
                     ris_log::debug!(
@@ -267,6 +299,8 @@ fn import(info: ImporterInfo, temp_directory: Option<&Path>) -> RisResult<()> {
             glsl_to_spirv_importer::import(source_path, target_paths, temp_directory)
         }
         ImporterKind::PNG => png_to_qoi_importer::import(source_path, target_paths),
+        ImporterKind::OBJ => obj_to_ris_mesh_importer::import(source_path, target_paths),
+        ImporterKind::GLTF => gltf_to_ris_mesh_importer::import(source_path, target_paths),
         // insert more importers here...
     }
 }
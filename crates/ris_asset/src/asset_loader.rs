@@ -32,6 +32,7 @@ pub enum LoadError {
     InvalidId,
     SendFailed,
     LoadFailed,
+    Corrupted,
 }
 
 impl std::error::Error for LoadError {
@@ -46,6 +47,7 @@ impl std::fmt::Display for LoadError {
             Self::InvalidId => write!(f, "the wrong id has been passed to the currently loaded loader"),
             Self::SendFailed => write!(f, "the request was not able to be send to the loading thread. this usually occurs when the loading doesn't exist"),
             Self::LoadFailed => write!(f, "asset could not be loaded. this may be because it doesn't exist, or because an io error occured when reading the file"),
+            Self::Corrupted => write!(f, "asset is corrupted. its checksum does not match or it could not be decompressed. the compiled asset file may be truncated or damaged"),
         }
     }
 }
@@ -145,10 +147,7 @@ fn load_asset_thread(receiver: Receiver<Request>, mut loader: InternalLoader) {
 
         let result = match &mut loader {
             InternalLoader::Compiled(loader) => match &request.id {
-                AssetId::Index(id) => loader.load(*id).map_err(|e| {
-                    ris_log::error!("failed loading {:?}: {}", id, e);
-                    LoadError::LoadFailed
-                }),
+                AssetId::Index(id) => loader.load(*id),
                 AssetId::Path(id) => {
                     ris_log::error!(
                         "invalid id. expected compiled but was directory. id: {:?}",
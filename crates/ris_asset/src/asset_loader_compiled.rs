@@ -5,8 +5,12 @@ use std::path::Path;
 use ris_error::RisResult;
 use ris_io::FatPtr;
 
+use crate::asset_compiler;
+use crate::asset_loader::LoadError;
+
 pub struct AssetLoaderCompiled {
     file: File,
+    version: u8,
     lookup: Vec<FatPtr>,
 }
 
@@ -20,12 +24,21 @@ impl AssetLoaderCompiled {
         let mut magic_bytes = [0u8; 16];
         ris_io::read(f, &mut magic_bytes)?;
 
-        if !ris_util::testing::bytes_eq(&magic_bytes, &crate::asset_compiler::MAGIC) {
-            return ris_error::new_result!("unkown magic value: {:?}", magic_bytes);
-        }
+        let version = asset_compiler::read_version(magic_bytes)?;
 
         let p_original_asset_names = ris_io::read_fat_ptr(f)?;
 
+        let addr_asset_lookup = ris_io::seek(f, SeekFrom::Current(0))?;
+        let file_len = ris_io::seek(f, SeekFrom::End(0))?;
+        if file_len < p_original_asset_names.end() {
+            return ris_error::new_result!(
+                "asset file is truncated. expected at least {} bytes but was {}",
+                p_original_asset_names.end(),
+                file_len,
+            );
+        }
+        ris_io::seek(f, SeekFrom::Start(addr_asset_lookup))?;
+
         let asset_lookup_count = ris_io::read_uint(f)?;
         let mut asset_lookup = vec![0; asset_lookup_count];
         for asset_lookup_entry in asset_lookup.iter_mut() {
@@ -47,17 +60,30 @@ impl AssetLoaderCompiled {
 
         Ok(Self {
             file,
+            version,
             lookup: fat_ptr_lookup,
         })
     }
 
-    pub fn load(&mut self, id: usize) -> RisResult<Vec<u8>> {
-        let p_asset = self
-            .lookup
-            .get(id)
-            .ok_or_else(|| ris_error::new!("asset does not exist"))?;
+    pub fn asset_count(&self) -> usize {
+        self.lookup.len()
+    }
+
+    /// reads the asset and verifies its checksum
+    pub fn load(&mut self, id: usize) -> Result<Vec<u8>, LoadError> {
+        let Some(p_asset) = self.lookup.get(id) else {
+            ris_log::error!("failed loading {:?}: asset does not exist", id);
+            return Err(LoadError::LoadFailed);
+        };
 
-        let bytes = ris_io::read_at(&mut self.file, *p_asset)?;
-        Ok(bytes)
+        let entry = ris_io::read_at(&mut self.file, *p_asset).map_err(|e| {
+            ris_log::error!("failed loading {:?}: {}", id, e);
+            LoadError::LoadFailed
+        })?;
+
+        asset_compiler::decode_entry(&entry, self.version).map_err(|e| {
+            ris_log::error!("asset {:?} is corrupted: {}", id, e);
+            LoadError::Corrupted
+        })
     }
 }
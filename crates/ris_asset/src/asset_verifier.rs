@@ -0,0 +1,337 @@
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::path::Path;
+
+use ris_data::asset_id::AssetId;
+use ris_error::RisResult;
+
+use crate::asset_graph;
+use crate::asset_loader_compiled::AssetLoaderCompiled;
+use crate::asset_loader_directory::AssetLoaderDirectory;
+use crate::assets::ris_god_asset;
+use crate::assets::ris_mesh;
+use crate::assets::ris_prefab;
+use crate::assets::ris_scene;
+use crate::codecs::qoi;
+use crate::RisHeader;
+
+const RIS_PREFIX: &[u8] = b"ris_";
+const QOI_MAGIC: &[u8] = b"qoif";
+const QOI_EXTENSION: &str = ".qoi";
+const SPIRV_MAGIC: u32 = 0x0723_0203;
+const SPIRV_HEADER_WORDS: usize = 5;
+const SPIRV_EXTENSION: &str = ".spv";
+
+#[derive(Debug)]
+pub enum Problem {
+    MissingGodAsset,
+    DanglingReference { asset: AssetId, reference: AssetId },
+    UnknownMagic { asset: AssetId, magic: String },
+    Undecodable { asset: AssetId, reason: String },
+    Orphaned { asset: AssetId },
+}
+
+#[derive(Debug, Default)]
+pub struct VerifyReport {
+    pub asset_count: usize,
+    pub problems: Vec<Problem>,
+}
+
+enum Loader {
+    Compiled(AssetLoaderCompiled),
+    Directory(AssetLoaderDirectory),
+}
+
+impl Problem {
+    /// orphans are only warnings, because assets may also be loaded by their path at runtime
+    pub fn is_error(&self) -> bool {
+        !matches!(self, Self::Orphaned { .. })
+    }
+}
+
+impl std::fmt::Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingGodAsset => write!(f, "god asset does not exist"),
+            Self::DanglingReference { asset, reference } => {
+                write!(
+                    f,
+                    "{:?} references {:?}, which does not exist",
+                    asset, reference
+                )
+            }
+            Self::UnknownMagic { asset, magic } => {
+                write!(f, "{:?} has unknown magic {}", asset, magic)
+            }
+            Self::Undecodable { asset, reason } => {
+                write!(f, "{:?} cannot be decoded: {}", asset, reason)
+            }
+            Self::Orphaned { asset } => {
+                write!(f, "{:?} is not reachable from the god asset", asset)
+            }
+        }
+    }
+}
+
+impl VerifyReport {
+    pub fn error_count(&self) -> usize {
+        self.problems.iter().filter(|x| x.is_error()).count()
+    }
+
+    pub fn warning_count(&self) -> usize {
+        self.problems.len() - self.error_count()
+    }
+}
+
+impl Loader {
+    fn load(&mut self, id: &AssetId) -> RisResult<Vec<u8>> {
+        match (self, id) {
+            (Self::Compiled(loader), AssetId::Index(id)) => loader
+                .load(*id)
+                .map_err(|e| ris_error::new!("failed to load: {}", e)),
+            (Self::Directory(loader), AssetId::Path(id)) => loader.load(id.clone()),
+            (_, id) => ris_error::new_result!("invalid id {:?}", id),
+        }
+    }
+}
+
+/// checks that all assets of a compiled asset file or an asset directory can be decoded, and
+/// that all their references resolve
+pub fn verify(asset_path: &Path) -> RisResult<VerifyReport> {
+    let metadata = asset_path.metadata()?;
+    let (mut loader, asset_ids, god_asset_id) = if metadata.is_file() {
+        let loader = AssetLoaderCompiled::new(asset_path)?;
+        let asset_ids = (0..loader.asset_count())
+            .map(AssetId::Index)
+            .collect::<Vec<_>>();
+        let god_asset_id = asset_ids.first().cloned();
+
+        (Loader::Compiled(loader), asset_ids, god_asset_id)
+    } else if metadata.is_dir() {
+        let loader = AssetLoaderDirectory::new(asset_path);
+        let asset_ids = find_files(asset_path)?;
+        let god_asset_id = [ris_god_asset::PATH, ris_god_asset::UNNAMED_PATH]
+            .into_iter()
+            .map(|x| AssetId::Path(x.to_string()))
+            .find(|x| asset_ids.contains(x));
+
+        (Loader::Directory(loader), asset_ids, god_asset_id)
+    } else {
+        return ris_error::new_result!("assets are neither a file nor a directory");
+    };
+
+    let mut problems = Vec::new();
+    let mut references = Vec::with_capacity(asset_ids.len());
+
+    for asset_id in asset_ids.iter() {
+        ris_log::trace!("verifying {:?}...", asset_id);
+
+        let asset_references = match loader.load(asset_id) {
+            Ok(bytes) => verify_asset(asset_id, &bytes, &mut problems),
+            Err(e) => {
+                problems.push(Problem::Undecodable {
+                    asset: asset_id.clone(),
+                    reason: e.to_string(),
+                });
+                Vec::new()
+            }
+        };
+
+        for reference in asset_references.iter() {
+            if !asset_ids.contains(reference) {
+                problems.push(Problem::DanglingReference {
+                    asset: asset_id.clone(),
+                    reference: reference.clone(),
+                });
+            }
+        }
+
+        references.push(asset_references);
+    }
+
+    // find orphans
+    match god_asset_id {
+        None => problems.push(Problem::MissingGodAsset),
+        Some(god_asset_id) => {
+            let mut reachable = HashSet::new();
+            let mut queue = VecDeque::new();
+            queue.push_back(god_asset_id);
+
+            while let Some(asset_id) = queue.pop_front() {
+                let Some(index) = asset_ids.iter().position(|x| *x == asset_id) else {
+                    continue;
+                };
+
+                if !reachable.insert(index) {
+                    continue;
+                }
+
+                queue.extend(references[index].iter().cloned());
+            }
+
+            for (i, asset_id) in asset_ids.iter().enumerate() {
+                if !reachable.contains(&i) {
+                    problems.push(Problem::Orphaned {
+                        asset: asset_id.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(VerifyReport {
+        asset_count: asset_ids.len(),
+        problems,
+    })
+}
+
+fn find_files(asset_path: &Path) -> RisResult<Vec<AssetId>> {
+    let mut asset_ids = Vec::new();
+    let mut directories = VecDeque::new();
+    directories.push_back(asset_path.to_path_buf());
+
+    while let Some(current) = directories.pop_front() {
+        for entry in std::fs::read_dir(&current)? {
+            let entry = entry?;
+            let metadata = entry.metadata()?;
+            let entry_path = entry.path();
+
+            if metadata.is_file() {
+                let id = asset_graph::to_id(asset_path, &entry_path)?;
+                asset_ids.push(AssetId::Path(id));
+            } else if metadata.is_dir() {
+                directories.push_back(entry_path);
+            }
+        }
+    }
+
+    asset_ids.sort_by(|left, right| format!("{:?}", left).cmp(&format!("{:?}", right)));
+    Ok(asset_ids)
+}
+
+/// verifies a single asset and returns its references
+fn verify_asset(asset_id: &AssetId, bytes: &[u8], problems: &mut Vec<Problem>) -> Vec<AssetId> {
+    let undecodable = |reason: String| Problem::Undecodable {
+        asset: asset_id.clone(),
+        reason,
+    };
+
+    let extension_is = |extension: &str| match asset_id {
+        AssetId::Path(path) => path.to_lowercase().ends_with(extension),
+        AssetId::Index(_) => false,
+    };
+
+    if bytes.starts_with(RIS_PREFIX) {
+        let header = match RisHeader::load(bytes) {
+            Ok(Some(header)) => header,
+            Ok(None) => return Vec::new(),
+            Err(e) => {
+                problems.push(undecodable(format!("invalid header: {}", e)));
+                return Vec::new();
+            }
+        };
+
+        if let Err(reason) = verify_ris_asset(&header, bytes) {
+            match reason {
+                None => problems.push(Problem::UnknownMagic {
+                    asset: asset_id.clone(),
+                    magic: RisHeader::format_magic(header.magic),
+                }),
+                Some(reason) => problems.push(undecodable(reason)),
+            }
+        }
+
+        header.references
+    } else if bytes.starts_with(QOI_MAGIC) || extension_is(QOI_EXTENSION) {
+        if let Err(e) = qoi::decode(bytes, None) {
+            problems.push(undecodable(format!("invalid qoi: {}", e)));
+        }
+
+        Vec::new()
+    } else if starts_with_spirv_magic(bytes) || extension_is(SPIRV_EXTENSION) {
+        if let Err(e) = parse_spirv(bytes) {
+            problems.push(undecodable(format!("invalid spirv: {}", e)));
+        }
+
+        Vec::new()
+    } else {
+        // not a known format, nothing to verify
+        Vec::new()
+    }
+}
+
+/// returns `Err(None)` if the magic is unknown
+fn verify_ris_asset(header: &RisHeader, bytes: &[u8]) -> Result<(), Option<String>> {
+    let magic = header.magic;
+    if magic == ris_god_asset::MAGIC {
+        if header.references.len() != ris_god_asset::REFERENCE_COUNT {
+            return Err(Some(format!(
+                "expected god asset to have {} references but it has {}",
+                ris_god_asset::REFERENCE_COUNT,
+                header.references.len(),
+            )));
+        }
+
+        Ok(())
+    } else if magic == ris_mesh::MAGIC {
+        ris_mesh::load(bytes)
+            .map(|_| ())
+            .map_err(|e| Some(format!("invalid mesh: {}", e)))
+    } else if is_versioned(magic, ris_scene::MAGIC) || is_versioned(magic, ris_prefab::MAGIC) {
+        // decoding scenes requires a registry, only the version is checked
+        Ok(())
+    } else {
+        Err(None)
+    }
+}
+
+fn is_versioned(magic: [u8; 16], newest: [u8; 16]) -> bool {
+    magic[..15] == newest[..15] && magic[15] <= newest[15]
+}
+
+fn starts_with_spirv_magic(bytes: &[u8]) -> bool {
+    bytes.len() >= 4 && u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) == SPIRV_MAGIC
+}
+
+/// checks the header of the module, and that every instruction fits into the module
+pub fn parse_spirv(bytes: &[u8]) -> RisResult<()> {
+    // `is_multiple_of` requires a newer rust version than the workspace supports
+    #[allow(clippy::manual_is_multiple_of)]
+    if bytes.len() % 4 != 0 {
+        return ris_error::new_result!("length {} is not a multiple of 4", bytes.len());
+    }
+
+    let words = bytes
+        .chunks_exact(4)
+        .map(|x| u32::from_le_bytes([x[0], x[1], x[2], x[3]]))
+        .collect::<Vec<_>>();
+
+    if words.len() < SPIRV_HEADER_WORDS {
+        return ris_error::new_result!("module is too small for a header");
+    }
+
+    if words[0] != SPIRV_MAGIC {
+        return ris_error::new_result!("unknown magic {:#010x}", words[0]);
+    }
+
+    let mut i = SPIRV_HEADER_WORDS;
+    while i < words.len() {
+        let word_count = (words[i] >> 16) as usize;
+        let opcode = words[i] & 0xFFFF;
+        if word_count == 0 {
+            return ris_error::new_result!("instruction {} at word {} has no length", opcode, i);
+        }
+
+        if i + word_count > words.len() {
+            return ris_error::new_result!(
+                "instruction {} at word {} exceeds the module",
+                opcode,
+                i,
+            );
+        }
+
+        i += word_count;
+    }
+
+    Ok(())
+}
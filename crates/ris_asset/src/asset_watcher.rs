@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::Weak;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+
+use ris_data::asset_id::AssetId;
+use ris_error::Extensions;
+use ris_error::RisResult;
+use ris_jobs::job_system;
+
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FileState {
+    modified: SystemTime,
+    len: u64,
+}
+
+#[derive(Debug, Default)]
+struct SubscriptionInner {
+    asset_ids: Vec<AssetId>,
+    changed: Vec<AssetId>,
+}
+
+/// receives the assets that changed, out of the assets that were subscribed to
+#[derive(Debug)]
+pub struct AssetSubscription {
+    inner: Arc<Mutex<SubscriptionInner>>,
+}
+
+/// watches the files of the directory asset loader, such that assets can be reloaded when they
+/// change on disk.
+///
+/// no platform specific apis are used. instead, the modification time and the size of every file
+/// are polled. an asset counts as changed when it was modified, created or deleted. the asset id
+/// of a file is its path relative to the watched directory.
+#[derive(Debug)]
+pub struct AssetWatcher {
+    base_path: PathBuf,
+    poll_interval: Duration,
+    last_poll: Instant,
+    files: HashMap<String, FileState>,
+    subscriptions: Vec<Weak<Mutex<SubscriptionInner>>>,
+}
+
+impl AssetSubscription {
+    /// returns the changed assets since the last call, and clears them
+    pub fn take_changed(&self) -> Vec<AssetId> {
+        std::mem::take(&mut job_system::lock(&self.inner).changed)
+    }
+
+    pub fn has_changed(&self) -> bool {
+        !job_system::lock(&self.inner).changed.is_empty()
+    }
+}
+
+impl AssetWatcher {
+    /// takes a snapshot of the directory. only changes after this snapshot are reported.
+    pub fn new(base_path: &Path, poll_interval: Duration) -> RisResult<Self> {
+        let base_path = base_path.to_path_buf();
+        let files = scan(&base_path)?;
+
+        Ok(Self {
+            base_path,
+            poll_interval,
+            last_poll: Instant::now(),
+            files,
+            subscriptions: Vec::new(),
+        })
+    }
+
+    /// the subscription is notified about changes to any of the given assets. it is removed when
+    /// it is dropped.
+    pub fn subscribe(&mut self, asset_ids: Vec<AssetId>) -> AssetSubscription {
+        let inner = Arc::new(Mutex::new(SubscriptionInner {
+            asset_ids,
+            changed: Vec::new(),
+        }));
+
+        self.subscriptions.push(Arc::downgrade(&inner));
+        AssetSubscription { inner }
+    }
+
+    /// rescans the directory, if `poll_interval` has passed since the last scan. returns all
+    /// changed assets and notifies the subscriptions.
+    pub fn poll(&mut self) -> RisResult<Vec<AssetId>> {
+        if self.last_poll.elapsed() < self.poll_interval {
+            return Ok(Vec::new());
+        }
+
+        self.poll_now()
+    }
+
+    /// like `poll`, but ignores `poll_interval`
+    pub fn poll_now(&mut self) -> RisResult<Vec<AssetId>> {
+        self.last_poll = Instant::now();
+
+        let files = scan(&self.base_path)?;
+
+        let mut changed = Vec::new();
+        for (path, state) in files.iter() {
+            if self.files.get(path) != Some(state) {
+                changed.push(AssetId::Path(path.clone()));
+            }
+        }
+
+        for path in self.files.keys() {
+            if !files.contains_key(path) {
+                changed.push(AssetId::Path(path.clone()));
+            }
+        }
+
+        self.files = files;
+
+        if changed.is_empty() {
+            return Ok(changed);
+        }
+
+        ris_log::debug!("assets changed: {:?}", changed);
+
+        self.subscriptions.retain(|x| {
+            let Some(inner) = x.upgrade() else {
+                return false;
+            };
+
+            let mut inner = job_system::lock(&inner);
+            for asset_id in changed.iter() {
+                if inner.asset_ids.contains(asset_id) && !inner.changed.contains(asset_id) {
+                    inner.changed.push(asset_id.clone());
+                }
+            }
+
+            true
+        });
+
+        Ok(changed)
+    }
+}
+
+fn scan(base_path: &Path) -> RisResult<HashMap<String, FileState>> {
+    let mut files = HashMap::new();
+    let mut directories = vec![base_path.to_path_buf()];
+
+    // files and directories may be removed while they are scanned, for example when an editor
+    // renames a temporary file. these are skipped, they are picked up by the next scan
+    while let Some(current) = directories.pop() {
+        let entries = match std::fs::read_dir(&current) {
+            Ok(entries) => entries,
+            Err(e) if is_not_found(&e) && current != base_path => continue,
+            Err(e) => return Err(e.into()),
+        };
+
+        for entry in entries {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(e) if is_not_found(&e) => continue,
+                Err(e) => return Err(e.into()),
+            };
+
+            let metadata = match entry.metadata() {
+                Ok(metadata) => metadata,
+                Err(e) if is_not_found(&e) => continue,
+                Err(e) => return Err(e.into()),
+            };
+
+            let entry_path = entry.path();
+
+            if metadata.is_dir() {
+                directories.push(entry_path);
+                continue;
+            }
+
+            if !metadata.is_file() {
+                continue;
+            }
+
+            let relative_path = entry_path.strip_prefix(base_path)?;
+            let id = relative_path.to_str().into_ris_error()?.replace('\\', "/");
+            let state = FileState {
+                modified: metadata.modified()?,
+                len: metadata.len(),
+            };
+
+            files.insert(id, state);
+        }
+    }
+
+    Ok(files)
+}
+
+fn is_not_found(e: &std::io::Error) -> bool {
+    e.kind() == std::io::ErrorKind::NotFound
+}
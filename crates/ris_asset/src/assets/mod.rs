@@ -1,3 +1,5 @@
 pub mod ris_god_asset;
 pub mod ris_header;
+pub mod ris_mesh;
+pub mod ris_prefab;
 pub mod ris_scene;
@@ -12,6 +12,8 @@ pub const MAGIC: [u8; 16] = [
 ];
 pub const PATH: &str = "god_asset.ris_god_asset";
 pub const UNNAMED_PATH: &str = "asset_0";
+/// the number of references in the header, one for each field of `RisGodAsset`
+pub const REFERENCE_COUNT: usize = 12;
 
 #[derive(Clone)]
 pub struct RisGodAsset {
@@ -60,6 +62,13 @@ impl RisGodAsset {
     pub fn load(bytes: &[u8]) -> RisResult<Self> {
         let header = RisHeader::load(bytes)?.into_ris_error()?;
         header.assert_magic(MAGIC)?;
+        if header.references.len() != REFERENCE_COUNT {
+            return ris_error::new_result!(
+                "expected {} references but got {}",
+                REFERENCE_COUNT,
+                header.references.len(),
+            );
+        }
 
         let default_vert_spv = header.references[0].clone();
         let default_frag_spv = header.references[1].clone();
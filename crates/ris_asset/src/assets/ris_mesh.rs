@@ -0,0 +1,97 @@
+use std::io::Cursor;
+
+use ris_data::ecs::mesh::Mesh;
+use ris_data::ecs::mesh::Vertex;
+use ris_error::Extensions;
+use ris_error::RisResult;
+use ris_math::color::Rgb;
+
+use super::ris_header::RisHeader;
+
+// # File Format
+//
+// encoding: little-endian
+//
+// - RisHeader: magic `ris_mesh\0\0\0\0\0\0\0\0`, no references
+// - u32: vertex_count
+// - [Vertex; vertex_count]: vertices
+//  - Vec3: pos
+//  - [f32; 3]: color
+//  - Vec2: uv
+// - u32: index_count
+// - [u32; index_count]: indices
+
+// ris_mesh\0\0\0\0\0\0\0\0
+pub const MAGIC: [u8; 16] = [
+    0x72, 0x69, 0x73, 0x5f, 0x6d, 0x65, 0x73, 0x68, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+];
+pub const EXTENSION: &str = "ris_mesh";
+
+pub fn serialize(mesh: &Mesh) -> RisResult<Vec<u8>> {
+    let mut stream = Cursor::new(Vec::new());
+    let f = &mut stream;
+
+    let header = RisHeader::new(MAGIC, Vec::new());
+    let header_bytes = header.serialize()?;
+    ris_io::write(f, &header_bytes)?;
+
+    ris_io::write_uint(f, mesh.vertices.len())?;
+    for vertex in mesh.vertices.iter() {
+        let Rgb(r, g, b) = vertex.color;
+
+        ris_io::write_vec3(f, vertex.pos)?;
+        ris_io::write_f32(f, r)?;
+        ris_io::write_f32(f, g)?;
+        ris_io::write_f32(f, b)?;
+        ris_io::write_vec2(f, vertex.uv)?;
+    }
+
+    ris_io::write_uint(f, mesh.indices.len())?;
+    for &index in mesh.indices.iter() {
+        ris_io::write_uint(f, index as usize)?;
+    }
+
+    let bytes = stream.into_inner();
+    Ok(bytes)
+}
+
+pub fn load(bytes: &[u8]) -> RisResult<Mesh> {
+    let header = RisHeader::load(bytes)?.into_ris_error()?;
+    header.assert_magic(MAGIC)?;
+
+    let content = header.content(bytes)?;
+    let f = &mut Cursor::new(content);
+
+    let vertex_count = ris_io::read_uint(f)?;
+    let mut vertices = Vec::with_capacity(vertex_count);
+    for _ in 0..vertex_count {
+        let pos = ris_io::read_vec3(f)?;
+        let r = ris_io::read_f32(f)?;
+        let g = ris_io::read_f32(f)?;
+        let b = ris_io::read_f32(f)?;
+        let uv = ris_io::read_vec2(f)?;
+
+        vertices.push(Vertex {
+            pos,
+            color: Rgb(r, g, b),
+            uv,
+        });
+    }
+
+    let index_count = ris_io::read_uint(f)?;
+    let mut indices = Vec::with_capacity(index_count);
+    for _ in 0..index_count {
+        let index = ris_io::read_uint(f)?;
+        if index >= vertex_count {
+            return ris_error::new_result!(
+                "index {} is out of bounds. vertex count: {}",
+                index,
+                vertex_count,
+            );
+        }
+
+        indices.push(index as u32);
+    }
+
+    Ok(Mesh { vertices, indices })
+}
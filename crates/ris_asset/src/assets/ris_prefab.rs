@@ -0,0 +1,126 @@
+use ris_data::asset_id::AssetId;
+use ris_data::ecs::decl::GameObjectHandle;
+use ris_data::ecs::id::GameObjectKind;
+use ris_data::ecs::scene::Scene;
+use ris_data::ecs::scene_stream::SceneReader;
+use ris_data::ecs::scene_stream::SceneWriter;
+use ris_error::RisResult;
+use ris_math::quaternion::Quat;
+use ris_math::vector::Vec3;
+
+use super::ris_scene;
+
+// # File Format
+//
+// - RisHeader: magic `ris_prefab\0\0\0\0\0\x02`, references are the assets used by components.
+//   the last byte of the magic is the version, which is shared with `ris_scene`
+// - deflate compressed content, same layout as the game objects of a `ris_scene`. the first game
+//   object is the root of the prefab
+
+// ris_prefab\0\0\0\0\0\x02
+pub const MAGIC: [u8; 16] = [
+    0x72, 0x69, 0x73, 0x5f, 0x70, 0x72, 0x65, 0x66, 0x61, 0x62, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+];
+pub const EXTENSION: &str = "ris_prefab";
+
+/// a loaded prefab, that can be instantiated any number of times
+#[derive(Debug, Clone)]
+pub struct Prefab {
+    content: Vec<u8>,
+    references: Vec<AssetId>,
+    version: u8,
+}
+
+/// overrides that are applied to the root of a single prefab instance. `None` keeps the value
+/// that is stored in the prefab.
+#[derive(Debug, Default, Clone)]
+pub struct PrefabOverrides {
+    pub name: Option<String>,
+    pub is_active: Option<bool>,
+    pub parent: Option<GameObjectHandle>,
+    pub local_position: Option<Vec3>,
+    pub local_rotation: Option<Quat>,
+    pub local_scale: Option<Vec3>,
+}
+
+/// serializes `root` and all its descendants, including their components and scripts. the parent
+/// of `root` is not serialized. components may only reference game objects inside the subtree.
+pub fn serialize(scene: &Scene, root: GameObjectHandle) -> RisResult<Vec<u8>> {
+    let kind = GameObjectKind::try_from(root.0.scene_id().kind)?;
+
+    let mut handles = Vec::new();
+    collect_subtree(scene, root, &mut handles)?;
+
+    let mut stream = SceneWriter::new(kind, scene);
+    let lookup = ris_scene::write_game_objects(&mut stream, &handles)?;
+    let (bytes, references) = stream.resolve(lookup)?;
+
+    ris_scene::compress(MAGIC, &bytes, references)
+}
+
+pub fn load(bytes: &[u8]) -> RisResult<Prefab> {
+    let (content, references, version) = ris_scene::decompress(MAGIC, bytes)?;
+    Ok(Prefab {
+        content,
+        references,
+        version,
+    })
+}
+
+impl Prefab {
+    /// creates a new dynamic game object for every game object in the prefab and returns the root
+    pub fn instantiate(
+        &self,
+        scene: &Scene,
+        overrides: &PrefabOverrides,
+    ) -> RisResult<GameObjectHandle> {
+        let kind = GameObjectKind::Dynamic;
+        let mut stream =
+            SceneReader::new(kind, scene, self.content.clone(), self.references.clone());
+        let game_objects = ris_scene::read_game_objects(&mut stream, kind, self.version)?;
+
+        let Some(&root) = game_objects.first() else {
+            return ris_error::new_result!("prefab contains no game objects");
+        };
+
+        if let Some(name) = overrides.name.as_ref() {
+            root.set_name(scene, name)?;
+        }
+
+        if let Some(is_active) = overrides.is_active {
+            root.set_active(scene, is_active)?;
+        }
+
+        if let Some(parent) = overrides.parent {
+            let sibling_index = parent.children(scene)?.len();
+            root.set_parent(scene, Some(parent), sibling_index, false)?;
+        }
+
+        if let Some(local_position) = overrides.local_position {
+            root.set_local_position(scene, local_position)?;
+        }
+
+        if let Some(local_rotation) = overrides.local_rotation {
+            root.set_local_rotation(scene, local_rotation)?;
+        }
+
+        if let Some(local_scale) = overrides.local_scale {
+            root.set_local_scale(scene, local_scale)?;
+        }
+
+        Ok(root)
+    }
+}
+
+fn collect_subtree(
+    scene: &Scene,
+    game_object: GameObjectHandle,
+    handles: &mut Vec<GameObjectHandle>,
+) -> RisResult<()> {
+    handles.push(game_object);
+    for child in game_object.children(scene)? {
+        collect_subtree(scene, child, handles)?;
+    }
+
+    Ok(())
+}
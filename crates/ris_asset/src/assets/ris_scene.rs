@@ -1,19 +1,42 @@
+use std::any::TypeId;
+use std::fmt::Write;
 use std::io::Cursor;
 use std::io::SeekFrom;
 
+use ris_data::asset_id::AssetId;
+use ris_data::ecs::components::script::DynScriptComponent;
 use ris_data::ecs::decl::GameObjectHandle;
+use ris_data::ecs::game_object::GetFrom;
+use ris_data::ecs::handle::ComponentHandle;
+use ris_data::ecs::id::GameObjectKind;
+use ris_data::ecs::registry::IComponentFactory;
+use ris_data::ecs::registry::Registry;
 use ris_data::ecs::scene::Scene;
 use ris_data::ecs::scene_stream::SceneReader;
 use ris_data::ecs::scene_stream::SceneWriter;
 use ris_error::Extensions;
 use ris_error::RisResult;
 use ris_io::FatPtr;
+use ris_math::quaternion::Quat;
+use ris_math::vector::Vec3;
 
 use super::ris_header::RisHeader;
 
-// ris_scene\0\0\0\0\0\0\0
+// # Versions
+//
+// the last byte of the magic is the version of the format. files of older versions can still be
+// loaded, but are always written in the current version.
+//
+// - 0: scale is a single `f32`
+// - 1: scale is a `Vec3`
+// - 2: components are identified by the hash of their sid instead of their position in the
+//   registry, followed by the version of the component. see `Component::version`
+//
+// `ris_prefab` shares the layout of the game objects, and thus the versions.
+
+// ris_scene\0\0\0\0\0\0\x02
 pub const MAGIC: [u8; 16] = [
-    0x72, 0x69, 0x73, 0x5f, 0x73, 0x63, 0x65, 0x6e, 0x65, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x72, 0x69, 0x73, 0x5f, 0x73, 0x63, 0x65, 0x6e, 0x65, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
 ];
 pub const EXTENSION: &str = "ris_scene";
 
@@ -23,29 +46,249 @@ pub fn serialize(scene: &Scene, chunk_index: Option<usize>) -> RisResult<Vec<u8>
     let (handles, chunk_index) = match chunk_index {
         Some(chunk_index) => {
             ris_error::debug_assert!(chunk_index < scene.static_chunks.len())?;
-            let chunk = &scene.static_chunks[chunk_index];
-
-            let handles = chunk
-                .game_objects
-                .iter()
-                .filter(|x| x.borrow().is_alive)
-                .map(|x| x.borrow().handle)
-                .collect::<Vec<_>>();
+            let kind = GameObjectKind::Static { chunk: chunk_index };
+            let handles = scene.game_objects(kind).collect::<Vec<_>>();
 
             (handles, chunk_index)
         }
         None => (Vec::with_capacity(0), 0),
     };
 
-    let mut stream = SceneWriter::new(chunk_index, scene);
+    let kind = GameObjectKind::Static { chunk: chunk_index };
+    let mut stream = SceneWriter::new(kind, scene);
+    let lookup = write_game_objects(&mut stream, &handles)?;
+    let (bytes, references) = stream.resolve(lookup)?;
+
+    compress(MAGIC, &bytes, references)
+}
+
+/// loads the scene into the first static chunk that is not reserved. returns `None` if all
+/// chunks are reserved. the chunk is cleared, if the scene fails to deserialize.
+pub fn load(scene: &Scene, bytes: &[u8]) -> RisResult<Option<usize>> {
+    let reserved = scene.reserve_chunk();
+    let Some(index) = reserved else {
+        return Ok(None);
+    };
+
+    if let Err(e) = deserialize_into_chunk(scene, bytes, index) {
+        // remove the game objects that were spawned before the error
+        scene.clear_chunk(index);
+        return Err(e);
+    }
+
+    Ok(Some(index))
+}
+
+/// loads the scene into the chunk at the given index. fails if that chunk is already reserved.
+/// the chunk is cleared, if the scene fails to deserialize.
+pub fn load_into_chunk(scene: &Scene, bytes: &[u8], index: usize) -> RisResult<()> {
+    if !scene.reserve_chunk_at(index) {
+        return ris_error::new_result!(
+            "chunk {} cannot be reserved. it is either out of bounds or already in use",
+            index,
+        );
+    }
+
+    if let Err(e) = deserialize_into_chunk(scene, bytes, index) {
+        scene.clear_chunk(index);
+        return Err(e);
+    }
+
+    Ok(())
+}
+
+fn deserialize_into_chunk(scene: &Scene, bytes: &[u8], index: usize) -> RisResult<()> {
+    let (uncompressed, references, version) = decompress(MAGIC, bytes)?;
+
+    let kind = GameObjectKind::Static { chunk: index };
+    let mut stream = SceneReader::new(kind, scene, uncompressed, references);
+    read_game_objects(&mut stream, kind, version)?;
+
+    Ok(())
+}
+
+/// decodes a serialized scene into a human readable tree of its game objects, their transforms
+/// and the types of their components. components and scripts are named using the given registry.
+/// ids that are unknown to the registry are printed as numbers.
+pub fn dump(bytes: &[u8], registry: &Registry) -> RisResult<String> {
+    let (uncompressed, references, version) = decompress(MAGIC, bytes)?;
+    let mut stream = Cursor::new(uncompressed);
     let f = &mut stream;
 
-    let mut lookup = Vec::new();
+    let game_object_count = ris_io::read_uint(f)?;
+    let mut game_objects = Vec::with_capacity(game_object_count);
+    for _ in 0..game_object_count {
+        let name = ris_io::read_string(f)?;
+        let is_active = ris_io::read_bool(f)?;
+        let local_position = ris_io::read_vec3(f)?;
+        let local_rotation = ris_io::read_quat(f)?;
+        let local_scale = read_scale(f, version)?;
+
+        let component_count = ris_io::read_uint(f)?;
+        let mut component_ptrs = Vec::with_capacity(component_count);
+        for _ in 0..component_count {
+            let ptr = ris_io::read_fat_ptr(f)?;
+            component_ptrs.push(ptr);
+
+            ris_io::seek(f, SeekFrom::Current(ptr.len.try_into()?))?;
+        }
+
+        let child_count = ris_io::read_uint(f)?;
+        let mut children = Vec::with_capacity(child_count);
+        for _ in 0..child_count {
+            let child_id = ris_io::read_uint(f)?;
+            if child_id >= game_object_count {
+                return ris_error::new_result!(
+                    "child {} is out of bounds. game object count: {}",
+                    child_id,
+                    game_object_count,
+                );
+            }
+
+            children.push(child_id);
+        }
+
+        let end = ris_io::seek(f, SeekFrom::Current(0))?;
+
+        let mut components = Vec::with_capacity(component_count);
+        for FatPtr { addr, len } in component_ptrs {
+            ris_io::seek(f, SeekFrom::Start(addr))?;
+            let (id, component_version) = read_component_key(f, version)?;
+
+            let component = match find_component_factory(registry, id, version) {
+                Some(factory) if factory.component_id() == TypeId::of::<DynScriptComponent>() => {
+                    // see `DynScriptComponent::version`
+                    let script_id = ris_io::read_uint(f)?;
+                    let script = match component_version {
+                        0 => registry
+                            .script_factories()
+                            .get(script_id)
+                            .map(|x| x.as_ref()),
+                        _ => registry.find_script_factory(script_id as u32),
+                    };
+
+                    match script {
+                        Some(script_factory) => {
+                            format!(
+                                "{} {}",
+                                factory.component_name(),
+                                script_factory.script_name()
+                            )
+                        }
+                        None => format!("{} #{}", factory.component_name(), script_id),
+                    }
+                }
+                Some(factory) => factory.component_name().to_string(),
+                None => format!("#{}", id),
+            };
+
+            components.push(format!("{} ({} bytes)", component, len));
+        }
+
+        ris_io::seek(f, SeekFrom::Start(end))?;
+
+        game_objects.push(DumpedGameObject {
+            name,
+            is_active,
+            local_position,
+            local_rotation,
+            local_scale,
+            components,
+            children,
+        });
+    }
+
+    let mut result = String::new();
+    writeln!(result, "version: {}", version)?;
+    writeln!(result, "references: {}", references.len())?;
+    for (i, reference) in references.iter().enumerate() {
+        match reference {
+            AssetId::Index(id) => writeln!(result, "    [{}] index {}", i, id)?,
+            AssetId::Path(path) => writeln!(result, "    [{}] path \"{}\"", i, path)?,
+        }
+    }
+
+    writeln!(result, "game objects: {}", game_objects.len())?;
+
+    let mut is_child = vec![false; game_objects.len()];
+    for game_object in game_objects.iter() {
+        for &child in game_object.children.iter() {
+            is_child[child] = true;
+        }
+    }
+
+    let mut visited = vec![false; game_objects.len()];
+    for (i, _) in is_child.iter().enumerate().filter(|(_, &x)| !x) {
+        dump_game_object(&mut result, &game_objects, i, 1, &mut visited)?;
+    }
+
+    if let Some(i) = visited.iter().position(|x| !x) {
+        return ris_error::new_result!("game object {} is part of a circular hierarchy", i);
+    }
+
+    Ok(result)
+}
+
+struct DumpedGameObject {
+    name: String,
+    is_active: bool,
+    local_position: Vec3,
+    local_rotation: Quat,
+    local_scale: Vec3,
+    components: Vec<String>,
+    children: Vec<usize>,
+}
+
+fn dump_game_object(
+    result: &mut String,
+    game_objects: &[DumpedGameObject],
+    index: usize,
+    depth: usize,
+    visited: &mut [bool],
+) -> RisResult<()> {
+    if visited[index] {
+        return ris_error::new_result!("game object {} is referenced more than once", index);
+    }
+    visited[index] = true;
+
+    let game_object = &game_objects[index];
+    let indent = "    ".repeat(depth);
+    let Vec3(px, py, pz) = game_object.local_position;
+    let Quat(rx, ry, rz, rw) = game_object.local_rotation;
+    let Vec3(sx, sy, sz) = game_object.local_scale;
+
+    writeln!(result, "{}\"{}\"", indent, game_object.name)?;
+    writeln!(result, "{}  active: {}", indent, game_object.is_active)?;
+    writeln!(result, "{}  position: ({}, {}, {})", indent, px, py, pz)?;
+    writeln!(
+        result,
+        "{}  rotation: ({}, {}, {}, {})",
+        indent, rx, ry, rz, rw
+    )?;
+    writeln!(result, "{}  scale: ({}, {}, {})", indent, sx, sy, sz)?;
+    for component in game_object.components.iter() {
+        writeln!(result, "{}  component: {}", indent, component)?;
+    }
+
+    for &child in game_object.children.iter() {
+        dump_game_object(result, game_objects, child, depth + 1, visited)?;
+    }
+
+    Ok(())
+}
+
+/// writes the given game objects, their components and references to their children. children
+/// must be contained in `handles` as well. returns the lookup, which is required to resolve the
+/// stream.
+pub(crate) fn write_game_objects(
+    f: &mut SceneWriter,
+    handles: &[GameObjectHandle],
+) -> RisResult<Vec<usize>> {
+    let scene = f.scene;
+    let mut lookup = Vec::with_capacity(handles.len());
 
-    // serialize game objects
     ris_io::write_uint(f, handles.len())?;
-    for generic_handle in handles.into_iter() {
-        let handle: GameObjectHandle = generic_handle.into();
+    for &handle in handles.iter() {
         let scene_index = handle.0.scene_id().index;
         lookup.push(scene_index);
 
@@ -53,7 +296,7 @@ pub fn serialize(scene: &Scene, chunk_index: Option<usize>) -> RisResult<Vec<u8>
         ris_io::write_bool(f, handle.is_active(scene)?)?;
         ris_io::write_vec3(f, handle.local_position(scene)?)?;
         ris_io::write_quat(f, handle.local_rotation(scene)?)?;
-        ris_io::write_f32(f, handle.local_scale(scene)?)?;
+        ris_io::write_vec3(f, handle.local_scale(scene)?)?;
 
         let components = handle.components(scene)?;
         ris_io::write_uint(f, components.len())?;
@@ -61,15 +304,18 @@ pub fn serialize(scene: &Scene, chunk_index: Option<usize>) -> RisResult<Vec<u8>
             let ptr_addr = ris_io::write_fat_ptr(f, FatPtr::null())?.addr; // placeholder ptr
             let addr = ris_io::seek(f, SeekFrom::Current(0))?;
 
-            let position = scene
+            let factory = scene
                 .registry
                 .component_factories()
                 .iter()
-                .position(|x| x.component_id() == component.type_id())
+                .find(|x| x.component_id() == component.type_id())
                 .into_ris_error()?;
 
-            ris_io::write_uint(f, position)?;
-            scene.deref_mut_component(component, |x| x.serialize(f))??;
+            ris_io::write_uint(f, factory.component_sid().hash as usize)?;
+            scene.deref_mut_component(component, |x| -> RisResult<()> {
+                ris_io::write_uint(f, x.version() as usize)?;
+                x.serialize(f)
+            })??;
 
             // fill placeholder ptr
             let end = ris_io::seek(f, SeekFrom::Current(0))?;
@@ -87,52 +333,22 @@ pub fn serialize(scene: &Scene, chunk_index: Option<usize>) -> RisResult<Vec<u8>
         }
     }
 
-    // resolve
-    let (bytes, references) = stream.resolve(lookup)?;
-
-    // compress
-    let compressed = miniz_oxide::deflate::compress_to_vec(&bytes, COMPRESSION_LEVEL);
-    ris_log::trace!(
-        "compressed {} to {}. percentage: {}",
-        bytes.len(),
-        compressed.len(),
-        compressed.len() as f32 / bytes.len() as f32,
-    );
-
-    // add header
-    let mut stream = Cursor::new(Vec::new());
-    let f = &mut stream;
-
-    let header = RisHeader::new(MAGIC, references);
-    let header_bytes = header.serialize()?;
-    ris_io::write(f, &header_bytes)?;
-    ris_io::write(f, &compressed)?;
-
-    let result = stream.into_inner();
-    Ok(result)
+    Ok(lookup)
 }
 
-pub fn load(scene: &Scene, bytes: &[u8]) -> RisResult<Option<usize>> {
-    let reserved = scene.reserve_chunk();
-    let Some(index) = reserved else {
-        return Ok(None);
-    };
-
-    let chunk = &scene.static_chunks[index];
-
-    let header = RisHeader::load(bytes)?.into_ris_error()?;
-    header.assert_magic(MAGIC)?;
-
-    let content = header.content(bytes)?;
-    let uncompressed = miniz_oxide::inflate::decompress_to_vec(content)
-        .map_err(|e| ris_error::new!("failed to decompress: {:?}", e))?;
-
-    let mut stream = SceneReader::new(index, scene, uncompressed, header.references);
-    let f = &mut stream;
-
+/// reads game objects that were written by `write_game_objects` in the given format version and
+/// creates them as game objects of the given kind. returns the created game objects in the order
+/// they were written.
+pub(crate) fn read_game_objects(
+    f: &mut SceneReader,
+    kind: GameObjectKind,
+    version: u8,
+) -> RisResult<Vec<GameObjectHandle>> {
+    let scene = f.scene;
     let game_object_count = ris_io::read_uint(f)?;
 
     f.lookup = Vec::with_capacity(game_object_count);
+    let mut game_objects = Vec::with_capacity(game_object_count);
     let mut children_to_assign = Vec::with_capacity(game_object_count);
     let mut components_to_deserialize = Vec::with_capacity(game_object_count);
 
@@ -142,7 +358,7 @@ pub fn load(scene: &Scene, bytes: &[u8]) -> RisResult<Option<usize>> {
         let is_active = ris_io::read_bool(f)?;
         let local_position = ris_io::read_vec3(f)?;
         let local_rotation = ris_io::read_quat(f)?;
-        let local_scale = ris_io::read_f32(f)?;
+        let local_scale = read_scale(f, version)?;
 
         let component_count = ris_io::read_uint(f)?;
         let mut component_ptrs = Vec::with_capacity(component_count);
@@ -160,9 +376,10 @@ pub fn load(scene: &Scene, bytes: &[u8]) -> RisResult<Option<usize>> {
             child_ids.push(child_id);
         }
 
-        let game_object = GameObjectHandle::new_static(scene, index)?;
+        let game_object = GameObjectHandle::new_with_kind(scene, kind)?;
         let id = game_object.0.scene_id().index;
         f.lookup.push(id);
+        game_objects.push(game_object);
 
         game_object.set_name(scene, &name)?;
         game_object.set_active(scene, is_active)?;
@@ -177,37 +394,119 @@ pub fn load(scene: &Scene, bytes: &[u8]) -> RisResult<Option<usize>> {
     // assign children
     for (game_object, child_ids) in children_to_assign {
         for (i, &child_id) in child_ids.iter().enumerate() {
-            let actual_id = f.lookup.get(child_id).into_ris_error()?;
-
-            let child: GameObjectHandle = chunk
-                .game_objects
-                .iter()
-                .find(|x| x.borrow().handle.scene_id().index == *actual_id)
-                .into_ris_error()?
-                .borrow()
-                .handle
-                .into();
-
+            let child = game_objects.get(child_id).into_ris_error()?;
             child.set_parent(scene, Some(game_object), i, false)?;
         }
     }
 
     // deserialize components
+    // components that are not registered are skipped, as the fat pointers locate the next one
     for (game_object, component_ptrs) in components_to_deserialize {
         for FatPtr { addr, len: _ } in component_ptrs {
             ris_io::seek(f, SeekFrom::Start(addr))?;
 
-            let position = ris_io::read_uint(f)?;
-            let factory = scene
-                .registry
-                .component_factories()
-                .get(position)
-                .into_ris_error()?;
+            let (id, component_version) = read_component_key(f, version)?;
+            let Some(factory) = find_component_factory(&scene.registry, id, version) else {
+                ris_log::warning!("skipped component #{}, because it is not registered", id);
+                continue;
+            };
 
             let component = factory.make(scene, game_object)?;
-            scene.deref_mut_component(component, |x| x.deserialize(f))??;
+            scene.deref_mut_component(component, |x| x.deserialize(f, component_version))??;
+        }
+
+        // see `DynScriptComponent::deserialize`
+        let scripts = game_object.get_components::<DynScriptComponent>(scene, GetFrom::This)?;
+        for script in scripts {
+            let is_unknown = scene.deref(script)?.borrow().type_id().is_none();
+            if is_unknown {
+                script.destroy(scene);
+            }
         }
     }
 
-    Ok(Some(index))
+    Ok(game_objects)
+}
+
+/// compresses the content and prepends a header
+pub(crate) fn compress(
+    magic: [u8; 16],
+    bytes: &[u8],
+    references: Vec<AssetId>,
+) -> RisResult<Vec<u8>> {
+    let compressed = miniz_oxide::deflate::compress_to_vec(bytes, COMPRESSION_LEVEL);
+    ris_log::trace!(
+        "compressed {} to {}. percentage: {}",
+        bytes.len(),
+        compressed.len(),
+        compressed.len() as f32 / bytes.len() as f32,
+    );
+
+    let mut stream = Cursor::new(Vec::new());
+    let f = &mut stream;
+
+    let header = RisHeader::new(magic, references);
+    let header_bytes = header.serialize()?;
+    ris_io::write(f, &header_bytes)?;
+    ris_io::write(f, &compressed)?;
+
+    let result = stream.into_inner();
+    Ok(result)
+}
+
+/// reads the header and returns the decompressed content together with its references and its
+/// format version. the last byte of `magic` is the newest version that is accepted.
+pub(crate) fn decompress(magic: [u8; 16], bytes: &[u8]) -> RisResult<(Vec<u8>, Vec<AssetId>, u8)> {
+    let header = RisHeader::load(bytes)?.into_ris_error()?;
+
+    let version = header.magic[15];
+    let mut versioned_magic = magic;
+    versioned_magic[15] = version;
+    header.assert_magic(versioned_magic)?;
+
+    if version > magic[15] {
+        return ris_error::new_result!(
+            "unsupported version {}. newest supported version is {}",
+            version,
+            magic[15],
+        );
+    }
+
+    let content = header.content(bytes)?;
+    let uncompressed = miniz_oxide::inflate::decompress_to_vec(content)
+        .map_err(|e| ris_error::new!("failed to decompress: {:?}", e))?;
+
+    Ok((uncompressed, header.references, version))
+}
+
+fn read_scale(f: &mut (impl std::io::Read + std::io::Seek), version: u8) -> RisResult<Vec3> {
+    let scale = match version {
+        0 => Vec3::init(ris_io::read_f32(f)?),
+        _ => ris_io::read_vec3(f)?,
+    };
+
+    Ok(scale)
+}
+
+/// reads the id and the version of a component. before version 2, the id is the position of the
+/// component in the registry and the version is always 0.
+fn read_component_key(f: &mut impl std::io::Read, version: u8) -> RisResult<(usize, u32)> {
+    let id = ris_io::read_uint(f)?;
+    let component_version = match version {
+        0 | 1 => 0,
+        _ => ris_io::read_uint(f)? as u32,
+    };
+
+    Ok((id, component_version))
+}
+
+fn find_component_factory(
+    registry: &Registry,
+    id: usize,
+    version: u8,
+) -> Option<&dyn IComponentFactory> {
+    match version {
+        0 | 1 => registry.component_factories().get(id).map(|x| x.as_ref()),
+        _ => registry.find_component_factory(id as u32),
+    }
 }
@@ -0,0 +1,373 @@
+// minimal JSON decoder, implemented according to RFC 8259: https://www.rfc-editor.org/rfc/rfc8259
+//
+// only decoding is supported, as `ris_engine` only ever needs to read JSON from third party
+// formats, like glTF.
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+#[derive(Debug)]
+pub struct DecodeError {
+    pub message: String,
+    pub position: usize,
+}
+
+impl std::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "json error at byte {}: {}", self.position, self.message)
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+impl JsonValue {
+    /// returns the value of the key, if `self` is an object and the key exists
+    pub fn get(&self, key: &str) -> Option<&JsonValue> {
+        match self {
+            JsonValue::Object(members) => members.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            JsonValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            JsonValue::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_f32(&self) -> Option<f32> {
+        self.as_f64().map(|x| x as f32)
+    }
+
+    /// returns the number as `usize`, if it is a non-negative integer
+    pub fn as_usize(&self) -> Option<usize> {
+        let value = self.as_f64()?;
+        if value >= 0.0 && value.fract() == 0.0 && value <= usize::MAX as f64 {
+            Some(value as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            JsonValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[JsonValue]> {
+        match self {
+            JsonValue::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+}
+
+pub fn decode(text: &str) -> Result<JsonValue, DecodeError> {
+    let mut decoder = Decoder {
+        bytes: text.as_bytes(),
+        position: 0,
+    };
+
+    decoder.skip_whitespace();
+    let value = decoder.decode_value(0)?;
+    decoder.skip_whitespace();
+
+    if decoder.position != decoder.bytes.len() {
+        return decoder.error("unexpected trailing characters");
+    }
+
+    Ok(value)
+}
+
+const MAX_DEPTH: usize = 512;
+
+struct Decoder<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn error<T>(&self, message: impl Into<String>) -> Result<T, DecodeError> {
+        Err(DecodeError {
+            message: message.into(),
+            position: self.position,
+        })
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let byte = self.peek()?;
+        self.position += 1;
+        Some(byte)
+    }
+
+    fn expect(&mut self, expected: u8) -> Result<(), DecodeError> {
+        match self.next() {
+            Some(byte) if byte == expected => Ok(()),
+            Some(byte) => {
+                self.position -= 1;
+                self.error(format!(
+                    "expected '{}' but found '{}'",
+                    expected as char, byte as char
+                ))
+            }
+            None => self.error(format!(
+                "expected '{}' but reached end of input",
+                expected as char
+            )),
+        }
+    }
+
+    fn expect_literal(&mut self, literal: &str) -> Result<(), DecodeError> {
+        for &byte in literal.as_bytes() {
+            self.expect(byte)?;
+        }
+
+        Ok(())
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek() {
+            self.position += 1;
+        }
+    }
+
+    fn decode_value(&mut self, depth: usize) -> Result<JsonValue, DecodeError> {
+        if depth > MAX_DEPTH {
+            return self.error("maximum nesting depth exceeded");
+        }
+
+        match self.peek() {
+            Some(b'n') => {
+                self.expect_literal("null")?;
+                Ok(JsonValue::Null)
+            }
+            Some(b't') => {
+                self.expect_literal("true")?;
+                Ok(JsonValue::Bool(true))
+            }
+            Some(b'f') => {
+                self.expect_literal("false")?;
+                Ok(JsonValue::Bool(false))
+            }
+            Some(b'"') => Ok(JsonValue::String(self.decode_string()?)),
+            Some(b'[') => self.decode_array(depth),
+            Some(b'{') => self.decode_object(depth),
+            Some(b'-' | b'0'..=b'9') => self.decode_number(),
+            Some(byte) => self.error(format!("unexpected character '{}'", byte as char)),
+            None => self.error("unexpected end of input"),
+        }
+    }
+
+    fn decode_array(&mut self, depth: usize) -> Result<JsonValue, DecodeError> {
+        self.expect(b'[')?;
+        self.skip_whitespace();
+
+        let mut values = Vec::new();
+        if self.peek() == Some(b']') {
+            self.position += 1;
+            return Ok(JsonValue::Array(values));
+        }
+
+        loop {
+            self.skip_whitespace();
+            let value = self.decode_value(depth + 1)?;
+            values.push(value);
+            self.skip_whitespace();
+
+            match self.next() {
+                Some(b',') => continue,
+                Some(b']') => break,
+                _ => {
+                    self.position = self.position.saturating_sub(1);
+                    return self.error("expected ',' or ']'");
+                }
+            }
+        }
+
+        Ok(JsonValue::Array(values))
+    }
+
+    fn decode_object(&mut self, depth: usize) -> Result<JsonValue, DecodeError> {
+        self.expect(b'{')?;
+        self.skip_whitespace();
+
+        let mut members = Vec::new();
+        if self.peek() == Some(b'}') {
+            self.position += 1;
+            return Ok(JsonValue::Object(members));
+        }
+
+        loop {
+            self.skip_whitespace();
+            let key = self.decode_string()?;
+            self.skip_whitespace();
+            self.expect(b':')?;
+            self.skip_whitespace();
+            let value = self.decode_value(depth + 1)?;
+            members.push((key, value));
+            self.skip_whitespace();
+
+            match self.next() {
+                Some(b',') => continue,
+                Some(b'}') => break,
+                _ => {
+                    self.position = self.position.saturating_sub(1);
+                    return self.error("expected ',' or '}'");
+                }
+            }
+        }
+
+        Ok(JsonValue::Object(members))
+    }
+
+    fn decode_number(&mut self) -> Result<JsonValue, DecodeError> {
+        let begin = self.position;
+
+        if self.peek() == Some(b'-') {
+            self.position += 1;
+        }
+
+        match self.next() {
+            Some(b'0') => (),
+            Some(b'1'..=b'9') => self.skip_digits(),
+            _ => return self.error("invalid number"),
+        }
+
+        if self.peek() == Some(b'.') {
+            self.position += 1;
+            if !matches!(self.peek(), Some(b'0'..=b'9')) {
+                return self.error("expected digit after decimal point");
+            }
+            self.skip_digits();
+        }
+
+        if let Some(b'e' | b'E') = self.peek() {
+            self.position += 1;
+            if let Some(b'+' | b'-') = self.peek() {
+                self.position += 1;
+            }
+            if !matches!(self.peek(), Some(b'0'..=b'9')) {
+                return self.error("expected digit in exponent");
+            }
+            self.skip_digits();
+        }
+
+        // the slice only contains ascii characters, thus it is valid utf8
+        let text = std::str::from_utf8(&self.bytes[begin..self.position]).unwrap_or_default();
+        match text.parse::<f64>() {
+            Ok(value) => Ok(JsonValue::Number(value)),
+            Err(e) => self.error(format!("invalid number \"{}\": {}", text, e)),
+        }
+    }
+
+    fn skip_digits(&mut self) {
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.position += 1;
+        }
+    }
+
+    fn decode_string(&mut self) -> Result<String, DecodeError> {
+        self.expect(b'"')?;
+
+        let mut bytes = Vec::new();
+        loop {
+            let Some(byte) = self.next() else {
+                return self.error("unterminated string");
+            };
+
+            match byte {
+                b'"' => break,
+                b'\\' => {
+                    let Some(escaped) = self.next() else {
+                        return self.error("unterminated escape sequence");
+                    };
+
+                    match escaped {
+                        b'"' => bytes.push(b'"'),
+                        b'\\' => bytes.push(b'\\'),
+                        b'/' => bytes.push(b'/'),
+                        b'b' => bytes.push(0x08),
+                        b'f' => bytes.push(0x0c),
+                        b'n' => bytes.push(b'\n'),
+                        b'r' => bytes.push(b'\r'),
+                        b't' => bytes.push(b'\t'),
+                        b'u' => {
+                            let c = self.decode_unicode_escape()?;
+                            let mut buffer = [0; 4];
+                            bytes.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+                        }
+                        _ => return self.error("invalid escape sequence"),
+                    }
+                }
+                0x00..=0x1f => return self.error("control characters must be escaped"),
+                _ => bytes.push(byte),
+            }
+        }
+
+        match String::from_utf8(bytes) {
+            Ok(string) => Ok(string),
+            Err(_) => self.error("string is not valid utf8"),
+        }
+    }
+
+    fn decode_unicode_escape(&mut self) -> Result<char, DecodeError> {
+        let high = self.decode_hex4()?;
+
+        let code_point = if (0xd800..0xdc00).contains(&high) {
+            // surrogate pair
+            self.expect(b'\\')?;
+            self.expect(b'u')?;
+            let low = self.decode_hex4()?;
+            if !(0xdc00..0xe000).contains(&low) {
+                return self.error("invalid low surrogate");
+            }
+
+            0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00)
+        } else {
+            high
+        };
+
+        match char::from_u32(code_point) {
+            Some(c) => Ok(c),
+            None => self.error(format!("invalid code point {:#x}", code_point)),
+        }
+    }
+
+    fn decode_hex4(&mut self) -> Result<u32, DecodeError> {
+        let mut value = 0;
+        for _ in 0..4 {
+            let Some(byte) = self.next() else {
+                return self.error("unterminated unicode escape");
+            };
+
+            let Some(digit) = (byte as char).to_digit(16) else {
+                return self.error("invalid hex digit");
+            };
+
+            value = (value << 4) | digit;
+        }
+
+        Ok(value)
+    }
+}
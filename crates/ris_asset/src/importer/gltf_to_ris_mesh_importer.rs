@@ -0,0 +1,609 @@
+use std::path::Path;
+use std::path::PathBuf;
+
+use ris_data::ecs::mesh::Mesh;
+use ris_data::ecs::mesh::Vertex;
+use ris_error::Extensions;
+use ris_error::RisResult;
+use ris_math::affine;
+use ris_math::color::Rgb;
+use ris_math::matrix::Mat4;
+use ris_math::quaternion::Quat;
+use ris_math::vector::Vec2;
+use ris_math::vector::Vec3;
+use ris_math::vector::Vec4;
+
+use crate::assets::ris_mesh;
+use crate::codecs::json;
+use crate::codecs::json::JsonValue;
+
+// glTF 2.0: https://registry.khronos.org/glTF/specs/2.0/glTF-2.0.html
+//
+// only the json variant `.gltf` is supported. buffers are loaded from files relative to the
+// source file or from embedded base64 data uris. nothing is ever downloaded.
+
+pub const IN_EXT: &str = "gltf";
+pub const OUT_EXT: &[&str] = &["ris_mesh"];
+
+const MODE_TRIANGLES: usize = 4;
+
+const COMPONENT_TYPE_UNSIGNED_BYTE: usize = 5121;
+const COMPONENT_TYPE_UNSIGNED_SHORT: usize = 5123;
+const COMPONENT_TYPE_UNSIGNED_INT: usize = 5125;
+const COMPONENT_TYPE_FLOAT: usize = 5126;
+
+pub fn import(source: PathBuf, targets: Vec<PathBuf>) -> RisResult<()> {
+    let text = std::fs::read_to_string(&source)?;
+    let directory = source.parent().unwrap_or(Path::new(""));
+    let mesh = decode(&text, directory)?;
+    let bytes = ris_mesh::serialize(&mesh)?;
+
+    let mut output = crate::asset_importer::create_file(&targets[0])?;
+    ris_io::write(&mut output, &bytes)?;
+
+    Ok(())
+}
+
+/// decodes a gltf document into a single mesh.
+///
+/// all triangle primitives of all nodes in the default scene are merged, with the node
+/// transforms applied. if the document has no scenes, all meshes are merged untransformed.
+/// gltf is y-up, thus positions are converted into the right-handed z-up coordinate system of
+/// the engine. `directory` is used to resolve relative buffer uris.
+pub fn decode(text: &str, directory: &Path) -> RisResult<Mesh> {
+    let root = json::decode(text)?;
+
+    let buffers = load_buffers(&root, directory)?;
+    let document = Document {
+        root: &root,
+        buffers,
+    };
+
+    let mut result = Mesh::default();
+
+    let scenes = root.get("scenes").and_then(JsonValue::as_array);
+    match scenes {
+        Some(scenes) if !scenes.is_empty() => {
+            let scene_index = match root.get("scene") {
+                Some(scene) => scene.as_usize().into_ris_error()?,
+                None => 0,
+            };
+            let scene = scenes.get(scene_index).into_ris_error()?;
+            let nodes = scene
+                .get("nodes")
+                .and_then(JsonValue::as_array)
+                .unwrap_or_default();
+
+            let mut stack = Vec::new();
+            for node in nodes {
+                let node_index = node.as_usize().into_ris_error()?;
+                stack.push((node_index, Mat4::init(1.0), 0));
+            }
+
+            let node_count = document.array("nodes").len();
+            while let Some((node_index, parent_transform, depth)) = stack.pop() {
+                if depth > node_count {
+                    return ris_error::new_result!("node hierarchy contains a cycle");
+                }
+
+                let node = document.element("nodes", node_index)?;
+                let transform = parent_transform * node_transform(node)?;
+
+                if let Some(mesh) = node.get("mesh") {
+                    let mesh_index = mesh.as_usize().into_ris_error()?;
+                    document.append_mesh(mesh_index, transform, &mut result)?;
+                }
+
+                let children = node
+                    .get("children")
+                    .and_then(JsonValue::as_array)
+                    .unwrap_or_default();
+                for child in children {
+                    let child_index = child.as_usize().into_ris_error()?;
+                    stack.push((child_index, transform, depth + 1));
+                }
+            }
+        }
+        _ => {
+            for mesh_index in 0..document.array("meshes").len() {
+                document.append_mesh(mesh_index, Mat4::init(1.0), &mut result)?;
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+struct Document<'a> {
+    root: &'a JsonValue,
+    buffers: Vec<Vec<u8>>,
+}
+
+struct Accessor<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    stride: usize,
+    count: usize,
+    component_type: usize,
+    component_count: usize,
+    normalized: bool,
+}
+
+impl<'a> Document<'a> {
+    fn array(&self, key: &str) -> &'a [JsonValue] {
+        self.root
+            .get(key)
+            .and_then(JsonValue::as_array)
+            .unwrap_or_default()
+    }
+
+    fn element(&self, key: &str, index: usize) -> RisResult<&'a JsonValue> {
+        let array = self.array(key);
+        match array.get(index) {
+            Some(element) => Ok(element),
+            None => ris_error::new_result!(
+                "{} index {} is out of bounds. count: {}",
+                key,
+                index,
+                array.len(),
+            ),
+        }
+    }
+
+    fn append_mesh(&self, mesh_index: usize, transform: Mat4, result: &mut Mesh) -> RisResult<()> {
+        let mesh = self.element("meshes", mesh_index)?;
+        let primitives = mesh
+            .get("primitives")
+            .and_then(JsonValue::as_array)
+            .unwrap_or_default();
+
+        for primitive in primitives {
+            let mode = match primitive.get("mode") {
+                Some(mode) => mode.as_usize().into_ris_error()?,
+                None => MODE_TRIANGLES,
+            };
+
+            if mode != MODE_TRIANGLES {
+                return ris_error::new_result!(
+                    "mesh {}: unsupported primitive mode {}. only triangles are supported",
+                    mesh_index,
+                    mode,
+                );
+            }
+
+            let attributes = primitive.get("attributes").into_ris_error()?;
+            let position_index = attributes.get("POSITION").into_ris_error()?;
+            let positions = self.read_floats(position_index, &[3])?;
+
+            let uvs = match attributes.get("TEXCOORD_0") {
+                Some(index) => Some(self.read_floats(index, &[2])?),
+                None => None,
+            };
+
+            let colors = match attributes.get("COLOR_0") {
+                Some(index) => Some(self.read_floats(index, &[3, 4])?),
+                None => None,
+            };
+
+            let vertex_count = positions.len();
+            if uvs.as_ref().is_some_and(|x| x.len() != vertex_count)
+                || colors.as_ref().is_some_and(|x| x.len() != vertex_count)
+            {
+                return ris_error::new_result!(
+                    "mesh {}: attributes have different counts",
+                    mesh_index
+                );
+            }
+
+            let base = result.vertices.len();
+            for (i, position) in positions.iter().enumerate() {
+                let p = transform * Vec4(position[0], position[1], position[2], 1.0);
+                let pos = Vec3(-p.0, p.2, p.1);
+
+                let color = match &colors {
+                    Some(colors) => Rgb(colors[i][0], colors[i][1], colors[i][2]),
+                    None => Rgb::white(),
+                };
+
+                let uv = match &uvs {
+                    Some(uvs) => Vec2(uvs[i][0], uvs[i][1]),
+                    None => Vec2::default(),
+                };
+
+                result.vertices.push(Vertex { pos, color, uv });
+            }
+
+            match primitive.get("indices") {
+                Some(index) => {
+                    let accessor = self.accessor(index)?;
+                    if accessor.component_count != 1 {
+                        return ris_error::new_result!("indices must be scalars");
+                    }
+
+                    for i in 0..accessor.count {
+                        let index = accessor.read_uint(i, 0)?;
+                        if index >= vertex_count {
+                            return ris_error::new_result!(
+                                "mesh {}: index {} is out of bounds. vertex count: {}",
+                                mesh_index,
+                                index,
+                                vertex_count,
+                            );
+                        }
+
+                        result.indices.push((base + index) as u32);
+                    }
+                }
+                None => {
+                    for i in 0..vertex_count {
+                        result.indices.push((base + i) as u32);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn accessor(&self, index: &JsonValue) -> RisResult<Accessor<'_>> {
+        let accessor_index = index.as_usize().into_ris_error()?;
+        let accessor = self.element("accessors", accessor_index)?;
+
+        if accessor.get("sparse").is_some() {
+            return ris_error::new_result!(
+                "accessor {}: sparse accessors are not supported",
+                accessor_index
+            );
+        }
+
+        let Some(buffer_view_index) = accessor.get("bufferView") else {
+            return ris_error::new_result!(
+                "accessor {}: accessors without buffer view are not supported",
+                accessor_index
+            );
+        };
+        let buffer_view_index = buffer_view_index.as_usize().into_ris_error()?;
+        let buffer_view = self.element("bufferViews", buffer_view_index)?;
+
+        let count = accessor
+            .get("count")
+            .into_ris_error()?
+            .as_usize()
+            .into_ris_error()?;
+        let component_type = accessor
+            .get("componentType")
+            .into_ris_error()?
+            .as_usize()
+            .into_ris_error()?;
+        let normalized = accessor
+            .get("normalized")
+            .and_then(JsonValue::as_bool)
+            .unwrap_or(false);
+        let component_count = match accessor.get("type").and_then(JsonValue::as_str) {
+            Some("SCALAR") => 1,
+            Some("VEC2") => 2,
+            Some("VEC3") => 3,
+            Some("VEC4") => 4,
+            value => {
+                return ris_error::new_result!(
+                    "accessor {}: unsupported type {:?}",
+                    accessor_index,
+                    value,
+                )
+            }
+        };
+
+        let component_size = match component_type {
+            COMPONENT_TYPE_UNSIGNED_BYTE => 1,
+            COMPONENT_TYPE_UNSIGNED_SHORT => 2,
+            COMPONENT_TYPE_UNSIGNED_INT | COMPONENT_TYPE_FLOAT => 4,
+            _ => {
+                return ris_error::new_result!(
+                    "accessor {}: unsupported component type {}",
+                    accessor_index,
+                    component_type,
+                )
+            }
+        };
+        let element_size = component_size * component_count;
+
+        let buffer_index = buffer_view
+            .get("buffer")
+            .into_ris_error()?
+            .as_usize()
+            .into_ris_error()?;
+        let buffer = match self.buffers.get(buffer_index) {
+            Some(buffer) => buffer,
+            None => {
+                return ris_error::new_result!("buffer index {} is out of bounds", buffer_index)
+            }
+        };
+
+        let view_offset = optional_usize(buffer_view, "byteOffset")?;
+        let view_length = buffer_view
+            .get("byteLength")
+            .into_ris_error()?
+            .as_usize()
+            .into_ris_error()?;
+        let stride = match buffer_view.get("byteStride") {
+            Some(stride) => stride.as_usize().into_ris_error()?,
+            None => element_size,
+        };
+
+        let view_end = view_offset.checked_add(view_length).into_ris_error()?;
+        if view_end > buffer.len() {
+            return ris_error::new_result!(
+                "buffer view {} is out of bounds of buffer {}",
+                buffer_view_index,
+                buffer_index,
+            );
+        }
+
+        let bytes = &buffer[view_offset..view_end];
+        let offset = optional_usize(accessor, "byteOffset")?;
+
+        if count > 0 {
+            let required = stride
+                .checked_mul(count - 1)
+                .and_then(|x| x.checked_add(offset))
+                .and_then(|x| x.checked_add(element_size))
+                .into_ris_error()?;
+
+            if required > bytes.len() {
+                return ris_error::new_result!(
+                    "accessor {} is out of bounds of buffer view {}",
+                    accessor_index,
+                    buffer_view_index,
+                );
+            }
+        }
+
+        Ok(Accessor {
+            bytes,
+            offset,
+            stride,
+            count,
+            component_type,
+            component_count,
+            normalized,
+        })
+    }
+
+    fn read_floats(
+        &self,
+        index: &JsonValue,
+        allowed_component_counts: &[usize],
+    ) -> RisResult<Vec<[f32; 4]>> {
+        let accessor = self.accessor(index)?;
+        if !allowed_component_counts.contains(&accessor.component_count) {
+            return ris_error::new_result!(
+                "unexpected number of components: {}",
+                accessor.component_count
+            );
+        }
+
+        let mut result = Vec::with_capacity(accessor.count);
+        for i in 0..accessor.count {
+            let mut value = [0.0; 4];
+            for (j, component) in value.iter_mut().enumerate().take(accessor.component_count) {
+                *component = accessor.read_float(i, j)?;
+            }
+
+            result.push(value);
+        }
+
+        Ok(result)
+    }
+}
+
+impl Accessor<'_> {
+    fn component_bytes(&self, element: usize, component: usize, size: usize) -> &[u8] {
+        let begin = self.offset + element * self.stride + component * size;
+        &self.bytes[begin..begin + size]
+    }
+
+    fn read_uint(&self, element: usize, component: usize) -> RisResult<usize> {
+        let value = match self.component_type {
+            COMPONENT_TYPE_UNSIGNED_BYTE => self.component_bytes(element, component, 1)[0] as usize,
+            COMPONENT_TYPE_UNSIGNED_SHORT => {
+                let bytes = self.component_bytes(element, component, 2);
+                u16::from_le_bytes([bytes[0], bytes[1]]) as usize
+            }
+            COMPONENT_TYPE_UNSIGNED_INT => {
+                let bytes = self.component_bytes(element, component, 4);
+                u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize
+            }
+            component_type => {
+                return ris_error::new_result!(
+                    "component type {} is not an unsigned integer",
+                    component_type
+                )
+            }
+        };
+
+        Ok(value)
+    }
+
+    fn read_float(&self, element: usize, component: usize) -> RisResult<f32> {
+        if self.component_type == COMPONENT_TYPE_FLOAT {
+            let bytes = self.component_bytes(element, component, 4);
+            return Ok(f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]));
+        }
+
+        if !self.normalized {
+            return ris_error::new_result!("integer attributes must be normalized");
+        }
+
+        let max = match self.component_type {
+            COMPONENT_TYPE_UNSIGNED_BYTE => u8::MAX as f32,
+            COMPONENT_TYPE_UNSIGNED_SHORT => u16::MAX as f32,
+            component_type => {
+                return ris_error::new_result!(
+                    "unsupported normalized component type {}",
+                    component_type
+                )
+            }
+        };
+
+        let value = self.read_uint(element, component)? as f32;
+        Ok(value / max)
+    }
+}
+
+fn optional_usize(value: &JsonValue, key: &str) -> RisResult<usize> {
+    match value.get(key) {
+        Some(value) => value.as_usize().into_ris_error(),
+        None => Ok(0),
+    }
+}
+
+fn node_transform(node: &JsonValue) -> RisResult<Mat4> {
+    if let Some(matrix) = node.get("matrix") {
+        let values = read_f32_array::<16>(matrix)?;
+
+        // column-major
+        let column = |i: usize| Vec4(values[i], values[i + 1], values[i + 2], values[i + 3]);
+        return Ok(Mat4(column(0), column(4), column(8), column(12)));
+    }
+
+    let t = match node.get("translation") {
+        Some(value) => {
+            let [x, y, z] = read_f32_array::<3>(value)?;
+            Vec3(x, y, z)
+        }
+        None => Vec3::default(),
+    };
+
+    let r = match node.get("rotation") {
+        Some(value) => {
+            let [x, y, z, w] = read_f32_array::<4>(value)?;
+            Quat(x, y, z, w)
+        }
+        None => Quat::identity(),
+    };
+
+    let s = match node.get("scale") {
+        Some(value) => {
+            let [x, y, z] = read_f32_array::<3>(value)?;
+            Vec3(x, y, z)
+        }
+        None => Vec3(1.0, 1.0, 1.0),
+    };
+
+    let t = affine::from_translation(t);
+    let r = Mat4::from(affine::from_rotation(r));
+    let s = Mat4::from(affine::from_scale(s));
+
+    Ok(t * r * s)
+}
+
+fn read_f32_array<const N: usize>(value: &JsonValue) -> RisResult<[f32; N]> {
+    let array = value.as_array().into_ris_error()?;
+    if array.len() != N {
+        return ris_error::new_result!("expected {} numbers but found {}", N, array.len());
+    }
+
+    let mut result = [0.0; N];
+    for (target, value) in result.iter_mut().zip(array) {
+        *target = value.as_f32().into_ris_error()?;
+    }
+
+    Ok(result)
+}
+
+fn load_buffers(root: &JsonValue, directory: &Path) -> RisResult<Vec<Vec<u8>>> {
+    let buffers = root
+        .get("buffers")
+        .and_then(JsonValue::as_array)
+        .unwrap_or_default();
+
+    let mut result = Vec::with_capacity(buffers.len());
+    for (i, buffer) in buffers.iter().enumerate() {
+        let Some(uri) = buffer.get("uri").and_then(JsonValue::as_str) else {
+            return ris_error::new_result!(
+                "buffer {}: buffers without uri (glb) are not supported",
+                i
+            );
+        };
+
+        let bytes = if let Some(data) = uri.strip_prefix("data:") {
+            let Some((_, encoded)) = data.split_once(";base64,") else {
+                return ris_error::new_result!("buffer {}: data uri is not base64 encoded", i);
+            };
+
+            decode_base64(encoded)?
+        } else if uri.contains("://") {
+            return ris_error::new_result!(
+                "buffer {}: only relative file uris are supported. uri: {}",
+                i,
+                uri,
+            );
+        } else {
+            let path = directory.join(decode_percent(uri)?);
+            std::fs::read(&path)?
+        };
+
+        let byte_length = optional_usize(buffer, "byteLength")?;
+        if bytes.len() < byte_length {
+            return ris_error::new_result!(
+                "buffer {}: expected {} bytes but found {}",
+                i,
+                byte_length,
+                bytes.len(),
+            );
+        }
+
+        result.push(bytes);
+    }
+
+    Ok(result)
+}
+
+fn decode_percent(uri: &str) -> RisResult<String> {
+    let bytes = uri.as_bytes();
+    let mut result = Vec::with_capacity(bytes.len());
+
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = uri.get(i + 1..i + 3).into_ris_error()?;
+            let value = u8::from_str_radix(hex, 16)?;
+            result.push(value);
+            i += 3;
+        } else {
+            result.push(bytes[i]);
+            i += 1;
+        }
+    }
+
+    let string = String::from_utf8(result)?;
+    Ok(string)
+}
+
+fn decode_base64(encoded: &str) -> RisResult<Vec<u8>> {
+    let mut result = Vec::with_capacity(encoded.len() / 4 * 3);
+    let mut accumulator = 0u32;
+    let mut bits = 0;
+
+    for c in encoded.bytes() {
+        let value = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            b'=' => break,
+            _ => return ris_error::new_result!("invalid base64 character '{}'", c as char),
+        };
+
+        accumulator = (accumulator << 6) | value as u32;
+        bits += 6;
+
+        if bits >= 8 {
+            bits -= 8;
+            result.push((accumulator >> bits) as u8);
+        }
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use ris_data::ecs::mesh::Mesh;
+use ris_data::ecs::mesh::Vertex;
+use ris_error::RisResult;
+use ris_math::color::Rgb;
+use ris_math::vector::Vec2;
+use ris_math::vector::Vec3;
+
+use crate::assets::ris_mesh;
+
+pub const IN_EXT: &str = "obj";
+pub const OUT_EXT: &[&str] = &["ris_mesh"];
+
+pub fn import(source: PathBuf, targets: Vec<PathBuf>) -> RisResult<()> {
+    let text = std::fs::read_to_string(&source)?;
+    let mesh = decode(&text)?;
+    let bytes = ris_mesh::serialize(&mesh)?;
+
+    let mut output = crate::asset_importer::create_file(&targets[0])?;
+    ris_io::write(&mut output, &bytes)?;
+
+    Ok(())
+}
+
+/// decodes a wavefront obj into a single mesh.
+///
+/// all objects and groups are merged. normals, materials and smoothing groups are ignored.
+/// faces with more than 3 vertices are triangulated as a fan. obj is y-up, thus positions are
+/// converted into the right-handed z-up coordinate system of the engine.
+pub fn decode(text: &str) -> RisResult<Mesh> {
+    let mut positions = Vec::new();
+    let mut colors = Vec::new();
+    let mut uvs = Vec::new();
+
+    let mut vertices = Vec::new();
+    let mut indices = Vec::new();
+    let mut lookup = HashMap::<(usize, Option<usize>), u32>::new();
+
+    for (i, line) in text.lines().enumerate() {
+        let line_number = i + 1;
+
+        let line = match line.find('#') {
+            Some(comment) => &line[..comment],
+            None => line,
+        };
+
+        let mut tokens = line.split_whitespace();
+        let Some(keyword) = tokens.next() else {
+            continue;
+        };
+
+        match keyword {
+            "v" => {
+                let values = parse_floats(tokens, line_number)?;
+                match values.len() {
+                    3 | 4 => colors.push(Rgb::white()),
+                    6 | 7 => colors.push(Rgb(values[3], values[4], values[5])),
+                    count => {
+                        return ris_error::new_result!(
+                            "line {}: vertex has an invalid number of components: {}",
+                            line_number,
+                            count,
+                        )
+                    }
+                }
+
+                let Vec3(x, y, z) = Vec3(values[0], values[1], values[2]);
+                positions.push(Vec3(x, -z, y));
+            }
+            "vt" => {
+                let values = parse_floats(tokens, line_number)?;
+                if values.is_empty() || values.len() > 3 {
+                    return ris_error::new_result!(
+                        "line {}: texture coordinate has an invalid number of components: {}",
+                        line_number,
+                        values.len(),
+                    );
+                }
+
+                let u = values[0];
+                let v = values.get(1).copied().unwrap_or(0.0);
+                uvs.push(Vec2(u, 1.0 - v));
+            }
+            "f" => {
+                let mut face = Vec::new();
+                for token in tokens {
+                    let mut parts = token.split('/');
+                    let position_part = parts.next().unwrap_or_default();
+                    let uv_part = parts.next().filter(|x| !x.is_empty());
+
+                    let position_index =
+                        resolve_index(position_part, positions.len(), line_number)?;
+                    let uv_index = match uv_part {
+                        Some(uv_part) => Some(resolve_index(uv_part, uvs.len(), line_number)?),
+                        None => None,
+                    };
+
+                    let key = (position_index, uv_index);
+                    let index = match lookup.get(&key) {
+                        Some(&index) => index,
+                        None => {
+                            let index = vertices.len() as u32;
+                            vertices.push(Vertex {
+                                pos: positions[position_index],
+                                color: colors[position_index],
+                                uv: uv_index.map(|x| uvs[x]).unwrap_or_default(),
+                            });
+                            lookup.insert(key, index);
+                            index
+                        }
+                    };
+
+                    face.push(index);
+                }
+
+                if face.len() < 3 {
+                    return ris_error::new_result!(
+                        "line {}: face must have at least 3 vertices, but has {}",
+                        line_number,
+                        face.len(),
+                    );
+                }
+
+                for j in 1..face.len() - 1 {
+                    indices.push(face[0]);
+                    indices.push(face[j]);
+                    indices.push(face[j + 1]);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    Ok(Mesh { vertices, indices })
+}
+
+fn parse_floats<'a>(
+    tokens: impl Iterator<Item = &'a str>,
+    line_number: usize,
+) -> RisResult<Vec<f32>> {
+    let mut result = Vec::new();
+    for token in tokens {
+        match token.parse::<f32>() {
+            Ok(value) => result.push(value),
+            Err(e) => {
+                return ris_error::new_result!(
+                    "line {}: failed to parse \"{}\": {}",
+                    line_number,
+                    token,
+                    e,
+                )
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+// obj indices are 1-based. negative indices are relative to the end of the list
+fn resolve_index(token: &str, count: usize, line_number: usize) -> RisResult<usize> {
+    let index = match token.parse::<isize>() {
+        Ok(index) => index,
+        Err(e) => {
+            return ris_error::new_result!(
+                "line {}: failed to parse index \"{}\": {}",
+                line_number,
+                token,
+                e,
+            )
+        }
+    };
+
+    let resolved = if index > 0 {
+        index - 1
+    } else {
+        count as isize + index
+    };
+
+    if index == 0 || resolved < 0 || resolved >= count as isize {
+        return ris_error::new_result!(
+            "line {}: index {} is out of bounds. count: {}",
+            line_number,
+            index,
+            count,
+        );
+    }
+
+    Ok(resolved as usize)
+}
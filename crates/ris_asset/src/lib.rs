@@ -2,11 +2,15 @@ pub mod assets;
 pub mod codecs;
 pub mod importer;
 
+pub mod asset_cache;
 pub mod asset_compiler;
+pub mod asset_graph;
 pub mod asset_importer;
 pub mod asset_loader;
 pub mod asset_loader_compiled;
 pub mod asset_loader_directory;
+pub mod asset_verifier;
+pub mod asset_watcher;
 
 pub mod util;
 
@@ -0,0 +1,272 @@
+use ris_asset::asset_loader;
+use ris_asset::asset_loader::LoadError;
+use ris_asset::assets::ris_scene;
+use ris_data::asset_id::AssetId;
+use ris_data::ecs::scene::Scene;
+use ris_error::RisResult;
+use ris_jobs::job_future::JobFuture;
+use ris_math::vector::Vec3;
+
+pub const DEFAULT_LOAD_DISTANCE: f32 = 100.0;
+pub const DEFAULT_UNLOAD_DISTANCE: f32 = 120.0;
+pub const DEFAULT_LOADS_PER_FRAME: usize = 1;
+
+pub type ChunkLoader = Box<dyn Fn(AssetId) -> JobFuture<Result<Vec<u8>, LoadError>>>;
+
+/// a `ris_scene` asset and the world space bounds of its content
+#[derive(Debug, Clone)]
+pub struct StreamedChunk {
+    pub asset_id: AssetId,
+    pub min: Vec3,
+    pub max: Vec3,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChunkState {
+    Unloaded,
+    Loading,
+    /// the asset is loaded, but all static chunks are in use
+    WaitingForChunk,
+    Loaded {
+        chunk: usize,
+    },
+    Failed,
+}
+
+/// loads static chunks when the camera approaches them, and clears them when it moves away.
+///
+/// chunks are loaded when the camera is closer than `load_distance` to their bounds, and cleared
+/// when it is farther away than `unload_distance`. `unload_distance` should be larger than
+/// `load_distance`, to prevent chunks from being loaded and cleared repeatedly when the camera
+/// moves along the border. at most `loads_per_frame` chunks are deserialized into the scene per
+/// frame. chunks that failed to load are not attempted again. when all static chunks are in use, a
+/// loaded asset is kept and deserialized once a chunk is cleared.
+pub struct ChunkStreamer {
+    pub load_distance: f32,
+    pub unload_distance: f32,
+    pub loads_per_frame: usize,
+    loader: ChunkLoader,
+    entries: Vec<Entry>,
+}
+
+struct Entry {
+    chunk: StreamedChunk,
+    state: ChunkState,
+    future: Option<JobFuture<Result<Vec<u8>, LoadError>>>,
+    bytes: Option<Vec<u8>>,
+}
+
+impl Default for ChunkStreamer {
+    fn default() -> Self {
+        Self::new(Box::new(asset_loader::load_async))
+    }
+}
+
+impl ChunkStreamer {
+    /// `loader` requests the bytes of an asset. usually this is `asset_loader::load_async`
+    pub fn new(loader: ChunkLoader) -> Self {
+        Self {
+            load_distance: DEFAULT_LOAD_DISTANCE,
+            unload_distance: DEFAULT_UNLOAD_DISTANCE,
+            loads_per_frame: DEFAULT_LOADS_PER_FRAME,
+            loader,
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn add(&mut self, chunk: StreamedChunk) -> RisResult<()> {
+        let Vec3(min_x, min_y, min_z) = chunk.min;
+        let Vec3(max_x, max_y, max_z) = chunk.max;
+        if min_x > max_x || min_y > max_y || min_z > max_z {
+            return ris_error::new_result!(
+                "invalid bounds. min: {:?} max: {:?}",
+                chunk.min,
+                chunk.max,
+            );
+        }
+
+        if self
+            .entries
+            .iter()
+            .any(|x| x.chunk.asset_id == chunk.asset_id)
+        {
+            return ris_error::new_result!("chunk {:?} was already added", chunk.asset_id);
+        }
+
+        self.entries.push(Entry {
+            chunk,
+            state: ChunkState::Unloaded,
+            future: None,
+            bytes: None,
+        });
+
+        Ok(())
+    }
+
+    /// stops streaming the given chunk. clears it, if it is loaded.
+    pub fn remove(&mut self, scene: &Scene, asset_id: &AssetId) {
+        let Some(position) = self
+            .entries
+            .iter()
+            .position(|x| x.chunk.asset_id == *asset_id)
+        else {
+            return;
+        };
+
+        let entry = self.entries.remove(position);
+        if let ChunkState::Loaded { chunk } = entry.state {
+            scene.clear_chunk(chunk);
+        }
+    }
+
+    pub fn state(&self, asset_id: &AssetId) -> Option<ChunkState> {
+        self.entries
+            .iter()
+            .find(|x| x.chunk.asset_id == *asset_id)
+            .map(|x| x.state)
+    }
+
+    pub fn update(&mut self, scene: &Scene, camera_position: Vec3) -> RisResult<()> {
+        let distances = self
+            .entries
+            .iter()
+            .map(|x| distance_to_bounds(camera_position, x.chunk.min, x.chunk.max))
+            .collect::<Vec<_>>();
+
+        // clear chunks that are out of range
+        for (entry, &distance) in self.entries.iter_mut().zip(distances.iter()) {
+            let ChunkState::Loaded { chunk } = entry.state else {
+                continue;
+            };
+
+            if distance > self.unload_distance {
+                ris_log::debug!("unload chunk {} {:?}", chunk, entry.chunk.asset_id);
+                scene.clear_chunk(chunk);
+                entry.state = ChunkState::Unloaded;
+            }
+        }
+
+        // process chunks nearest to the camera first
+        let mut order = (0..self.entries.len()).collect::<Vec<_>>();
+        order.sort_by(|&a, &b| distances[a].total_cmp(&distances[b]));
+
+        let mut loads = 0;
+        for i in order {
+            let distance = distances[i];
+            let entry = &mut self.entries[i];
+
+            match entry.state {
+                ChunkState::Unloaded => {
+                    if distance <= self.load_distance {
+                        entry.future = Some((self.loader)(entry.chunk.asset_id.clone()));
+                        entry.state = ChunkState::Loading;
+                    }
+                }
+                ChunkState::Loading => {
+                    if loads >= self.loads_per_frame {
+                        continue;
+                    }
+
+                    let Some(future) = entry.future.take() else {
+                        entry.state = ChunkState::Unloaded;
+                        continue;
+                    };
+
+                    let result = match future.try_take() {
+                        Ok(result) => result,
+                        Err(future) => {
+                            entry.future = Some(future);
+                            continue;
+                        }
+                    };
+
+                    // the camera may have moved away while the chunk was loading
+                    if distance > self.unload_distance {
+                        entry.state = ChunkState::Unloaded;
+                        continue;
+                    }
+
+                    let bytes = match result {
+                        Ok(bytes) => bytes,
+                        Err(e) => {
+                            ris_log::error!(
+                                "failed to load chunk {:?}: {}",
+                                entry.chunk.asset_id,
+                                e
+                            );
+                            entry.state = ChunkState::Failed;
+                            continue;
+                        }
+                    };
+
+                    if entry.deserialize(scene, bytes) {
+                        loads += 1;
+                    } else {
+                        ris_log::warning!(
+                            "chunk {:?} waits, because no static chunk is available",
+                            entry.chunk.asset_id,
+                        );
+                    }
+                }
+                ChunkState::WaitingForChunk => {
+                    if distance > self.unload_distance {
+                        entry.bytes = None;
+                        entry.state = ChunkState::Unloaded;
+                        continue;
+                    }
+
+                    if loads >= self.loads_per_frame {
+                        continue;
+                    }
+
+                    let Some(bytes) = entry.bytes.take() else {
+                        entry.state = ChunkState::Unloaded;
+                        continue;
+                    };
+
+                    if entry.deserialize(scene, bytes) {
+                        loads += 1;
+                    }
+                }
+                ChunkState::Loaded { .. } | ChunkState::Failed => (),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Entry {
+    /// returns false, if no static chunk is available. the bytes are kept in that case, such that
+    /// they can be deserialized once a chunk is cleared
+    fn deserialize(&mut self, scene: &Scene, bytes: Vec<u8>) -> bool {
+        match ris_scene::load(scene, &bytes) {
+            Ok(Some(chunk)) => {
+                ris_log::debug!("loaded chunk {} {:?}", chunk, self.chunk.asset_id);
+                self.state = ChunkState::Loaded { chunk };
+                true
+            }
+            Ok(None) => {
+                self.bytes = Some(bytes);
+                self.state = ChunkState::WaitingForChunk;
+                false
+            }
+            Err(e) => {
+                ris_log::error!(
+                    "failed to deserialize chunk {:?}: {}",
+                    self.chunk.asset_id,
+                    e
+                );
+                self.state = ChunkState::Failed;
+                true
+            }
+        }
+    }
+}
+
+/// distance between `position` and the closest point inside the bounds. 0 if `position` lies
+/// inside the bounds.
+pub fn distance_to_bounds(position: Vec3, min: Vec3, max: Vec3) -> f32 {
+    let closest = position.clamp(min, max);
+    position.distance(closest)
+}
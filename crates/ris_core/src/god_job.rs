@@ -1,8 +1,13 @@
+use std::sync::Arc;
+
+use ris_data::ecs::components::script::DynScriptComponent;
 use ris_data::ecs::script_prelude::*;
 use ris_data::gameloop::gameloop_state::GameloopState;
 use ris_jobs::job_system;
 
 use crate::god_object::GodObject;
+use crate::script_scheduler::ScriptPhase;
+use crate::script_scheduler::ScriptSchedule;
 
 pub enum WantsTo {
     Quit,
@@ -15,6 +20,7 @@ pub fn run(mut god_object: GodObject) -> RisResult<WantsTo> {
     loop {
         ris_debug::profiler::new_frame()?;
         let frame = frame_calculator.bump_and_create_frame();
+        let frame = god_object.logic_frame.next_frame(frame)?;
 
         // reset events
         let mut r = ris_debug::new_record!("main loop");
@@ -42,13 +48,38 @@ pub fn run(mut god_object: GodObject) -> RisResult<WantsTo> {
         ris_debug::add_record!(r, "logic frame")?;
         let logic_result = god_object.logic_frame.run(frame, &mut god_object.state);
 
-        for script in god_object.state.scene.script_components.iter() {
-            let mut aref_mut = script.borrow_mut();
-            if aref_mut.is_alive {
-                aref_mut.update(frame, &god_object.state)?;
-            }
+        ris_debug::add_record!(r, "schedule scripts")?;
+        let script_state = Arc::new(god_object.state.clone());
+        let script_schedule = ScriptSchedule::build(&script_state.scene)?;
+
+        ris_debug::add_record!(r, "fixed update scripts")?;
+        let steps = god_object.fixed_timestep.advance(frame.previous_duration());
+        let timestep = god_object.fixed_timestep.timestep();
+        for _ in 0..steps {
+            script_schedule.run(ScriptPhase::FixedUpdate { timestep }, frame, &script_state)?;
         }
 
+        ris_debug::add_record!(r, "update scripts")?;
+        script_schedule.run(ScriptPhase::Update, frame, &script_state)?;
+
+        ris_debug::add_record!(r, "late update scripts")?;
+        script_schedule.run(ScriptPhase::LateUpdate, frame, &script_state)?;
+        drop(script_state);
+
+        ris_debug::add_record!(r, "play back commands")?;
+        god_object.state.commands.playback(&god_object.state.scene);
+
+        ris_debug::add_record!(r, "notify scripts")?;
+        god_object.state.scene.notify_scripts();
+
+        ris_debug::add_record!(r, "dispatch events")?;
+        god_object.state.events.dispatch(&god_object.state)?;
+
+        ris_debug::add_record!(r, "chunk streaming")?;
+        god_object
+            .chunk_streamer
+            .update(&god_object.state.scene, god_object.state.camera.position)?;
+
         ris_debug::add_record!(r, "output frame")?;
         let output_result =
             god_object
@@ -63,6 +94,13 @@ pub fn run(mut god_object: GodObject) -> RisResult<WantsTo> {
         ris_debug::add_record!(r, "update buffers")?;
         god_object.settings_serializer = new_settings_serializer;
 
+        // apply input bindings
+        if god_object.state.settings.input().changed() {
+            ris_log::debug!("input bindings changed");
+            let state = &mut god_object.state;
+            state.settings.input().apply(&mut state.input);
+        }
+
         // restart job system
         ris_debug::add_record!(r, "restart job system")?;
 
@@ -113,11 +151,12 @@ pub fn run(mut god_object: GodObject) -> RisResult<WantsTo> {
         };
 
         // shutdown
-        for script in god_object.state.scene.script_components.iter() {
-            let mut aref_mut = script.borrow_mut();
-            if aref_mut.is_alive {
-                aref_mut.end(&god_object.state.scene)?;
-            }
+        for (_, script) in god_object.state.scene.query::<DynScriptComponent>()? {
+            let Ok(ptr) = god_object.state.scene.deref(script) else {
+                continue;
+            };
+
+            ptr.borrow_mut().end(&god_object.state.scene)?;
         }
 
         god_object.output_frame.wait_idle()?;
@@ -1,10 +1,9 @@
-use sdl2::keyboard::Scancode;
-
 use ris_asset::asset_loader;
 use ris_asset::asset_loader::AssetLoaderGuard;
 use ris_asset::RisGodAsset;
 use ris_data::ecs::registry::Registry;
 use ris_data::ecs::scene::SceneCreateInfo;
+use ris_data::gameloop::fixed_timestep::FixedTimestep;
 use ris_data::gameloop::frame::FrameCalculator;
 use ris_data::god_state::GodState;
 use ris_data::info::app_info::AppInfo;
@@ -22,9 +21,12 @@ use ris_video_renderers::SceneRenderer;
 #[cfg(feature = "ui_helper_enabled")]
 use ris_video_renderers::{ImguiBackend, ImguiRenderer};
 
+use crate::chunk_streamer::ChunkStreamer;
+use crate::logic_frame::InputMode;
 use crate::logic_frame::LogicFrame;
 use crate::output_frame::OutputFrame;
 use crate::output_frame::Renderer;
+use crate::output_frame::RendererAssets;
 #[cfg(feature = "ui_helper_enabled")]
 use crate::ui_helper::UiHelper;
 
@@ -49,8 +51,10 @@ pub struct GodObject {
     pub app_info: AppInfo,
     pub settings_serializer: SettingsSerializer,
     pub frame_calculator: FrameCalculator,
+    pub fixed_timestep: FixedTimestep,
     pub logic_frame: LogicFrame,
     pub output_frame: OutputFrame,
+    pub chunk_streamer: ChunkStreamer,
     pub god_asset: RisGodAsset,
     pub state: GodState,
 
@@ -158,7 +162,13 @@ impl GodObject {
         };
 
         // logic frame
-        let logic_frame = LogicFrame::new(event_pump, sdl_context.keyboard(), controller_subsystem);
+        let input_mode = InputMode::from_args(&app_info.args)?;
+        let logic_frame = LogicFrame::new(
+            event_pump,
+            sdl_context.keyboard(),
+            controller_subsystem,
+            input_mode,
+        );
 
         // output frame
         #[cfg(feature = "ui_helper_enabled")]
@@ -172,9 +182,19 @@ impl GodObject {
             imgui: imgui_renderer,
         };
 
+        // hot reload
+        let asset_path = app_info.asset_path()?;
+        let asset_path = std::path::Path::new(&asset_path);
+        let renderer_assets = if asset_path.is_dir() {
+            Some(RendererAssets::new(asset_path, &god_asset)?)
+        } else {
+            None
+        };
+
         let output_frame = OutputFrame {
             current_frame: 0,
             renderer,
+            assets: renderer_assets,
             #[cfg(feature = "ui_helper_enabled")]
             imgui_backend,
             #[cfg(feature = "ui_helper_enabled")]
@@ -184,6 +204,7 @@ impl GodObject {
         };
 
         let frame_calculator = FrameCalculator::default();
+        let fixed_timestep = FixedTimestep::default();
 
         // god state
         let scene_create_info = SceneCreateInfo {
@@ -192,30 +213,17 @@ impl GodObject {
         };
         let mut state = GodState::new(settings, scene_create_info)?;
 
-        {
-            let input = &mut state.input;
-            input.keyboard.keymask[0] = Scancode::Return;
-            input.keyboard.keymask[15] = Scancode::W;
-            input.keyboard.keymask[16] = Scancode::S;
-            input.keyboard.keymask[17] = Scancode::A;
-            input.keyboard.keymask[18] = Scancode::D;
-            input.keyboard.keymask[19] = Scancode::Up;
-            input.keyboard.keymask[20] = Scancode::Down;
-            input.keyboard.keymask[21] = Scancode::Left;
-            input.keyboard.keymask[22] = Scancode::Right;
-            input.keyboard.keymask[28] = Scancode::Kp8;
-            input.keyboard.keymask[29] = Scancode::Kp2;
-            input.keyboard.keymask[30] = Scancode::Kp4;
-            input.keyboard.keymask[31] = Scancode::Kp6;
-        }
+        state.settings.input().apply(&mut state.input);
 
         // god object
         let god_object = GodObject {
             app_info,
             settings_serializer,
             frame_calculator,
+            fixed_timestep,
             logic_frame,
             output_frame,
+            chunk_streamer: ChunkStreamer::default(),
             god_asset,
             state,
 
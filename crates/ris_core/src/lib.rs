@@ -1,8 +1,10 @@
+pub mod chunk_streamer;
 pub mod god_job;
 pub mod god_object;
 pub mod inspector_util;
 pub mod logic_frame;
 pub mod output_frame;
+pub mod script_scheduler;
 
 pub mod log_appenders;
 
@@ -1,4 +1,5 @@
 use std::f32::consts::PI;
+use std::path::Path;
 
 use sdl2::event::Event;
 use sdl2::event::WindowEvent;
@@ -10,47 +11,116 @@ use sdl2::GameControllerSubsystem;
 use ris_data::gameloop::frame::Frame;
 use ris_data::gameloop::gameloop_state::GameloopState;
 use ris_data::god_state::GodState;
+use ris_data::info::args_info::ArgsInfo;
 use ris_data::input::action;
 use ris_error::RisResult;
+use ris_input::action_map;
+use ris_input::action_map::ActionMap;
 use ris_input::gamepad_logic::GamepadLogic;
 use ris_input::general_logic::update_general;
+use ris_input::gesture_logic::update_gestures;
+use ris_input::input_recording::InputRecorder;
+use ris_input::input_recording::InputReplay;
+use ris_input::input_recording::RecordedFrame;
 use ris_input::keyboard_logic;
 use ris_input::mouse_logic;
 use ris_math::quaternion::Quat;
 use ris_math::vector::Vec3;
 
+pub enum InputMode {
+    Live,
+    Record(InputRecorder),
+    Replay {
+        replay: InputReplay,
+        pending: Option<Box<RecordedFrame>>,
+    },
+}
+
 pub struct LogicFrame {
     // input
     event_pump: EventPump,
     keyboard_util: KeyboardUtil,
     gamepad_logic: GamepadLogic,
+    input_mode: InputMode,
+    action_map: ActionMap,
 
     // camera
     camera_horizontal_angle: f32,
     camera_vertical_angle: f32,
 }
 
+impl InputMode {
+    pub fn from_args(args: &ArgsInfo) -> RisResult<Self> {
+        if let Some(path) = &args.replay_input {
+            let replay = InputReplay::new(Path::new(path))?;
+            return Ok(Self::Replay {
+                replay,
+                pending: None,
+            });
+        }
+
+        if let Some(path) = &args.record_input {
+            let recorder = InputRecorder::new(Path::new(path))?;
+            return Ok(Self::Record(recorder));
+        }
+
+        Ok(Self::Live)
+    }
+}
+
 impl LogicFrame {
     pub fn new(
         event_pump: EventPump,
         keyboard_util: KeyboardUtil,
         controller_subsystem: GameControllerSubsystem,
+        input_mode: InputMode,
     ) -> Self {
         Self {
             event_pump,
             keyboard_util,
             gamepad_logic: GamepadLogic::new(controller_subsystem),
+            input_mode,
+            action_map: ActionMap::new_default(),
 
             camera_horizontal_angle: 0.,
             camera_vertical_angle: 0.,
         }
     }
 
+    pub fn action_map(&self) -> &ActionMap {
+        &self.action_map
+    }
+
+    pub fn action_map_mut(&mut self) -> &mut ActionMap {
+        &mut self.action_map
+    }
+
+    /// when replaying, the durations of the recorded frame replace the durations of `frame`, such
+    /// that everything driven by frame durations behaves like during the recording
+    pub fn next_frame(&mut self, frame: Frame) -> RisResult<Frame> {
+        let InputMode::Replay { replay, pending } = &mut self.input_mode else {
+            return Ok(frame);
+        };
+
+        match replay.next_frame()? {
+            Some((recorded_frame, recorded)) => {
+                *pending = Some(Box::new(recorded));
+                Ok(recorded_frame)
+            }
+            None => {
+                *pending = None;
+                Ok(frame)
+            }
+        }
+    }
+
     pub fn run(&mut self, frame: Frame, state: &mut GodState) -> RisResult<GameloopState> {
         // input
         mouse_logic::pre_events(&mut state.input.mouse);
         keyboard_logic::pre_events(&mut state.input.keyboard);
 
+        let is_replaying = matches!(self.input_mode, InputMode::Replay { .. });
+
         for event in self.event_pump.poll_iter() {
             if let Event::Quit { .. } = event {
                 return Ok(GameloopState::WantsToQuit);
@@ -65,22 +135,52 @@ impl LogicFrame {
                 ris_log::trace!("window changed size to {}x{}", w, h);
             }
 
+            if is_replaying {
+                continue;
+            }
+
             mouse_logic::handle_event(&mut state.input.mouse, &event);
             keyboard_logic::handle_event(&mut state.input.keyboard, &event);
             self.gamepad_logic.handle_event(&event);
         }
 
-        mouse_logic::post_events(&mut state.input.mouse, self.event_pump.mouse_state());
+        match &mut self.input_mode {
+            InputMode::Replay { pending, .. } => {
+                let Some(recorded) = pending.take() else {
+                    ris_log::info!("input replay finished");
+                    return Ok(GameloopState::WantsToQuit);
+                };
 
-        keyboard_logic::post_events(
-            &mut state.input.keyboard,
-            self.event_pump.keyboard_state(),
-            self.keyboard_util.mod_state(),
-        );
+                recorded.apply(&mut state.input);
 
-        self.gamepad_logic.post_events(&mut state.input.gamepad);
+                // replayed players have no controllers to rumble
+                state.rumble.take(0);
+            }
+            input_mode => {
+                mouse_logic::post_events(&mut state.input.mouse, self.event_pump.mouse_state());
+
+                keyboard_logic::post_events(
+                    &mut state.input.keyboard,
+                    self.event_pump.keyboard_state(),
+                    self.keyboard_util.mod_state(),
+                );
+
+                let input = &mut state.input;
+                self.gamepad_logic.post_events(
+                    &mut input.gamepad,
+                    &mut input.players,
+                    &state.rumble,
+                );
+
+                if let InputMode::Record(recorder) = input_mode {
+                    recorder.record(frame, &state.input)?;
+                }
+            }
+        }
 
         update_general(state);
+        update_gestures(&mut state.input.general, frame);
+        self.action_map.update(&mut state.input);
 
         let input = &state.input;
 
@@ -103,21 +203,9 @@ impl LogicFrame {
             state.camera.position = Vec3::backward();
         }
 
-        if input.general.buttons.is_hold(action::CAMERA_UP) {
-            self.camera_vertical_angle += rotation_speed;
-        }
-
-        if input.general.buttons.is_hold(action::CAMERA_DOWN) {
-            self.camera_vertical_angle -= rotation_speed;
-        }
-
-        if input.general.buttons.is_hold(action::CAMERA_LEFT) {
-            self.camera_horizontal_angle += rotation_speed;
-        }
-
-        if input.general.buttons.is_hold(action::CAMERA_RIGHT) {
-            self.camera_horizontal_angle -= rotation_speed;
-        }
+        let look = input.actions.vec2(action_map::LOOK);
+        self.camera_vertical_angle += rotation_speed * look.1;
+        self.camera_horizontal_angle -= rotation_speed * look.0;
 
         while self.camera_horizontal_angle < 0. {
             self.camera_horizontal_angle += 2. * PI;
@@ -131,25 +219,11 @@ impl LogicFrame {
         let rotation2 = Quat::from((self.camera_horizontal_angle, Vec3::up()));
         state.camera.rotation = rotation2 * rotation1;
 
-        if input.general.buttons.is_hold(action::MOVE_UP) {
-            let forward = state.camera.rotation.rotate(Vec3::forward());
-            state.camera.position += movement_speed * forward;
-        }
-
-        if input.general.buttons.is_hold(action::MOVE_DOWN) {
-            let forward = state.camera.rotation.rotate(Vec3::forward());
-            state.camera.position -= movement_speed * forward;
-        }
-
-        if input.general.buttons.is_hold(action::MOVE_LEFT) {
-            let right = state.camera.rotation.rotate(Vec3::right());
-            state.camera.position -= movement_speed * right;
-        }
-
-        if input.general.buttons.is_hold(action::MOVE_RIGHT) {
-            let right = state.camera.rotation.rotate(Vec3::right());
-            state.camera.position += movement_speed * right;
-        }
+        let movement = input.actions.vec2(action_map::MOVE);
+        let forward = state.camera.rotation.rotate(Vec3::forward());
+        let right = state.camera.rotation.rotate(Vec3::right());
+        state.camera.position += movement_speed * movement.1 * forward;
+        state.camera.position += movement_speed * movement.0 * right;
 
         if input.keyboard.keys.is_down(Scancode::F) {
             println!(
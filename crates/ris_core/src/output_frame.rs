@@ -1,9 +1,14 @@
+use std::path::Path;
 use std::ptr;
 
 use ash::vk;
 use sdl2::video::Window;
 use sdl2_sys::SDL_WindowFlags;
 
+use ris_asset::asset_cache;
+use ris_asset::asset_watcher;
+use ris_asset::asset_watcher::AssetSubscription;
+use ris_asset::asset_watcher::AssetWatcher;
 use ris_asset::RisGodAsset;
 use ris_data::gameloop::frame::Frame;
 use ris_data::gameloop::gameloop_state::GameloopState;
@@ -32,9 +37,21 @@ pub struct Renderer {
     pub imgui: ImguiRenderer,
 }
 
+/// the assets each renderer depends on. changed assets are reloaded by rebuilding only the
+/// affected renderers. only exists when assets are loaded from a directory.
+pub struct RendererAssets {
+    pub watcher: AssetWatcher,
+    pub scene: AssetSubscription,
+    pub gizmo_segment: AssetSubscription,
+    pub gizmo_text: AssetSubscription,
+    #[cfg(feature = "ui_helper_enabled")]
+    pub imgui: AssetSubscription,
+}
+
 pub struct OutputFrame {
     pub current_frame: usize,
     pub renderer: Renderer,
+    pub assets: Option<RendererAssets>,
     #[cfg(feature = "ui_helper_enabled")]
     pub imgui_backend: ImguiBackend,
     #[cfg(feature = "ui_helper_enabled")]
@@ -70,6 +87,27 @@ impl Drop for OutputFrame {
     }
 }
 
+impl RendererAssets {
+    pub fn new(asset_path: &Path, god_asset: &RisGodAsset) -> RisResult<Self> {
+        let mut watcher = AssetWatcher::new(asset_path, asset_watcher::DEFAULT_POLL_INTERVAL)?;
+
+        let scene = watcher.subscribe(SceneRenderer::asset_ids(god_asset));
+        let gizmo_segment = watcher.subscribe(GizmoSegmentRenderer::asset_ids(god_asset));
+        let gizmo_text = watcher.subscribe(GizmoTextRenderer::asset_ids(god_asset));
+        #[cfg(feature = "ui_helper_enabled")]
+        let imgui = watcher.subscribe(ImguiRenderer::asset_ids(god_asset));
+
+        Ok(Self {
+            watcher,
+            scene,
+            gizmo_segment,
+            gizmo_text,
+            #[cfg(feature = "ui_helper_enabled")]
+            imgui,
+        })
+    }
+}
+
 impl OutputFrame {
     pub fn wait_idle(&self) -> RisResult<()> {
         unsafe { self.core.device.device_wait_idle() }?;
@@ -172,6 +210,83 @@ impl OutputFrame {
             }
         }
 
+        // reload changed assets
+        ris_debug::add_record!(r, "reload changed assets")?;
+        if let Some(assets) = self.assets.as_mut() {
+            // a failed reload must not end the game, the asset may be fixed and saved again
+            let changed = match assets.watcher.poll() {
+                Ok(changed) => changed,
+                Err(e) => {
+                    ris_log::error!("failed to poll changed assets: {}", e);
+                    Vec::new()
+                }
+            };
+            if !changed.is_empty() {
+                for asset_id in changed.iter() {
+                    asset_cache::global().invalidate(asset_id);
+                }
+
+                unsafe {
+                    self.core.device.device_wait_idle()?;
+
+                    let scene_changed = assets.scene.take_changed();
+                    if scene_changed.iter().any(|x| *x != god_asset.texture) {
+                        reload_renderer(
+                            "scene",
+                            &mut self.renderer.scene,
+                            device,
+                            |x, d| x.free(d),
+                            || SceneRenderer::alloc(&self.core, god_asset),
+                        );
+                    } else if !scene_changed.is_empty() {
+                        if let Err(e) = self.renderer.scene.reload_texture(&self.core, god_asset) {
+                            ris_log::error!("failed to reload scene texture: {}", e);
+                        }
+                    }
+
+                    if let Err(e) =
+                        self.renderer
+                            .scene
+                            .reload_meshes(&self.core, &state.scene, &changed)
+                    {
+                        ris_log::error!("failed to reload meshes: {}", e);
+                    }
+
+                    if !assets.gizmo_segment.take_changed().is_empty() {
+                        reload_renderer(
+                            "gizmo segment",
+                            &mut self.renderer.gizmo_segment,
+                            device,
+                            |x, d| x.free(d),
+                            || GizmoSegmentRenderer::alloc(&self.core, god_asset),
+                        );
+                    }
+
+                    if !assets.gizmo_text.take_changed().is_empty() {
+                        reload_renderer(
+                            "gizmo text",
+                            &mut self.renderer.gizmo_text,
+                            device,
+                            |x, d| x.free(d),
+                            || GizmoTextRenderer::alloc(&self.core, god_asset),
+                        );
+                    }
+
+                    #[cfg(feature = "ui_helper_enabled")]
+                    if !assets.imgui.take_changed().is_empty() {
+                        let context = self.imgui_backend.context();
+                        reload_renderer(
+                            "imgui",
+                            &mut self.renderer.imgui,
+                            device,
+                            |x, d| x.free(d),
+                            || ImguiRenderer::alloc(&self.core, god_asset, context),
+                        );
+                    }
+                }
+            }
+        }
+
         // acquire an image from the swap chain
         ris_debug::add_record!(r, "acquire an image from the swapchain")?;
 
@@ -335,3 +450,26 @@ impl OutputFrame {
         Ok(ui_helper_state)
     }
 }
+
+/// replaces the renderer with a newly allocated one. if the allocation fails, for example because
+/// a changed asset is broken, the old renderer is kept.
+///
+/// # Safety
+///
+/// The old renderer must not be in use by the gpu.
+unsafe fn reload_renderer<T>(
+    name: &str,
+    renderer: &mut T,
+    device: &ash::Device,
+    free: impl FnOnce(&mut T, &ash::Device),
+    alloc: impl FnOnce() -> RisResult<T>,
+) {
+    match alloc() {
+        Ok(new_renderer) => {
+            let mut old_renderer = std::mem::replace(renderer, new_renderer);
+            free(&mut old_renderer, device);
+            ris_log::debug!("reloaded {} renderer", name);
+        }
+        Err(e) => ris_log::error!("failed to reload {} renderer: {}", name, e),
+    }
+}
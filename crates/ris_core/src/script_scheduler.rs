@@ -0,0 +1,160 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use ris_data::ecs::components::script::DynScriptComponent;
+use ris_data::ecs::components::script::ScriptAccess;
+use ris_data::ecs::decl::DynScriptComponentHandle;
+use ris_data::ecs::decl::GameObjectHandle;
+use ris_data::ecs::id::SceneId;
+use ris_data::ecs::scene::Scene;
+use ris_data::gameloop::frame::Frame;
+use ris_data::god_state::GodState;
+use ris_error::RisResult;
+use ris_jobs::job_system;
+
+/// the number of scripts that are updated by a single job
+pub const SCRIPTS_PER_JOB: usize = 64;
+
+#[derive(Debug, Clone, Copy)]
+pub enum ScriptPhase {
+    FixedUpdate { timestep: Duration },
+    Update,
+    LateUpdate,
+}
+
+/// groups scripts by their `ScriptAccess`.
+///
+/// parallel scripts are split into batches, such that no script in a batch writes a game object
+/// that another script in the same batch reads or writes. the game object of a script counts as
+/// written. batches run one after another, the
+/// scripts inside a batch run in parallel on the job system. main thread scripts run after all
+/// batches, in the order they are stored in the scene.
+#[derive(Debug, Default)]
+pub struct ScriptSchedule {
+    pub batches: Vec<Vec<DynScriptComponentHandle>>,
+    pub main_thread: Vec<DynScriptComponentHandle>,
+}
+
+#[derive(Default)]
+struct Batch {
+    scripts: Vec<DynScriptComponentHandle>,
+    reads: HashSet<SceneId>,
+    writes: HashSet<SceneId>,
+}
+
+impl ScriptSchedule {
+    pub fn build(scene: &Scene) -> RisResult<Self> {
+        let mut batches = Vec::<Batch>::new();
+        let mut main_thread = Vec::new();
+
+        for (_, script) in scene.query::<DynScriptComponent>()? {
+            let Ok(ptr) = scene.deref(script) else {
+                continue;
+            };
+
+            let aref = ptr.borrow();
+            let access = aref.access(scene);
+            let game_object = aref.game_object();
+            drop(aref);
+            let handle = DynScriptComponentHandle::from(script);
+
+            let (reads, mut writes) = match access {
+                ScriptAccess::MainThread => {
+                    main_thread.push(handle);
+                    continue;
+                }
+                ScriptAccess::Parallel { reads, writes } => (to_ids(&reads), to_ids(&writes)),
+            };
+
+            // scripts may always modify their own game object, even if it is not declared
+            writes.insert(game_object.0.scene_id());
+
+            let position = batches.iter().position(|batch| {
+                batch.writes.is_disjoint(&reads)
+                    && batch.writes.is_disjoint(&writes)
+                    && batch.reads.is_disjoint(&writes)
+            });
+
+            let batch = match position {
+                Some(position) => &mut batches[position],
+                None => {
+                    batches.push(Batch::default());
+                    let last = batches.len() - 1;
+                    &mut batches[last]
+                }
+            };
+
+            batch.scripts.push(handle);
+            batch.reads.extend(reads);
+            batch.writes.extend(writes);
+        }
+
+        let batches = batches.into_iter().map(|x| x.scripts).collect();
+
+        Ok(Self {
+            batches,
+            main_thread,
+        })
+    }
+
+    /// runs the given phase on all scheduled scripts. waits for each batch to finish, before the
+    /// next one is started. if a script fails, the remaining jobs of its batch still finish, but
+    /// no further batches are started.
+    pub fn run(&self, phase: ScriptPhase, frame: Frame, state: &Arc<GodState>) -> RisResult<()> {
+        for batch in self.batches.iter() {
+            let mut futures = Vec::with_capacity(batch.len().div_ceil(SCRIPTS_PER_JOB));
+            for scripts in batch.chunks(SCRIPTS_PER_JOB) {
+                let scripts = scripts.to_vec();
+                let state = state.clone();
+                let future = job_system::submit(move || -> RisResult<()> {
+                    for script in scripts {
+                        run_script(script, phase, frame, &state)?;
+                    }
+
+                    Ok(())
+                });
+                futures.push(future);
+            }
+
+            let mut result = Ok(());
+            for future in futures {
+                let job_result = future.wait(None)?;
+                if result.is_ok() {
+                    result = job_result;
+                }
+            }
+
+            result?;
+        }
+
+        for &script in self.main_thread.iter() {
+            run_script(script, phase, frame, state)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn run_script(
+    script: DynScriptComponentHandle,
+    phase: ScriptPhase,
+    frame: Frame,
+    state: &GodState,
+) -> RisResult<()> {
+    // the script may have been destroyed by a script that ran before it
+    let Ok(ptr) = state.scene.deref(script.into()) else {
+        return Ok(());
+    };
+
+    let mut aref_mut = ptr.borrow_mut();
+    match phase {
+        ScriptPhase::FixedUpdate { timestep } => aref_mut.fixed_update(timestep, state),
+        ScriptPhase::Update => aref_mut.update(frame, state),
+        ScriptPhase::LateUpdate => aref_mut.late_update(frame, state),
+    }
+}
+
+fn to_ids(game_objects: &[GameObjectHandle]) -> HashSet<SceneId> {
+    game_objects.iter().map(|x| x.0.scene_id()).collect()
+}
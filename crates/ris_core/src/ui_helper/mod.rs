@@ -29,6 +29,7 @@ pub mod selection;
 use selection::Selector;
 
 use modules::asset_browser::AssetBrowser;
+use modules::bindings::BindingsModule;
 use modules::gizmo::GizmoModule;
 use modules::hierarchy::HierarchyModule;
 use modules::inspector::InspectorModule;
@@ -78,6 +79,7 @@ macro_rules! module_vec {
 fn builders() -> RisResult<Vec<UiHelperModuleBuilder>> {
     let modules = module_vec![
         AssetBrowser,
+        BindingsModule,
         GizmoModule,
         HierarchyModule,
         InspectorModule,
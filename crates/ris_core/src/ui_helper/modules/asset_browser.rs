@@ -3,6 +3,7 @@ use std::path::Path;
 use std::path::PathBuf;
 
 use ris_asset::assets::ris_scene;
+use ris_data::asset_id::AssetId;
 use ris_error::Extensions;
 use ris_error::RisResult;
 
@@ -121,6 +122,26 @@ impl AssetBrowser {
         } else if path.is_file()
             && unsafe { imgui::sys::igBeginPopupContextItem(std::ptr::null(), 1) }
         {
+            let is_scene = path
+                .extension()
+                .map(|x| x == ris_scene::EXTENSION)
+                .unwrap_or(false);
+
+            if is_scene && data.ui.menu_item("load") {
+                let bytes = std::fs::read(path)?;
+                let reserved = ris_scene::load(&data.state.scene, &bytes)?;
+                match reserved {
+                    Some(chunk_index) => {
+                        let id = AssetId::Path(path_without_root.display().to_string());
+                        self.shared_state
+                            .borrow_mut()
+                            .set_chunk(chunk_index, Some(id));
+                        ris_log::info!("loaded asset into chunk {}", chunk_index);
+                    }
+                    None => ris_log::warning!("failed to load scene: all chunks are in use"),
+                }
+            }
+
             if data.ui.menu_item("delete") {
                 if let Err(e) = std::fs::remove_file(path) {
                     ris_log::error!("failed to delete file: {}", e)
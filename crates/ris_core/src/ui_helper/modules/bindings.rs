@@ -0,0 +1,225 @@
+use sdl2::keyboard::Scancode;
+
+use ris_data::input::action;
+use ris_data::input::gamepad_data;
+use ris_data::input::keyboard_data;
+use ris_data::input::keys::KeyState;
+use ris_data::input::mouse_data;
+use ris_data::input::rebind_matrix::RebindMatrix;
+use ris_data::settings::input_settings::InputSettings;
+use ris_error::RisResult;
+
+use crate::ui_helper::IUiHelperModule;
+use crate::ui_helper::SharedStateWeakPtr;
+use crate::ui_helper::UiHelperDrawData;
+
+const CANCEL_KEY: Scancode = Scancode::Escape;
+const UNBIND_KEY: Scancode = Scancode::Backspace;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Device {
+    Keyboard,
+    Mouse,
+    Gamepad,
+}
+
+pub struct BindingsModule {
+    /// the device and action that wait for a key or button to be pressed
+    listening: Option<(Device, usize)>,
+}
+
+impl IUiHelperModule for BindingsModule {
+    fn name() -> &'static str {
+        "bindings"
+    }
+
+    fn build(_shared_state: SharedStateWeakPtr) -> Box<dyn IUiHelperModule> {
+        Box::new(Self { listening: None })
+    }
+
+    fn draw(&mut self, data: &mut UiHelperDrawData) -> RisResult<()> {
+        let ui = data.ui;
+
+        if let Some((device, index)) = self.listening {
+            ui.text(format!(
+                "press a {} to bind \"{}\"",
+                match device {
+                    Device::Keyboard => "key",
+                    Device::Mouse | Device::Gamepad => "button",
+                },
+                action::NAMES[index],
+            ));
+            ui.text_disabled(format!(
+                "{:?} cancels, {:?} unbinds",
+                CANCEL_KEY, UNBIND_KEY
+            ));
+
+            self.listen(device, index, data);
+            return Ok(());
+        }
+
+        let settings = data.state.settings.input_mut();
+
+        for device in [Device::Keyboard, Device::Mouse, Device::Gamepad] {
+            let label = match device {
+                Device::Keyboard => "keyboard",
+                Device::Mouse => "mouse",
+                Device::Gamepad => "gamepad",
+            };
+
+            if !ui.collapsing_header(label, imgui::TreeNodeFlags::empty()) {
+                continue;
+            }
+
+            for (i, name) in action::NAMES.iter().enumerate() {
+                let binding = match device {
+                    Device::Keyboard => match settings.get_keymask()[i] {
+                        Some(scancode) => keyboard_data::scancode_name(scancode),
+                        None => "none".to_string(),
+                    },
+                    Device::Mouse => format_buttons(
+                        settings.get_mouse_rebind_matrix(),
+                        &mouse_data::BUTTON_NAMES,
+                        i,
+                    ),
+                    Device::Gamepad => format_buttons(
+                        settings.get_gamepad_rebind_matrix(),
+                        &gamepad_data::BUTTON_NAMES,
+                        i,
+                    ),
+                };
+
+                if ui.button(format!("{}##{}_{}", binding, label, i)) {
+                    self.listening = Some((device, i));
+                }
+
+                ui.same_line();
+                ui.text(name);
+            }
+        }
+
+        if ui.button("restore default bindings") {
+            settings.restore_default();
+        }
+
+        ui.same_line();
+        if ui.button("save") {
+            data.state.settings.request_save();
+        }
+
+        Ok(())
+    }
+}
+
+impl BindingsModule {
+    fn listen(&mut self, device: Device, index: usize, data: &mut UiHelperDrawData) {
+        let input = &data.state.input;
+        let settings = data.state.settings.input_mut();
+
+        if input.keyboard.keys.is_down(CANCEL_KEY) {
+            self.listening = None;
+            return;
+        }
+
+        if input.keyboard.keys.is_down(UNBIND_KEY) {
+            match device {
+                Device::Keyboard => {
+                    let mut keymask = *settings.get_keymask();
+                    keymask[index] = None;
+                    settings.set_keymask(keymask);
+                }
+                Device::Mouse => {
+                    let rebind_matrix = bind(settings.get_mouse_rebind_matrix(), index, None);
+                    settings.set_mouse_rebind_matrix(rebind_matrix);
+                }
+                Device::Gamepad => {
+                    let rebind_matrix = bind(settings.get_gamepad_rebind_matrix(), index, None);
+                    settings.set_gamepad_rebind_matrix(rebind_matrix);
+                }
+            }
+
+            self.listening = None;
+            return;
+        }
+
+        let bound = match device {
+            Device::Keyboard => bind_key(settings, input.keyboard.keys.down(), index),
+            Device::Mouse => match first_button(input.mouse.buttons.down()) {
+                Some(button) => {
+                    let rebind_matrix =
+                        bind(settings.get_mouse_rebind_matrix(), index, Some(button));
+                    settings.set_mouse_rebind_matrix(rebind_matrix);
+                    true
+                }
+                None => false,
+            },
+            Device::Gamepad => match first_button(input.gamepad.buttons.down()) {
+                Some(button) => {
+                    let rebind_matrix =
+                        bind(settings.get_gamepad_rebind_matrix(), index, Some(button));
+                    settings.set_gamepad_rebind_matrix(rebind_matrix);
+                    true
+                }
+                None => false,
+            },
+        };
+
+        if bound {
+            self.listening = None;
+        }
+    }
+}
+
+fn bind_key(settings: &mut InputSettings, down: KeyState, index: usize) -> bool {
+    let Some(scancode) = down
+        .iter()
+        .position(|x| *x)
+        .and_then(|x| Scancode::from_i32(x as i32))
+    else {
+        return false;
+    };
+
+    let mut keymask = *settings.get_keymask();
+    keymask[index] = Some(scancode);
+    settings.set_keymask(keymask);
+    true
+}
+
+fn first_button(buttons: u32) -> Option<usize> {
+    if buttons == 0 {
+        None
+    } else {
+        Some(buttons.trailing_zeros() as usize)
+    }
+}
+
+/// makes `button` the only button that triggers the action
+fn bind(rebind_matrix: &RebindMatrix, action: usize, button: Option<usize>) -> RebindMatrix {
+    let mut result = rebind_matrix.clone();
+    for row in result.data.iter_mut() {
+        *row &= !(1 << action);
+    }
+
+    if let Some(button) = button {
+        result.data[button] |= 1 << action;
+    }
+
+    result
+}
+
+/// lists the names of the buttons that trigger the action
+fn format_buttons(rebind_matrix: &RebindMatrix, button_names: &[&str], action: usize) -> String {
+    let buttons = rebind_matrix
+        .data
+        .iter()
+        .zip(button_names.iter())
+        .filter(|(row, _)| *row & (1 << action) != 0)
+        .map(|(_, name)| *name)
+        .collect::<Vec<_>>();
+
+    if buttons.is_empty() {
+        "none".to_string()
+    } else {
+        buttons.join(" | ")
+    }
+}
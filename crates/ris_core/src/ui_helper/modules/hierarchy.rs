@@ -20,6 +20,7 @@ const PAYLOAD_ID: &CStr = c"hierarchy drag drop payload id";
 pub struct HierarchyModule {
     shared_state: SharedStateWeakPtr,
     selected_chunk: usize,
+    chunk_path: String,
 }
 
 impl IUiHelperModule for HierarchyModule {
@@ -31,6 +32,7 @@ impl IUiHelperModule for HierarchyModule {
         Box::new(Self {
             shared_state,
             selected_chunk: 0,
+            chunk_path: String::new(),
         })
     }
 
@@ -86,6 +88,53 @@ impl IUiHelperModule for HierarchyModule {
                 ui.same_line();
                 ui.text(path)
             }
+
+            drop(_disabled_token);
+
+            ui.input_text("path", &mut self.chunk_path).build();
+            let _disabled_token = ui.begin_disabled(self.chunk_path.is_empty());
+
+            let asset_path = self.shared_state.borrow().app_info.asset_path()?;
+            let path = asset_path.join(&self.chunk_path);
+
+            if ui.button("save as") {
+                ris_log::debug!(
+                    "saving scene... chunk: {} path: {}",
+                    chunk_index,
+                    self.chunk_path,
+                );
+                let bytes = ris_scene::serialize(scene, Some(chunk_index))?;
+
+                let mut file = std::fs::File::create(path.clone())?;
+                ris_io::write(&mut file, &bytes)?;
+
+                // the chunk now belongs to the saved asset. reserve it, such that no other scene
+                // is loaded into it
+                scene.reserve_chunk_at(chunk_index);
+
+                let id = AssetId::Path(self.chunk_path.clone());
+                self.shared_state
+                    .borrow_mut()
+                    .set_chunk(chunk_index, Some(id));
+            }
+
+            ui.same_line();
+            let chunk_is_reserved = scene.is_chunk_reserved(chunk_index);
+            let _disabled_token = ui.begin_disabled(chunk_is_reserved);
+            if ui.button("load") {
+                ris_log::debug!(
+                    "loading scene... chunk: {} path: {}",
+                    chunk_index,
+                    self.chunk_path,
+                );
+                let bytes = std::fs::read(path)?;
+                ris_scene::load_into_chunk(scene, &bytes, chunk_index)?;
+
+                let id = AssetId::Path(self.chunk_path.clone());
+                self.shared_state
+                    .borrow_mut()
+                    .set_chunk(chunk_index, Some(id));
+            }
         }
 
         let (chunk, kind) = if dynamics_are_selected {
@@ -101,7 +150,7 @@ impl IUiHelperModule for HierarchyModule {
 
         let child_token = ui.child_window("hierarchy child window").begin();
         if child_token.is_some() {
-            let alive = chunk.iter().filter(|x| x.borrow().is_alive).count();
+            let alive = scene.game_objects(kind).count();
             ui.label_text("game objects", format!("{}/{}", alive, chunk.len()));
 
             if unsafe { imgui::sys::igBeginPopupContextWindow(ptr::null(), 1) } {
@@ -112,21 +161,10 @@ impl IUiHelperModule for HierarchyModule {
                 unsafe { imgui::sys::igEndPopup() }
             }
 
-            let handles = chunk
-                .iter()
-                .filter(|x| {
-                    let handle: GameObjectHandle = x.borrow().handle.into();
-                    let parent_handle = handle.parent(scene).unwrap_or(None);
-                    let is_root = parent_handle.is_none();
-                    let is_alive = x.borrow().is_alive;
-
-                    is_alive && is_root
-                })
-                .map(|x| x.borrow().handle)
-                .collect::<Vec<_>>();
+            let handles = scene.game_objects(kind).roots_only().collect::<Vec<_>>();
 
             for handle in handles {
-                self.draw_node(handle.into(), data)?;
+                self.draw_node(handle, data)?;
             }
         }
 
@@ -9,7 +9,10 @@ use ris_data::ecs::components::mesh_renderer::MeshRendererComponent;
 use ris_data::ecs::components::script::DynScriptComponent;
 use ris_data::ecs::components::script::ScriptInspectData;
 use ris_data::ecs::decl::GameObjectHandle;
+use ris_data::ecs::decl::MeshRendererComponentHandle;
+use ris_data::ecs::decl::VideoMeshHandle;
 use ris_data::ecs::error::EcsResult;
+use ris_data::ecs::handle::GenericHandle;
 use ris_data::ecs::scene::Scene;
 use ris_error::Extensions;
 use ris_error::RisResult;
@@ -40,10 +43,12 @@ pub struct InspectorModule {
     cached_xzw: Vec3,
     cached_yzw: Vec3,
     component_filter: String,
+    mesh_path: String,
 
     // asset
     load_asset_jobs: Vec<JobFuture<Result<Vec<u8>, LoadError>>>,
     loaded_asset: Vec<u8>,
+    loaded_mesh_counts: Option<(usize, usize)>,
 }
 
 impl IUiHelperModule for InspectorModule {
@@ -63,10 +68,12 @@ impl IUiHelperModule for InspectorModule {
             cached_xyw: Vec3(0.0, 0.0, 1.0),
             cached_xyz: Vec3(1.0, 0.0, 0.0),
             component_filter: String::new(),
+            mesh_path: String::new(),
 
             // asset
             load_asset_jobs: Vec::new(),
             loaded_asset: Vec::new(),
+            loaded_mesh_counts: None,
         })
     }
 
@@ -120,8 +127,8 @@ impl IUiHelperModule for InspectorModule {
                 let set_position: fn(GameObjectHandle, &Scene, Vec3) -> EcsResult<()>;
                 let get_rotation: fn(GameObjectHandle, &Scene) -> EcsResult<Quat>;
                 let set_rotation: fn(GameObjectHandle, &Scene, Quat) -> EcsResult<()>;
-                let get_scale: fn(GameObjectHandle, &Scene) -> EcsResult<f32>;
-                let set_scale: fn(GameObjectHandle, &Scene, f32) -> EcsResult<()>;
+                let get_scale: fn(GameObjectHandle, &Scene) -> EcsResult<Vec3>;
+                let set_scale: fn(GameObjectHandle, &Scene, Vec3) -> EcsResult<()>;
 
                 match self.space {
                     Space::Local => {
@@ -376,22 +383,11 @@ impl IUiHelperModule for InspectorModule {
                     }
                 }
 
-                let label = CString::new("scale")?;
                 let scale_min = 0.001;
                 let mut scale = get_scale(game_object, &data.state.scene)?;
-                let changed = unsafe {
-                    imgui::sys::igDragFloat(
-                        label.as_ptr(),
-                        &mut scale as *mut f32,
-                        0.001,
-                        scale_min,
-                        f32::MAX,
-                        format.as_ptr(),
-                        0,
-                    )
-                };
-                scale = f32::max(scale, scale_min);
+                let changed = inspector_util::drag_vec3("scale", &mut scale)?;
                 if changed {
+                    let scale = Vec3::max(scale, Vec3::init(scale_min));
                     set_scale(game_object, &data.state.scene, scale)?;
                 }
 
@@ -429,14 +425,9 @@ impl IUiHelperModule for InspectorModule {
                 //data.ui.text(format!("{} components", components.len()));
 
                 for component in components {
-                    let index = component.scene_id().index;
-
                     let delete_requested;
 
                     if component.type_id() == TypeId::of::<MeshRendererComponent>() {
-                        //let ptr = data.state.scene.mesh_renderer_components[index].to_weak();
-                        //let aref_mut = ptr.borrow_mut();
-
                         let header =
                             ComponentHeader::draw(data.ui, format!("mesh##{:?}", component));
                         delete_requested = header.delete_requested;
@@ -444,9 +435,43 @@ impl IUiHelperModule for InspectorModule {
                             continue;
                         }
 
-                        data.ui.text("im a mesh :)");
+                        let handle: MeshRendererComponentHandle =
+                            GenericHandle::from_dyn(component.into())?.into();
+                        let asset_id = match handle.video_mesh(&data.state.scene)? {
+                            Some(video_mesh) => video_mesh.asset_id(&data.state.scene)?,
+                            None => None,
+                        };
+
+                        match asset_id {
+                            Some(AssetId::Path(path)) => data.ui.text(format!("mesh: {}", path)),
+                            Some(AssetId::Index(index)) => {
+                                data.ui.text(format!("mesh: #{}", index))
+                            }
+                            None => data.ui.text("mesh: none"),
+                        }
+
+                        data.ui
+                            .input_text(
+                                format!("path##mesh_path_{:?}", component),
+                                &mut self.mesh_path,
+                            )
+                            .hint(format!("*.{}", ris_asset::assets::ris_mesh::EXTENSION))
+                            .build();
+
+                        let is_mesh_path = self
+                            .mesh_path
+                            .ends_with(ris_asset::assets::ris_mesh::EXTENSION);
+                        let _disabled_token = data.ui.begin_disabled(!is_mesh_path);
+                        if data.ui.button(format!("assign##{:?}", component)) {
+                            let asset_id = AssetId::Path(self.mesh_path.clone());
+                            let video_mesh =
+                                VideoMeshHandle::from_asset_id(&data.state.scene, asset_id)?;
+                            handle.set_video_mesh(&data.state.scene, video_mesh)?;
+                        }
                     } else if component.type_id() == TypeId::of::<DynScriptComponent>() {
-                        let ptr = data.state.scene.script_components[index].to_weak();
+                        let handle =
+                            GenericHandle::<DynScriptComponent>::from_dyn(component.into())?;
+                        let ptr = data.state.scene.deref(handle)?;
                         let mut aref_mut = ptr.borrow_mut();
                         let script_name = aref_mut.type_name().into_ris_error()?;
 
@@ -543,18 +568,33 @@ impl IUiHelperModule for InspectorModule {
                     }
 
                     self.loaded_asset.clear();
+                    self.loaded_mesh_counts = None;
                 }
 
                 if !self.load_asset_jobs.is_empty() {
                     let job = self.load_asset_jobs.remove(0);
                     match job.try_take() {
-                        Ok(data) => self.loaded_asset = data?,
+                        Ok(data) => {
+                            self.loaded_asset = data?;
+
+                            if path_string.ends_with(ris_asset::assets::ris_mesh::EXTENSION) {
+                                let mesh = ris_asset::assets::ris_mesh::load(&self.loaded_asset)?;
+                                self.loaded_mesh_counts =
+                                    Some((mesh.vertices.len(), mesh.indices.len()));
+                            }
+                        }
                         Err(job) => self.load_asset_jobs.insert(0, job),
                     }
                 }
 
                 let size = self.loaded_asset.len();
                 data.ui.text(format!("size: {:?}", size));
+
+                if let Some((vertex_count, index_count)) = self.loaded_mesh_counts {
+                    data.ui.text(format!("vertices: {}", vertex_count));
+                    data.ui.text(format!("indices: {}", index_count));
+                }
+
                 let _disabled_token = data.ui.begin_disabled(size == 0);
 
                 if path_string.ends_with(ris_asset::assets::ris_scene::EXTENSION)
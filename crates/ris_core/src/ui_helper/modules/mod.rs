@@ -1,4 +1,5 @@
 pub mod asset_browser;
+pub mod bindings;
 pub mod gizmo;
 pub mod hierarchy;
 pub mod inspector;
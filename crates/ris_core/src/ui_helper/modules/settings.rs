@@ -72,6 +72,7 @@ impl IUiHelperModule for SettingsModule {
             if ui.button("restore default") {
                 let app_info = &self.shared_state.borrow().app_info;
                 *settings = Settings::new(app_info);
+                settings.input_mut().restore_default();
                 self.saved = false;
             }
         }
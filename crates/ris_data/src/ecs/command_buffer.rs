@@ -0,0 +1,219 @@
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+
+use ris_error::RisResult;
+use ris_jobs::job_system;
+
+use super::components::script::Script;
+use super::decl::GameObjectHandle;
+use super::decl::ScriptComponentHandle;
+use super::handle::ComponentHandle;
+use super::handle::GenericHandle;
+use super::id::Component;
+use super::scene::Scene;
+
+type Command = Box<dyn FnOnce(&Scene) -> RisResult<()> + Send + Sync>;
+
+/// a handle to an object that is created when the command buffer is played back
+#[derive(Debug)]
+pub struct Pending<H: Copy> {
+    cell: Arc<OnceLock<H>>,
+}
+
+/// a game object that either exists already, or is spawned by the command buffer
+#[derive(Debug, Clone)]
+pub enum GameObjectRef {
+    Existing(GameObjectHandle),
+    Pending(Pending<GameObjectHandle>),
+}
+
+/// records structural changes to the scene, such that they can be applied at a sync point.
+///
+/// scripts may record into the buffer from any thread, even while other scripts hold borrows into
+/// the scene. `god_job` plays the buffer back once per frame, after all scripts are updated.
+/// commands are applied in the order they were recorded. commands that are recorded during
+/// playback are applied by the next playback.
+#[derive(Default)]
+pub struct CommandBuffer {
+    commands: Mutex<Vec<Command>>,
+}
+
+impl<H: Copy> Clone for Pending<H> {
+    fn clone(&self) -> Self {
+        Self {
+            cell: self.cell.clone(),
+        }
+    }
+}
+
+impl<H: Copy> Pending<H> {
+    fn new() -> Self {
+        Self {
+            cell: Arc::new(OnceLock::new()),
+        }
+    }
+
+    /// `None` until the command buffer is played back
+    pub fn resolve(&self) -> Option<H> {
+        self.cell.get().copied()
+    }
+}
+
+impl From<GameObjectHandle> for GameObjectRef {
+    fn from(value: GameObjectHandle) -> Self {
+        Self::Existing(value)
+    }
+}
+
+impl From<Pending<GameObjectHandle>> for GameObjectRef {
+    fn from(value: Pending<GameObjectHandle>) -> Self {
+        Self::Pending(value)
+    }
+}
+
+impl From<&Pending<GameObjectHandle>> for GameObjectRef {
+    fn from(value: &Pending<GameObjectHandle>) -> Self {
+        Self::Pending(value.clone())
+    }
+}
+
+impl GameObjectRef {
+    pub fn resolve(&self) -> RisResult<GameObjectHandle> {
+        match self {
+            Self::Existing(handle) => Ok(*handle),
+            Self::Pending(pending) => match pending.resolve() {
+                Some(handle) => Ok(handle),
+                None => ris_error::new_result!("pending game object was not spawned yet"),
+            },
+        }
+    }
+}
+
+impl std::fmt::Debug for CommandBuffer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CommandBuffer")
+            .field("commands", &self.len())
+            .finish()
+    }
+}
+
+impl CommandBuffer {
+    pub fn len(&self) -> usize {
+        job_system::lock(&self.commands).len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// spawns a new dynamic game object
+    pub fn spawn(&self) -> Pending<GameObjectHandle> {
+        let pending = Pending::new();
+        let cell = pending.cell.clone();
+        self.push(move |scene| {
+            let handle = GameObjectHandle::new(scene)?;
+            let _ = cell.set(handle);
+            Ok(())
+        });
+        pending
+    }
+
+    /// destroys the game object, its components and its children
+    pub fn destroy(&self, game_object: impl Into<GameObjectRef>) {
+        let game_object = game_object.into();
+        self.push(move |scene| {
+            game_object.resolve()?.destroy(scene);
+            Ok(())
+        });
+    }
+
+    pub fn add_component<T: Component + Default + Send + Sync + 'static>(
+        &self,
+        game_object: impl Into<GameObjectRef>,
+    ) -> Pending<GenericHandle<T>> {
+        let game_object = game_object.into();
+        let pending = Pending::new();
+        let cell = pending.cell.clone();
+        self.push(move |scene| {
+            let handle = game_object.resolve()?.add_component::<T>(scene)?;
+            let _ = cell.set(handle);
+            Ok(())
+        });
+        pending
+    }
+
+    pub fn add_script<T: Script + Default + 'static>(
+        &self,
+        game_object: impl Into<GameObjectRef>,
+    ) -> Pending<ScriptComponentHandle<T>> {
+        let game_object = game_object.into();
+        let pending = Pending::new();
+        let cell = pending.cell.clone();
+        self.push(move |scene| {
+            let handle = game_object.resolve()?.add_script::<T>(scene)?;
+            let _ = cell.set(handle);
+            Ok(())
+        });
+        pending
+    }
+
+    /// removes the component from its game object and destroys it
+    pub fn remove_component(&self, component: impl ComponentHandle + Send + Sync + 'static) {
+        self.push(move |scene| {
+            component.destroy(scene);
+            Ok(())
+        });
+    }
+
+    pub fn set_parent(
+        &self,
+        game_object: impl Into<GameObjectRef>,
+        parent: Option<GameObjectRef>,
+        sibling_index: usize,
+        keep_world_transform: bool,
+    ) {
+        let game_object = game_object.into();
+        self.push(move |scene| {
+            let parent = parent.map(|x| x.resolve()).transpose()?;
+            game_object.resolve()?.set_parent(
+                scene,
+                parent,
+                sibling_index,
+                keep_world_transform,
+            )?;
+            Ok(())
+        });
+    }
+
+    pub fn set_active(&self, game_object: impl Into<GameObjectRef>, value: bool) {
+        let game_object = game_object.into();
+        self.push(move |scene| {
+            game_object.resolve()?.set_active(scene, value)?;
+            Ok(())
+        });
+    }
+
+    /// applies all recorded commands to the scene. returns the number of commands that failed.
+    ///
+    /// scripts record in parallel, thus a command may target a game object that an earlier
+    /// command destroyed. failed commands are logged and skipped, and the remaining commands are
+    /// still applied.
+    pub fn playback(&self, scene: &Scene) -> usize {
+        let commands = std::mem::take(&mut *job_system::lock(&self.commands));
+
+        let mut failed = 0;
+        for command in commands {
+            if let Err(e) = command(scene) {
+                ris_log::warning!("skipped command, because it failed: {}", e);
+                failed += 1;
+            }
+        }
+
+        failed
+    }
+
+    fn push(&self, command: impl FnOnce(&Scene) -> RisResult<()> + Send + Sync + 'static) {
+        job_system::lock(&self.commands).push(Box::new(command));
+    }
+}
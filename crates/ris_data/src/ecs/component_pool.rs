@@ -0,0 +1,95 @@
+use std::any::Any;
+use std::any::TypeId;
+
+use super::error::EcsError;
+use super::error::EcsResult;
+use super::id::Component;
+use super::id::EcsObject;
+use super::id::EcsWeakPtr;
+use super::id::SceneKind;
+use super::pool::Pool;
+
+/// type erased storage of a single component type. each component type that is registered in
+/// the `Registry` gets its own pool in every `Scene`.
+pub trait IComponentPool: Send + Sync {
+    fn component_id(&self) -> TypeId;
+    fn as_any(&self) -> &dyn Any;
+    fn len(&self) -> usize;
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    fn mark_as_destroyed(&self, index: usize) -> EcsResult<()>;
+    fn deref_component(
+        &self,
+        index: usize,
+        callback: &mut dyn FnMut(&dyn Component),
+    ) -> EcsResult<()>;
+    fn deref_mut_component(
+        &self,
+        index: usize,
+        callback: &mut dyn FnMut(&mut dyn Component),
+    ) -> EcsResult<()>;
+}
+
+pub struct ComponentPool<T: EcsObject> {
+    pub pool: Pool<T>,
+}
+
+impl<T: Component + Default + 'static> ComponentPool<T> {
+    pub fn new(capacity: usize) -> EcsResult<Self> {
+        let pool = Pool::new(SceneKind::Component, capacity)?;
+        Ok(Self { pool })
+    }
+}
+
+impl<T: EcsObject> ComponentPool<T> {
+    fn get(&self, index: usize) -> EcsResult<EcsWeakPtr<T>> {
+        self.pool
+            .get(index)
+            .ok_or(EcsError::InvalidOperation(format!(
+                "index {} is out of bounds. pool length: {}",
+                index,
+                self.pool.len(),
+            )))
+    }
+}
+
+impl<T: Component + Send + Sync + 'static> IComponentPool for ComponentPool<T> {
+    fn component_id(&self) -> TypeId {
+        TypeId::of::<T>()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn len(&self) -> usize {
+        self.pool.len()
+    }
+
+    fn mark_as_destroyed(&self, index: usize) -> EcsResult<()> {
+        self.pool.free(index)
+    }
+
+    fn deref_component(
+        &self,
+        index: usize,
+        callback: &mut dyn FnMut(&dyn Component),
+    ) -> EcsResult<()> {
+        let ptr = self.get(index)?;
+        let aref = ptr.borrow();
+        callback(&aref.value);
+        Ok(())
+    }
+
+    fn deref_mut_component(
+        &self,
+        index: usize,
+        callback: &mut dyn FnMut(&mut dyn Component),
+    ) -> EcsResult<()> {
+        let ptr = self.get(index)?;
+        let mut aref_mut = ptr.borrow_mut();
+        callback(&mut aref_mut.value);
+        Ok(())
+    }
+}
@@ -35,12 +35,34 @@ impl Component for MeshRendererComponent {
         &mut self.game_object
     }
 
-    fn serialize(&mut self, _stream: &mut SceneWriter) -> RisResult<()> {
-        ris_error::new_result!("not implemeneted")
+    fn serialize(&mut self, stream: &mut SceneWriter) -> RisResult<()> {
+        let Some(video_mesh) = self.video_mesh else {
+            ris_io::write_bool(stream, false)?;
+            return Ok(());
+        };
+
+        let Some(asset_id) = video_mesh.asset_id(stream.scene)? else {
+            return ris_error::new_result!(
+                "video mesh has no asset id. only meshes that were loaded from an asset can be serialized"
+            );
+        };
+
+        ris_io::write_bool(stream, true)?;
+        stream.write_asset_id(asset_id)?;
+        Ok(())
     }
 
-    fn deserialize(&mut self, _stream: &mut SceneReader) -> RisResult<()> {
-        ris_error::new_result!("not implemented")
+    fn deserialize(&mut self, stream: &mut SceneReader, _version: u32) -> RisResult<()> {
+        let has_video_mesh = ris_io::read_bool(stream)?;
+        if !has_video_mesh {
+            self.video_mesh = None;
+            return Ok(());
+        }
+
+        let asset_id = stream.read_asset_id()?;
+        let video_mesh = VideoMeshHandle::from_asset_id(stream.scene, asset_id)?;
+        self.video_mesh = Some(video_mesh);
+        Ok(())
     }
 }
 
@@ -1,6 +1,7 @@
 use std::any::TypeId;
 use std::fmt::Debug;
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use imgui::Ui;
 
@@ -34,6 +35,13 @@ pub struct ScriptUpdateData<'a> {
     pub state: &'a GodState,
 }
 
+pub struct ScriptFixedUpdateData<'a> {
+    pub game_object: GameObjectHandle,
+    /// the fixed timestep. the same for every call
+    pub timestep: Duration,
+    pub state: &'a GodState,
+}
+
 pub struct ScriptInspectData<'a> {
     pub id: String,
     pub ui: &'a Ui,
@@ -42,13 +50,63 @@ pub struct ScriptInspectData<'a> {
     pub state: &'a GodState,
 }
 
+/// declares which game objects a script accesses during `fixed_update`, `update` and
+/// `late_update`. accessing a game object includes accessing its components.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum ScriptAccess {
+    /// the script runs on the main thread, after all parallel scripts. it may access anything,
+    /// including structural changes like creating or destroying game objects and components.
+    #[default]
+    MainThread,
+    /// the script runs on the job system, in parallel to other scripts that do not write what it
+    /// reads or writes. it must not access game objects that are not listed, except its own game
+    /// object, which always counts as written. world transforms read all ancestors, thus they
+    /// must be listed as well. structural changes must be recorded into `GodState::commands`.
+    Parallel {
+        reads: Vec<GameObjectHandle>,
+        writes: Vec<GameObjectHandle>,
+    },
+}
+
 pub trait Script: Debug + Send + Sync {
     fn start(&mut self, data: ScriptStartEndData) -> RisResult<()>;
     fn update(&mut self, data: ScriptUpdateData) -> RisResult<()>;
     fn end(&mut self, data: ScriptStartEndData) -> RisResult<()>;
     fn serialize(&mut self, stream: &mut SceneWriter) -> RisResult<()>;
-    fn deserialize(&mut self, stream: &mut SceneReader) -> RisResult<()>;
+    /// `version` is the version the data was serialized with. see `version`
+    fn deserialize(&mut self, stream: &mut SceneReader, version: u32) -> RisResult<()>;
     fn inspect(&mut self, data: ScriptInspectData) -> RisResult<()>;
+
+    /// the version of the data that `serialize` writes. increment it when the layout changes,
+    /// such that `deserialize` is able to read data of older versions.
+    fn version(&self) -> u32 {
+        0
+    }
+
+    /// called zero or more times per frame, before `update`, such that it runs at a fixed rate
+    fn fixed_update(&mut self, _data: ScriptFixedUpdateData) -> RisResult<()> {
+        Ok(())
+    }
+
+    /// called once per frame, after `update` was called on all scripts
+    fn late_update(&mut self, _data: ScriptUpdateData) -> RisResult<()> {
+        Ok(())
+    }
+
+    /// called when the game object becomes active in the hierarchy
+    fn on_enable(&mut self, _data: ScriptStartEndData) -> RisResult<()> {
+        Ok(())
+    }
+
+    /// called when the game object becomes inactive in the hierarchy
+    fn on_disable(&mut self, _data: ScriptStartEndData) -> RisResult<()> {
+        Ok(())
+    }
+
+    /// called once per frame, before the script is updated. see `ScriptAccess`
+    fn access(&self, _data: ScriptStartEndData) -> ScriptAccess {
+        ScriptAccess::MainThread
+    }
 }
 
 #[derive(Debug)]
@@ -122,14 +180,15 @@ impl Component for DynScriptComponent {
     fn serialize(&mut self, stream: &mut SceneWriter) -> RisResult<()> {
         match self.script.as_mut() {
             Some(script) => {
-                let position = stream
+                let factory = stream
                     .scene
                     .registry
                     .script_factories()
                     .iter()
-                    .position(|x| x.script_id() == script.id)
+                    .find(|x| x.script_id() == script.id)
                     .into_ris_error()?;
-                ris_io::write_uint(stream, position)?;
+                ris_io::write_uint(stream, factory.script_sid().hash as usize)?;
+                ris_io::write_uint(stream, script.boxed.version() as usize)?;
                 script.boxed.serialize(stream)
             }
             None => ris_error::new_result!(
@@ -138,17 +197,36 @@ impl Component for DynScriptComponent {
         }
     }
 
-    fn deserialize(&mut self, stream: &mut SceneReader) -> RisResult<()> {
+    fn deserialize(&mut self, stream: &mut SceneReader, version: u32) -> RisResult<()> {
         match self.script.as_mut() {
             Some(script) => ris_error::new_result!("script was Some({:?}). make sure that the script is not started before deserializing", script),
             None => {
-                let position = ris_io::read_uint(stream)?;
-                let factory = stream.scene.registry.script_factories()
-                    .get(position)
-                    .into_ris_error()?;
+                let scene = stream.scene;
+                let registry = &scene.registry;
+                let (factory, id, script_version) = match version {
+                    // scripts are identified by their position in the registry and have no version
+                    0 => {
+                        let position = ris_io::read_uint(stream)?;
+                        let factory = registry.script_factories().get(position).map(|x| x.as_ref());
+                        (factory, position, 0)
+                    }
+                    _ => {
+                        let sid = ris_io::read_uint(stream)?;
+                        let script_version = ris_io::read_uint(stream)?;
+                        let factory = registry.find_script_factory(sid as u32);
+                        (factory, sid, script_version as u32)
+                    }
+                };
+
+                // unknown scripts leave the component empty, such that the scene loader is able to
+                // skip it
+                let Some(factory) = factory else {
+                    ris_log::warning!("skipped script #{}, because it is not registered", id);
+                    return Ok(());
+                };
 
                 let mut script = factory.make();
-                script.boxed.deserialize(stream)?;
+                script.boxed.deserialize(stream, script_version)?;
                 let data = ScriptStartEndData {
                     game_object: self.game_object(),
                     scene: stream.scene,
@@ -160,6 +238,12 @@ impl Component for DynScriptComponent {
             },
         }
     }
+
+    /// - 0: the script is identified by its position in the registry
+    /// - 1: the script is identified by its sid, followed by the version of the script
+    fn version(&self) -> u32 {
+        1
+    }
 }
 
 impl DynScriptComponent {
@@ -201,6 +285,77 @@ impl DynScriptComponent {
         }
     }
 
+    /// scripts that are not started yet run on the main thread
+    pub fn access(&self, scene: &Scene) -> ScriptAccess {
+        let data = ScriptStartEndData {
+            game_object: self.game_object,
+            scene,
+        };
+
+        match self.script.as_ref() {
+            Some(script) => script.boxed.access(data),
+            None => ScriptAccess::MainThread,
+        }
+    }
+
+    pub fn fixed_update(&mut self, timestep: Duration, state: &GodState) -> RisResult<()> {
+        let data = ScriptFixedUpdateData {
+            game_object: self.game_object,
+            timestep,
+            state,
+        };
+
+        match self.script_mut() {
+            Some(script) => script.fixed_update(data),
+            None => ris_error::new_result!(
+                "script was none. make sure to start the script before calling fixed_update"
+            ),
+        }
+    }
+
+    pub fn late_update(&mut self, frame: Frame, state: &GodState) -> RisResult<()> {
+        let data = ScriptUpdateData {
+            game_object: self.game_object,
+            frame,
+            state,
+        };
+
+        match self.script_mut() {
+            Some(script) => script.late_update(data),
+            None => ris_error::new_result!(
+                "script was none. make sure to start the script before calling late_update"
+            ),
+        }
+    }
+
+    pub fn on_enable(&mut self, scene: &Scene) -> RisResult<()> {
+        let data = ScriptStartEndData {
+            game_object: self.game_object,
+            scene,
+        };
+
+        match self.script_mut() {
+            Some(script) => script.on_enable(data),
+            None => ris_error::new_result!(
+                "script was none. make sure to start the script before calling on_enable"
+            ),
+        }
+    }
+
+    pub fn on_disable(&mut self, scene: &Scene) -> RisResult<()> {
+        let data = ScriptStartEndData {
+            game_object: self.game_object,
+            scene,
+        };
+
+        match self.script_mut() {
+            Some(script) => script.on_disable(data),
+            None => ris_error::new_result!(
+                "script was none. make sure to start the script before calling on_disable"
+            ),
+        }
+    }
+
     pub fn end(&mut self, scene: &Scene) -> RisResult<()> {
         let data = ScriptStartEndData {
             game_object: self.game_object,
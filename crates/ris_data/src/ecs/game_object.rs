@@ -1,5 +1,8 @@
+use std::sync::Mutex;
+
 use ris_error::Extensions;
 use ris_error::RisResult;
+use ris_jobs::job_system;
 use ris_math::affine;
 use ris_math::matrix::Mat4;
 use ris_math::quaternion::Quat;
@@ -45,12 +48,16 @@ pub struct GameObject {
     is_active: bool,
     position: Vec3,
     rotation: Quat,
-    scale: f32,
+    scale: Vec3,
     components: Vec<DynComponentHandle>,
 
     // hierarchy
     parent: Option<GameObjectHandle>,
     children: Vec<GameObjectHandle>,
+
+    // cached values. `None` if dirty. a mutex, such that the cache can be updated through a
+    // shared borrow
+    model: Mutex<Option<Mat4>>,
 }
 
 impl Default for GameObject {
@@ -60,10 +67,11 @@ impl Default for GameObject {
             is_active: true,
             position: Vec3::init(0.0),
             rotation: Quat::identity(),
-            scale: 1.0,
+            scale: Vec3::init(1.0),
             components: Vec::new(),
             parent: None,
             children: Vec::new(),
+            model: Mutex::new(None),
         }
     }
 }
@@ -129,10 +137,36 @@ impl GameObjectHandle {
         Ok(ptr.borrow().is_active)
     }
 
+    /// queues `on_enable` or `on_disable` for all scripts whose game object changes its
+    /// `is_active_in_hierarchy`. they are called by `Scene::notify_scripts`
     pub fn set_active(self, scene: &Scene, value: bool) -> EcsResult<()> {
+        let was_active_in_hierarchy = self.is_active_in_hierarchy(scene)?;
+
         let ptr = scene.deref(self.into())?;
         let mut aref_mut = ptr.borrow_mut();
         aref_mut.is_active = value;
+        drop(aref_mut);
+
+        let is_active_in_hierarchy = self.is_active_in_hierarchy(scene)?;
+        if was_active_in_hierarchy != is_active_in_hierarchy {
+            self.notify_active_in_hierarchy_changed(scene, is_active_in_hierarchy)?;
+        }
+
+        Ok(())
+    }
+
+    fn notify_active_in_hierarchy_changed(self, scene: &Scene, enabled: bool) -> EcsResult<()> {
+        let scripts = self.get_components::<DynScriptComponent>(scene, GetFrom::This)?;
+        for script in scripts {
+            scene.queue_activation_change(script, enabled);
+        }
+
+        // inactive children did not change, as they were and stay inactive in the hierarchy
+        for child in self.children(scene)? {
+            if child.is_active(scene)? {
+                child.notify_active_in_hierarchy_changed(scene, enabled)?;
+            }
+        }
 
         Ok(())
     }
@@ -149,6 +183,7 @@ impl GameObjectHandle {
         if aref_mut.position.not_equal(value).any() {
             aref_mut.position = value;
             drop(aref_mut);
+            self.mark_model_dirty(scene);
         }
 
         Ok(())
@@ -168,18 +203,19 @@ impl GameObjectHandle {
         if left.not_equal(right).any() {
             aref_mut.rotation = value;
             drop(aref_mut);
+            self.mark_model_dirty(scene);
         }
 
         Ok(())
     }
 
-    pub fn local_scale(self, scene: &Scene) -> EcsResult<f32> {
+    pub fn local_scale(self, scene: &Scene) -> EcsResult<Vec3> {
         let ptr = scene.deref(self.into())?;
         Ok(ptr.borrow().scale)
     }
 
-    pub fn set_local_scale(self, scene: &Scene, value: f32) -> EcsResult<()> {
-        if value <= 0.0 {
+    pub fn set_local_scale(self, scene: &Scene, value: Vec3) -> EcsResult<()> {
+        if !value.greater_than(Vec3::init(0.0)).all() {
             return Err(EcsError::InvalidOperation(
                 "scale must be positive".to_string(),
             ));
@@ -188,9 +224,10 @@ impl GameObjectHandle {
         let ptr = scene.deref(self.into())?;
         let mut aref_mut = ptr.borrow_mut();
 
-        if aref_mut.scale != value {
+        if aref_mut.scale.not_equal(value).any() {
             aref_mut.scale = value;
             drop(aref_mut);
+            self.mark_model_dirty(scene);
         }
 
         Ok(())
@@ -198,22 +235,26 @@ impl GameObjectHandle {
 
     pub fn world_position(self, scene: &Scene) -> EcsResult<Vec3> {
         let model = self.model(scene)?;
-        let (position, _rotation, _scale) = affine::trs_decompose(model);
-        Ok(position)
+        Ok(affine::to_translation(model))
     }
 
     pub fn set_world_position(self, scene: &Scene, value: Vec3) -> EcsResult<()> {
-        let position = match self.parent(scene)? {
-            Some(parent_handle) => {
-                let parent_trs = parent_handle.model(scene)?;
-                let (parent_world_position, parent_world_rotation, parent_world_scale) =
-                    affine::trs_decompose(parent_trs);
+        let mut ancestors = Vec::new();
+        let mut option = self.parent(scene)?;
+        while let Some(handle) = option {
+            ancestors.push(handle);
+            option = handle.parent(scene)?;
+        }
 
-                let p = (value - parent_world_position) / parent_world_scale;
-                parent_world_rotation.conjugate().rotate(p)
-            }
-            None => value,
-        };
+        // undo the transform of each ancestor, starting at the root. this is equivalent to
+        // multiplying with the inverse of the parents model, but does not suffer from tiny
+        // determinants when scales are small
+        let mut position = value;
+        for ancestor in ancestors.into_iter().rev() {
+            let ptr = scene.deref(ancestor.into())?;
+            let aref = ptr.borrow();
+            position = aref.rotation.conjugate().rotate(position - aref.position) / aref.scale;
+        }
 
         self.set_local_position(scene, position)?;
         Ok(())
@@ -221,8 +262,7 @@ impl GameObjectHandle {
 
     pub fn world_rotation(self, scene: &Scene) -> EcsResult<Quat> {
         let model = self.model(scene)?;
-        let (_position, rotation, _scale) = affine::trs_decompose(model);
-        Ok(rotation)
+        Ok(affine::decompose_fully(model).rotation)
     }
 
     pub fn set_world_rotation(self, scene: &Scene, value: Quat) -> EcsResult<()> {
@@ -238,16 +278,22 @@ impl GameObjectHandle {
         Ok(())
     }
 
-    pub fn world_scale(self, scene: &Scene) -> EcsResult<f32> {
+    /// the scale along the axes of the world matrix. if a parent is scaled non-uniformly and the
+    /// game object is rotated relative to it, the world matrix is skewed. the skew cannot be
+    /// represented by this scale and is ignored.
+    pub fn world_scale(self, scene: &Scene) -> EcsResult<Vec3> {
         let model = self.model(scene)?;
-        let (_position, _rotation, scale) = affine::trs_decompose(model);
-        Ok(scale)
+        Ok(affine::decompose_fully(model).scale)
     }
 
-    pub fn set_world_scale(self, scene: &Scene, value: f32) -> EcsResult<()> {
+    /// sets the local scale, such that `world_scale` returns `value`. like `world_scale`, this
+    /// ignores skew.
+    pub fn set_world_scale(self, scene: &Scene, value: Vec3) -> EcsResult<()> {
         let scale = match self.parent(scene)? {
-            Some(parent_handle) => {
-                let parent_world_scale = parent_handle.world_scale(scene)?;
+            Some(_) => {
+                let local_scale = self.local_scale(scene)?;
+                let world_scale = self.world_scale(scene)?;
+                let parent_world_scale = world_scale / local_scale;
                 value / parent_world_scale
             }
             None => value,
@@ -401,35 +447,66 @@ impl GameObjectHandle {
         Ok(true)
     }
 
+    /// the local to world matrix. it is cached and only recomputed when the game object or one
+    /// of its ancestors changed.
     pub fn model(self, scene: &Scene) -> EcsResult<Mat4> {
+        // collect dirty game objects, up to the first ancestor with a valid cache
+        let mut dirty = Vec::new();
         let mut model = Mat4::init(1.0);
         let mut option = Some(self);
         while let Some(handle) = option {
             let ptr = scene.deref(handle.into())?;
-            let aref = ptr.borrow();
-
-            model = affine::trs_compose(aref.position, aref.rotation, aref.scale) * model;
+            let cached = *job_system::lock(&ptr.borrow().model);
+            if let Some(cached) = cached {
+                model = cached;
+                break;
+            }
 
-            drop(aref);
+            dirty.push(ptr);
             option = handle.parent(scene)?;
         }
 
+        for ptr in dirty.into_iter().rev() {
+            let aref = ptr.borrow();
+            model = model * affine::trs_compose(aref.position, aref.rotation, aref.scale);
+            *job_system::lock(&aref.model) = Some(model);
+        }
+
         Ok(model)
     }
 
+    /// invalidates the cached model of the game object and all its descendants. descendants of a
+    /// dirty game object are always dirty, thus subtrees that are already dirty are skipped.
+    fn mark_model_dirty(self, scene: &Scene) {
+        let mut stack = vec![self];
+        while let Some(handle) = stack.pop() {
+            let Ok(ptr) = scene.deref(handle.into()) else {
+                continue;
+            };
+
+            let aref = ptr.borrow();
+            let was_cached = job_system::lock(&aref.model).take().is_some();
+            if was_cached {
+                stack.extend(aref.children.iter().copied());
+            }
+        }
+    }
+
+    /// `None` if the parent was destroyed. this only reads the game object, such that parallel
+    /// scripts may call it. the destroyed parent is cleared by `set_parent`
     pub fn parent(self, scene: &Scene) -> EcsResult<Option<GameObjectHandle>> {
         let ptr = scene.deref(self.into())?;
-        let mut aref_mut = ptr.borrow_mut();
+        let parent_handle = ptr.borrow().parent;
 
-        let Some(parent_handle) = aref_mut.parent else {
+        let Some(parent_handle) = parent_handle else {
             return Ok(None);
         };
 
         if parent_handle.is_alive(scene) {
             Ok(Some(parent_handle))
         } else {
-            aref_mut.parent = None;
-            drop(aref_mut);
+            // the cached model may still contain the transform of the destroyed parent
+            self.mark_model_dirty(scene);
             Ok(None)
         }
     }
@@ -498,6 +575,9 @@ impl GameObjectHandle {
         // add game object to new parents children
         if let Some(new_parent) = new_parent {
             let mut new_aref_mut = new_parent.borrow_mut();
+
+            // `sibling_index` skips destroyed children, thus they are cleared before inserting
+            new_aref_mut.children.retain(|x| x.is_alive(scene));
             let position = new_aref_mut.children.iter().position(|x| *x == self);
 
             // only add if it is not a child yet
@@ -510,6 +590,7 @@ impl GameObjectHandle {
         // set parent
         aref_mut.parent = new_handle;
         drop(aref_mut);
+        self.mark_model_dirty(scene);
 
         if let Some((position, rotation, scale)) = world_transform {
             self.set_world_position(scene, position)?;
@@ -520,9 +601,17 @@ impl GameObjectHandle {
         Ok(())
     }
 
+    /// skips destroyed children. this only reads the game object, such that parallel scripts may
+    /// call it. destroyed children are cleared by `set_parent`
     pub fn children(self, scene: &Scene) -> EcsResult<Vec<GameObjectHandle>> {
-        let ptr = self.clear_destroyed_children(scene)?;
-        let children = ptr.borrow().children.clone();
+        let ptr = scene.deref(self.into())?;
+        let children = ptr
+            .borrow()
+            .children
+            .iter()
+            .copied()
+            .filter(|x| x.is_alive(scene))
+            .collect();
         Ok(children)
     }
 
@@ -2,8 +2,6 @@ use std::any::TypeId;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
-use super::components::mesh_renderer::MeshRendererComponent;
-use super::components::script::DynScriptComponent;
 use super::decl::GameObjectHandle;
 use super::error::EcsError;
 use super::error::EcsResult;
@@ -75,8 +73,11 @@ impl DynHandle {
             SceneKind::StaticGameObjct { chunk: _ } if type_id == TypeId::of::<GameObject>() => {
                 true
             }
-            SceneKind::Component if type_id == TypeId::of::<MeshRendererComponent>() => true,
-            SceneKind::Component if type_id == TypeId::of::<DynScriptComponent>() => true,
+            // components are registered at runtime. whether a pool for this type exists is
+            // checked when the handle is dereferenced
+            SceneKind::Component => {
+                type_id != TypeId::of::<GameObject>() && type_id != TypeId::of::<VideoMesh>()
+            }
             SceneKind::Other if type_id == TypeId::of::<VideoMesh>() => true,
             _ => false,
         };
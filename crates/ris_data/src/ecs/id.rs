@@ -22,7 +22,7 @@ pub enum GameObjectKind {
     Static { chunk: usize },
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SceneKind {
     Null,
     DynamicGameObject,
@@ -31,7 +31,7 @@ pub enum SceneKind {
     Other,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct SceneId {
     pub kind: SceneKind,
     pub index: usize,
@@ -70,7 +70,14 @@ pub trait Component: EcsObject {
     fn game_object(&self) -> GameObjectHandle;
     fn game_object_mut(&mut self) -> &mut GameObjectHandle;
     fn serialize(&mut self, stream: &mut SceneWriter) -> RisResult<()>;
-    fn deserialize(&mut self, stream: &mut SceneReader) -> RisResult<()>;
+    /// `version` is the version the data was serialized with. see `version`
+    fn deserialize(&mut self, stream: &mut SceneReader, version: u32) -> RisResult<()>;
+
+    /// the version of the data that `serialize` writes. increment it when the layout changes,
+    /// such that `deserialize` is able to read data of older versions.
+    fn version(&self) -> u32 {
+        0
+    }
 }
 
 pub struct EcsInstance<T: EcsObject> {
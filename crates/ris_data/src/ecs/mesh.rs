@@ -6,6 +6,8 @@ use ris_math::vector::Vec2;
 use ris_math::vector::Vec3;
 use ris_video_data::buffer::Buffer;
 
+use crate::asset_id::AssetId;
+
 use super::decl::VideoMeshHandle;
 use super::id::SceneKind;
 use super::scene::Scene;
@@ -54,6 +56,7 @@ pub struct Mesh {
 
 #[derive(Debug, Default)]
 pub struct VideoMesh {
+    asset_id: Option<AssetId>,
     inner: Option<VideoMeshInner>,
 }
 
@@ -84,6 +87,39 @@ impl VideoMeshHandle {
         Ok(ptr.borrow().handle.into())
     }
 
+    /// returns the video mesh that was created for the given asset. if no such mesh exists yet, a
+    /// new one is created. the returned mesh may not be uploaded yet.
+    pub fn from_asset_id(scene: &Scene, asset_id: AssetId) -> RisResult<Self> {
+        for ptr in scene.video_meshes.iter() {
+            let aref = ptr.borrow();
+            if aref.is_alive && aref.asset_id.as_ref() == Some(&asset_id) {
+                return Ok(aref.handle.into());
+            }
+        }
+
+        let handle = Self::new(scene)?;
+        handle.set_asset_id(scene, Some(asset_id))?;
+        Ok(handle)
+    }
+
+    pub fn asset_id(self, scene: &Scene) -> RisResult<Option<AssetId>> {
+        let ptr = scene.deref(self.into())?;
+        let asset_id = ptr.borrow().asset_id.clone();
+        Ok(asset_id)
+    }
+
+    pub fn set_asset_id(self, scene: &Scene, asset_id: Option<AssetId>) -> RisResult<()> {
+        let ptr = scene.deref(self.into())?;
+        ptr.borrow_mut().asset_id = asset_id;
+        Ok(())
+    }
+
+    pub fn is_uploaded(self, scene: &Scene) -> RisResult<bool> {
+        let ptr = scene.deref(self.into())?;
+        let is_uploaded = ptr.borrow().inner.is_some();
+        Ok(is_uploaded)
+    }
+
     pub fn free(self, scene: &Scene, device: &ash::Device) -> RisResult<()> {
         let ptr = scene.deref(self.into())?;
         let mut aref_mut = ptr.borrow_mut();
@@ -97,7 +133,7 @@ impl VideoMeshHandle {
         scene: &Scene,
         device: &ash::Device,
         physical_device_memory_properties: vk::PhysicalDeviceMemoryProperties,
-        mesh: Mesh,
+        mesh: &Mesh,
     ) -> RisResult<()> {
         let ptr = scene.deref(self.into())?;
         if ptr.borrow_mut().inner.is_some() {
@@ -1,11 +1,15 @@
 pub mod components;
 
+pub mod command_buffer;
+pub mod component_pool;
 pub mod decl;
 pub mod error;
 pub mod game_object;
 pub mod handle;
 pub mod id;
 pub mod mesh;
+pub mod pool;
+pub mod query;
 pub mod registry;
 pub mod scene;
 pub mod scene_stream;
@@ -15,9 +19,12 @@ pub mod script_prelude {
     pub use ris_error::RisResult;
 
     pub use crate::ecs::components::script::Script;
+    pub use crate::ecs::components::script::ScriptAccess;
+    pub use crate::ecs::components::script::ScriptFixedUpdateData;
     pub use crate::ecs::components::script::ScriptInspectData;
     pub use crate::ecs::components::script::ScriptStartEndData;
     pub use crate::ecs::components::script::ScriptUpdateData;
     pub use crate::ecs::scene_stream::SceneReader;
     pub use crate::ecs::scene_stream::SceneWriter;
+    pub use crate::event_bus::EventData;
 }
@@ -0,0 +1,174 @@
+use ris_ptr::ArefCell;
+use ris_ptr::StrongPtr;
+
+use super::error::EcsError;
+use super::error::EcsResult;
+use super::handle::GenericHandle;
+use super::id::EcsInstance;
+use super::id::EcsObject;
+use super::id::EcsPtr;
+use super::id::EcsWeakPtr;
+use super::id::SceneId;
+use super::id::SceneKind;
+
+/// the minimum number of slots a pool grows by, when it runs out of free slots
+pub const MIN_GROWTH: usize = 32;
+
+/// growable storage of ecs objects of a single type.
+///
+/// slots are never removed or moved, thus pointers and handles into the pool stay valid when it
+/// grows. free slots are tracked in a free list, making allocation and destruction O(1). each time
+/// a slot is reused, the generation of its handle is incremented, which invalidates all handles
+/// that refer to the previous object.
+pub struct Pool<T: EcsObject> {
+    kind: SceneKind,
+    growth: usize,
+    ptrs: ArefCell<Vec<EcsPtr<T>>>,
+    free_list: ArefCell<Vec<usize>>,
+}
+
+pub struct PoolIter<'a, T: EcsObject> {
+    pool: &'a Pool<T>,
+    index: usize,
+}
+
+impl<T: EcsObject + Default + 'static> Pool<T> {
+    /// creates a pool with `capacity` preallocated slots. when these are exhausted, the pool grows
+    /// by `capacity` slots, but at least by `MIN_GROWTH`.
+    pub fn new(kind: SceneKind, capacity: usize) -> EcsResult<Self> {
+        let pool = Self {
+            kind,
+            growth: capacity.max(MIN_GROWTH),
+            ptrs: ArefCell::new(Vec::with_capacity(capacity)),
+            free_list: ArefCell::new(Vec::with_capacity(capacity)),
+        };
+
+        pool.grow(capacity)?;
+
+        Ok(pool)
+    }
+
+    /// takes a slot from the free list and revives it with a default value. grows the pool if no
+    /// slot is free.
+    pub fn allocate(&self) -> EcsResult<EcsWeakPtr<T>> {
+        let ptr = loop {
+            let popped = self.free_list.borrow_mut().pop();
+            let index = match popped {
+                Some(index) => index,
+                None => {
+                    self.grow(self.growth)?;
+                    continue;
+                }
+            };
+
+            let Some(ptr) = self.get(index) else {
+                return Err(EcsError::InvalidOperation(format!(
+                    "free list contained index {}, which is out of bounds. pool length: {}",
+                    index,
+                    self.len(),
+                )));
+            };
+
+            // `is_alive` is public and may have been set without going through the pool
+            if !ptr.borrow().is_alive {
+                break ptr;
+            }
+        };
+
+        let old_handle = ptr.borrow().handle;
+        let new_generation = old_handle.generation().wrapping_add(1);
+        let new_handle = GenericHandle::new(old_handle.scene_id(), new_generation)?;
+
+        let mut aref_mut = ptr.borrow_mut();
+        aref_mut.handle = new_handle;
+        aref_mut.is_alive = true;
+        aref_mut.value = T::default();
+        drop(aref_mut);
+
+        Ok(ptr)
+    }
+
+    fn grow(&self, count: usize) -> EcsResult<()> {
+        let mut ptrs = self.ptrs.borrow_mut();
+        let start = ptrs.len();
+        let end = start + count;
+
+        ptrs.reserve(count);
+        for i in start..end {
+            let id = SceneId {
+                kind: self.kind,
+                index: i,
+            };
+            let handle = GenericHandle::new(id, 0)?;
+            let instance = EcsInstance::new(handle);
+            let ptr = StrongPtr::new(ArefCell::new(instance));
+            ptrs.push(ptr);
+        }
+
+        // reversed, such that lower indices are allocated first
+        self.free_list.borrow_mut().extend((start..end).rev());
+
+        Ok(())
+    }
+}
+
+impl<T: EcsObject> Pool<T> {
+    pub fn len(&self) -> usize {
+        self.ptrs.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// number of slots that are currently not in use
+    pub fn free_count(&self) -> usize {
+        self.free_list.borrow().len()
+    }
+
+    pub fn get(&self, index: usize) -> Option<EcsWeakPtr<T>> {
+        self.ptrs.borrow().get(index).map(|x| x.to_weak())
+    }
+
+    /// iterates all slots, alive or not. slots that are added while iterating are visited as well.
+    pub fn iter(&self) -> PoolIter<'_, T> {
+        PoolIter {
+            pool: self,
+            index: 0,
+        }
+    }
+
+    /// marks the object in the given slot as destroyed and returns the slot to the free list.
+    /// does nothing if the object is already destroyed.
+    pub fn free(&self, index: usize) -> EcsResult<()> {
+        let Some(ptr) = self.get(index) else {
+            return Err(EcsError::InvalidOperation(format!(
+                "index {} is out of bounds. pool length: {}",
+                index,
+                self.len(),
+            )));
+        };
+
+        let mut aref_mut = ptr.borrow_mut();
+        if !aref_mut.is_alive {
+            return Ok(());
+        }
+
+        aref_mut.is_alive = false;
+        drop(aref_mut);
+
+        self.free_list.borrow_mut().push(index);
+
+        Ok(())
+    }
+}
+
+impl<T: EcsObject> Iterator for PoolIter<'_, T> {
+    type Item = EcsWeakPtr<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ptr = self.pool.get(self.index)?;
+        self.index += 1;
+        Some(ptr)
+    }
+}
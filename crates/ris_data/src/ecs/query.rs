@@ -0,0 +1,189 @@
+use super::decl::GameObjectHandle;
+use super::error::EcsResult;
+use super::game_object::GameObject;
+use super::game_object::GetFrom;
+use super::handle::GenericHandle;
+use super::id::Component;
+use super::id::GameObjectKind;
+use super::pool::Pool;
+use super::scene::Scene;
+
+/// describes which components a `Query` yields.
+///
+/// implemented for every component `T`, which yields `GenericHandle<T>`, and for tuples of up to
+/// 4 components, which yield tuples of handles. tuples only match game objects that have all
+/// components attached. the first component of the tuple drives the iteration, meaning one item
+/// is yielded per component of the first type. the remaining handles are the first matching
+/// components on the same game object.
+pub trait QueryData {
+    type Driver: Component + 'static;
+    type Item;
+
+    fn fetch(
+        scene: &Scene,
+        game_object: GameObjectHandle,
+        driver: GenericHandle<Self::Driver>,
+    ) -> EcsResult<Option<Self::Item>>;
+}
+
+impl<T: Component + 'static> QueryData for T {
+    type Driver = T;
+    type Item = GenericHandle<T>;
+
+    fn fetch(
+        _scene: &Scene,
+        _game_object: GameObjectHandle,
+        driver: GenericHandle<Self::Driver>,
+    ) -> EcsResult<Option<Self::Item>> {
+        Ok(Some(driver))
+    }
+}
+
+macro_rules! impl_query_data_for_tuple {
+    ($first:ident $(, $rest:ident)*) => {
+        impl<$first: Component + 'static $(, $rest: Component + 'static)*> QueryData
+            for ($first, $($rest,)*)
+        {
+            type Driver = $first;
+            type Item = (GenericHandle<$first>, $(GenericHandle<$rest>,)*);
+
+            #[allow(non_snake_case, unused_variables)]
+            fn fetch(
+                scene: &Scene,
+                game_object: GameObjectHandle,
+                driver: GenericHandle<Self::Driver>,
+            ) -> EcsResult<Option<Self::Item>> {
+                $(
+                    let Some($rest) = game_object.get_component::<$rest>(scene, GetFrom::This)?
+                    else {
+                        return Ok(None);
+                    };
+                )*
+
+                Ok(Some((driver, $($rest,)*)))
+            }
+        }
+    };
+}
+
+impl_query_data_for_tuple!(A);
+impl_query_data_for_tuple!(A, B);
+impl_query_data_for_tuple!(A, B, C);
+impl_query_data_for_tuple!(A, B, C, D);
+
+/// iterates all alive components described by `Q`, together with the game object they are
+/// attached to. created by `Scene::query`.
+pub struct Query<'a, Q: QueryData> {
+    scene: &'a Scene,
+    pool: &'a Pool<Q::Driver>,
+    index: usize,
+    active_only: bool,
+}
+
+/// iterates all alive game objects of a single chunk. created by `Scene::game_objects`.
+pub struct GameObjectQuery<'a> {
+    scene: &'a Scene,
+    chunk: Option<&'a Pool<GameObject>>,
+    index: usize,
+    roots_only: bool,
+}
+
+impl Scene {
+    pub fn query<Q: QueryData>(&self) -> EcsResult<Query<'_, Q>> {
+        let pool = self.component_pool::<Q::Driver>()?;
+        Ok(Query {
+            scene: self,
+            pool,
+            index: 0,
+            active_only: false,
+        })
+    }
+
+    pub fn game_objects(&self, kind: GameObjectKind) -> GameObjectQuery<'_> {
+        let chunk = match kind {
+            GameObjectKind::Dynamic => Some(&self.dynamic_game_objects),
+            GameObjectKind::Static { chunk } => {
+                self.static_chunks.get(chunk).map(|x| &x.game_objects)
+            }
+        };
+
+        GameObjectQuery {
+            scene: self,
+            chunk,
+            index: 0,
+            roots_only: false,
+        }
+    }
+}
+
+impl<Q: QueryData> Query<'_, Q> {
+    /// skips components whose game object is not active in the hierarchy
+    pub fn active_only(mut self) -> Self {
+        self.active_only = true;
+        self
+    }
+}
+
+impl<Q: QueryData> Iterator for Query<'_, Q> {
+    type Item = (GameObjectHandle, Q::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(ptr) = self.pool.get(self.index) {
+            self.index += 1;
+
+            let aref = ptr.borrow();
+            if !aref.is_alive {
+                continue;
+            }
+
+            let handle = aref.handle;
+            let game_object = aref.value.game_object();
+            drop(aref);
+
+            if self.active_only && game_object.is_active_in_hierarchy(self.scene) != Ok(true) {
+                continue;
+            }
+
+            if let Ok(Some(item)) = Q::fetch(self.scene, game_object, handle) {
+                return Some((game_object, item));
+            }
+        }
+
+        None
+    }
+}
+
+impl GameObjectQuery<'_> {
+    /// skips game objects that have a parent
+    pub fn roots_only(mut self) -> Self {
+        self.roots_only = true;
+        self
+    }
+}
+
+impl Iterator for GameObjectQuery<'_> {
+    type Item = GameObjectHandle;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk = self.chunk?;
+        while let Some(ptr) = chunk.get(self.index) {
+            self.index += 1;
+
+            let aref = ptr.borrow();
+            if !aref.is_alive {
+                continue;
+            }
+
+            let handle = GameObjectHandle::from(aref.handle);
+            drop(aref);
+
+            if self.roots_only && handle.parent(self.scene).ok() != Some(None) {
+                continue;
+            }
+
+            return Some(handle);
+        }
+
+        None
+    }
+}
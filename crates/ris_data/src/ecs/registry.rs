@@ -2,14 +2,18 @@ use std::any::TypeId;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
+use ris_debug::sid::Sid;
 use ris_error::RisResult;
 
+use super::component_pool::ComponentPool;
+use super::component_pool::IComponentPool;
 use super::components::mesh_renderer::MeshRendererComponent;
 use super::components::script::DynScript;
 use super::components::script::DynScriptComponent;
 use super::components::script::Script;
 use super::decl::DynScriptComponentHandle;
 use super::decl::GameObjectHandle;
+use super::error::EcsResult;
 use super::handle::DynComponentHandle;
 use super::id::Component;
 use super::scene::Scene;
@@ -25,12 +29,21 @@ pub struct Registry {
 pub trait IComponentFactory: Debug + Send + Sync {
     fn component_id(&self) -> TypeId;
     fn component_name(&self) -> &str;
+    /// identifies the component in serialized scenes. unlike its position in the registry, this
+    /// does not change when other components are registered. it does change when the component
+    /// is renamed.
+    fn component_sid(&self) -> Sid;
     fn make(&self, scene: &Scene, game_object: GameObjectHandle) -> RisResult<DynComponentHandle>;
+    fn create_pool(&self, capacity: usize) -> EcsResult<Box<dyn IComponentPool>>;
 }
 
 pub trait IScriptFactory: Debug + Send + Sync {
     fn script_id(&self) -> TypeId;
     fn script_name(&self) -> &str;
+    /// identifies the script in serialized scenes. unlike its position in the registry, this
+    /// does not change when other scripts are registered. it does change when the script is
+    /// renamed.
+    fn script_sid(&self) -> Sid;
     fn make_and_attach(
         &self,
         scene: &Scene,
@@ -41,17 +54,20 @@ pub trait IScriptFactory: Debug + Send + Sync {
 
 #[derive(Debug)]
 pub struct ComponentFactory<T: Component> {
+    sid: Sid,
     boo: PhantomData<T>,
 }
 
 #[derive(Debug)]
 pub struct ScriptFactory<T: Script + Default> {
+    sid: Sid,
     boo: PhantomData<T>,
 }
 
 impl Registry {
-    fn component<T: Component>() -> RisResult<Box<ComponentFactory<T>>> {
+    pub fn component<T: Component>() -> RisResult<Box<ComponentFactory<T>>> {
         let factory = ComponentFactory {
+            sid: Sid::new(type_name::<T>()),
             boo: PhantomData::<T>,
         };
 
@@ -60,6 +76,7 @@ impl Registry {
 
     pub fn script<T: Script + Default>() -> RisResult<Box<ScriptFactory<T>>> {
         let factory = ScriptFactory {
+            sid: Sid::new(type_name::<T>()),
             boo: PhantomData::<T>,
         };
 
@@ -78,7 +95,7 @@ impl Registry {
                 let left_id = left.script_id();
                 let right_id = right.script_id();
 
-                if left_id == right_id {
+                if left_id == right_id || left.script_sid().hash == right.script_sid().hash {
                     return ris_error::new_result!(
                         "script id collision detected!\n[{}]: {:?} -> {:?}\n[{}]: {:?} -> {:?}\n",
                         i,
@@ -98,6 +115,29 @@ impl Registry {
         })
     }
 
+    /// registers a native component. every scene that is created with this registry allocates
+    /// a pool for it.
+    pub fn register_component(&mut self, factory: Box<dyn IComponentFactory>) -> RisResult<()> {
+        let id = factory.component_id();
+        let sid = factory.component_sid().hash;
+        let collision = self
+            .components
+            .iter()
+            .find(|x| x.component_id() == id || x.component_sid().hash == sid);
+        if let Some(collision) = collision {
+            return ris_error::new_result!(
+                "component id collision detected!\n{:?} -> {:?}\n{:?} -> {:?}\n",
+                collision,
+                collision.component_id(),
+                factory,
+                id,
+            );
+        }
+
+        self.components.push(factory);
+        Ok(())
+    }
+
     pub fn component_factories(&self) -> &[Box<dyn IComponentFactory>] {
         &self.components
     }
@@ -105,6 +145,22 @@ impl Registry {
     pub fn script_factories(&self) -> &[Box<dyn IScriptFactory>] {
         &self.scripts
     }
+
+    /// finds the component by the hash of its `component_sid`
+    pub fn find_component_factory(&self, sid: u32) -> Option<&dyn IComponentFactory> {
+        self.components
+            .iter()
+            .find(|x| x.component_sid().hash == sid)
+            .map(|x| x.as_ref())
+    }
+
+    /// finds the script by the hash of its `script_sid`
+    pub fn find_script_factory(&self, sid: u32) -> Option<&dyn IScriptFactory> {
+        self.scripts
+            .iter()
+            .find(|x| x.script_sid().hash == sid)
+            .map(|x| x.as_ref())
+    }
 }
 
 impl<T: Component + Default + Send + Sync + 'static> IComponentFactory for ComponentFactory<T> {
@@ -113,14 +169,22 @@ impl<T: Component + Default + Send + Sync + 'static> IComponentFactory for Compo
     }
 
     fn component_name(&self) -> &str {
-        let type_name = std::any::type_name::<T>();
-        ris_util::reflection::trim_type_name(type_name)
+        type_name::<T>()
+    }
+
+    fn component_sid(&self) -> Sid {
+        self.sid.clone()
     }
 
     fn make(&self, scene: &Scene, game_object: GameObjectHandle) -> RisResult<DynComponentHandle> {
         let handle = game_object.add_component::<T>(scene)?;
         Ok(handle.into())
     }
+
+    fn create_pool(&self, capacity: usize) -> EcsResult<Box<dyn IComponentPool>> {
+        let pool = ComponentPool::<T>::new(capacity)?;
+        Ok(Box::new(pool))
+    }
 }
 
 impl<T: Script + Default + 'static> IScriptFactory for ScriptFactory<T> {
@@ -129,8 +193,11 @@ impl<T: Script + Default + 'static> IScriptFactory for ScriptFactory<T> {
     }
 
     fn script_name(&self) -> &str {
-        let type_name = std::any::type_name::<T>();
-        ris_util::reflection::trim_type_name(type_name)
+        type_name::<T>()
+    }
+
+    fn script_sid(&self) -> Sid {
+        self.sid.clone()
     }
 
     fn make_and_attach(
@@ -148,6 +215,11 @@ impl<T: Script + Default + 'static> IScriptFactory for ScriptFactory<T> {
     }
 }
 
+fn type_name<T>() -> &'static str {
+    let type_name = std::any::type_name::<T>();
+    ris_util::reflection::trim_type_name(type_name)
+}
+
 pub fn init(scripts: Vec<Box<dyn IScriptFactory>>) -> RisResult<()> {
     let new_registry = Registry::new(scripts)?;
     unsafe { REGISTRY = Some(new_registry) };
@@ -1,9 +1,12 @@
 use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::Mutex;
 
+use ris_jobs::job_system;
 use ris_ptr::ArefCell;
-use ris_ptr::StrongPtr;
 
-use super::components::mesh_renderer::MeshRendererComponent;
+use super::component_pool::ComponentPool;
+use super::component_pool::IComponentPool;
 use super::components::script::DynScriptComponent;
 use super::decl::GameObjectHandle;
 use super::error::EcsError;
@@ -13,22 +16,23 @@ use super::handle::DynComponentHandle;
 use super::handle::DynHandle;
 use super::handle::GenericHandle;
 use super::id::Component;
-use super::id::EcsInstance;
 use super::id::EcsObject;
-use super::id::EcsPtr;
 use super::id::EcsWeakPtr;
+use super::id::GameObjectKind;
 use super::id::SceneId;
 use super::id::SceneKind;
 use super::mesh::VideoMesh;
+use super::pool::Pool;
 use super::registry::Registry;
 
 const DEFAULT_DYNAMIC_GAME_OBJECTS: usize = 1024;
 const DEFAULT_STATIC_CHUNKS: usize = 8;
 const DEFAULT_GAME_OBJECTS_PER_STATIC_CHUNK: usize = 1024;
-const DEFAULT_MESH_RENDERER_COMPONENTS: usize = 1024;
-const DEFAULT_SCRIPT_COMPONENTS: usize = 1024;
+const DEFAULT_COMPONENTS: usize = 1024;
 const DEFAULT_VIDEO_MESHES: usize = 1024;
 
+/// describes the initial capacities of the pools of a scene. pools grow on demand, when their
+/// capacity is exhausted. the number of static chunks is fixed.
 #[derive(Debug)]
 pub struct SceneCreateInfo {
     // game objects
@@ -37,8 +41,9 @@ pub struct SceneCreateInfo {
     pub game_objects_per_static_chunk: usize,
 
     // components
-    pub mesh_renderer_components: usize,
-    pub script_components: usize,
+    /// initial capacity of each component pool, unless overwritten by `component_capacities`
+    pub components: usize,
+    pub component_capacities: HashMap<TypeId, usize>,
 
     // other
     pub video_meshes: usize,
@@ -47,21 +52,23 @@ pub struct SceneCreateInfo {
 
 pub struct StaticChunk {
     is_reserved: ArefCell<bool>,
-    pub game_objects: Vec<EcsPtr<GameObject>>,
+    pub game_objects: Pool<GameObject>,
 }
 
 pub struct Scene {
     // game objects
-    pub dynamic_game_objects: Vec<EcsPtr<GameObject>>,
+    pub dynamic_game_objects: Pool<GameObject>,
     pub static_chunks: Vec<StaticChunk>,
 
     // compontents
-    pub mesh_renderer_components: Vec<EcsPtr<MeshRendererComponent>>,
-    pub script_components: Vec<EcsPtr<DynScriptComponent>>,
+    component_pools: Vec<Box<dyn IComponentPool>>,
 
     // other
-    pub video_meshes: Vec<EcsPtr<VideoMesh>>,
+    pub video_meshes: Pool<VideoMesh>,
     pub registry: Registry,
+
+    // scripts, whose game object changed its active state. true means enabled
+    activation_changes: Mutex<Vec<(GenericHandle<DynScriptComponent>, bool)>>,
 }
 
 impl Default for SceneCreateInfo {
@@ -70,8 +77,8 @@ impl Default for SceneCreateInfo {
             dynamic_game_objects: DEFAULT_DYNAMIC_GAME_OBJECTS,
             static_chunks: DEFAULT_STATIC_CHUNKS,
             game_objects_per_static_chunk: DEFAULT_GAME_OBJECTS_PER_STATIC_CHUNK,
-            mesh_renderer_components: DEFAULT_MESH_RENDERER_COMPONENTS,
-            script_components: DEFAULT_SCRIPT_COMPONENTS,
+            components: DEFAULT_COMPONENTS,
+            component_capacities: HashMap::new(),
             video_meshes: DEFAULT_VIDEO_MESHES,
             registry: None,
         }
@@ -84,12 +91,17 @@ impl SceneCreateInfo {
             dynamic_game_objects: 0,
             static_chunks: 0,
             game_objects_per_static_chunk: 0,
-            mesh_renderer_components: 0,
-            script_components: 0,
+            components: 0,
+            component_capacities: HashMap::new(),
             video_meshes: 0,
             registry: None,
         }
     }
+
+    pub fn set_component_capacity<T: Component + 'static>(&mut self, capacity: usize) {
+        self.component_capacities
+            .insert(TypeId::of::<T>(), capacity);
+    }
 }
 
 impl Scene {
@@ -106,12 +118,12 @@ impl Scene {
         };
 
         let dynamic_game_objects =
-            create_chunk(SceneKind::DynamicGameObject, info.dynamic_game_objects)?;
+            Pool::new(SceneKind::DynamicGameObject, info.dynamic_game_objects)?;
 
         let mut static_chunks = Vec::with_capacity(info.static_chunks);
         for i in 0..info.static_chunks {
             let kind = SceneKind::StaticGameObjct { chunk: i };
-            let game_objects = create_chunk(kind, info.game_objects_per_static_chunk)?;
+            let game_objects = Pool::new(kind, info.game_objects_per_static_chunk)?;
             let chunk = StaticChunk {
                 is_reserved: ArefCell::new(false),
                 game_objects,
@@ -119,19 +131,26 @@ impl Scene {
             static_chunks.push(chunk);
         }
 
-        let mesh_renderer_components =
-            create_chunk(SceneKind::Component, info.mesh_renderer_components)?;
-        let script_components = create_chunk(SceneKind::Component, info.script_components)?;
+        let mut component_pools = Vec::new();
+        for factory in registry.component_factories() {
+            let capacity = info
+                .component_capacities
+                .get(&factory.component_id())
+                .copied()
+                .unwrap_or(info.components);
+            let pool = factory.create_pool(capacity)?;
+            component_pools.push(pool);
+        }
 
-        let video_meshes = create_chunk(SceneKind::Other, info.video_meshes)?;
+        let video_meshes = Pool::new(SceneKind::Other, info.video_meshes)?;
 
         Ok(Self {
             dynamic_game_objects,
             static_chunks,
-            mesh_renderer_components,
-            script_components,
+            component_pools,
             video_meshes,
             registry,
+            activation_changes: Mutex::new(Vec::new()),
         })
     }
 
@@ -149,6 +168,28 @@ impl Scene {
         position
     }
 
+    /// reserves the chunk at the given index. returns false if it is out of bounds or already
+    /// reserved.
+    pub fn reserve_chunk_at(&self, index: usize) -> bool {
+        let Some(chunk) = self.static_chunks.get(index) else {
+            return false;
+        };
+
+        if *chunk.is_reserved.borrow() {
+            return false;
+        }
+
+        *chunk.is_reserved.borrow_mut() = true;
+        true
+    }
+
+    pub fn is_chunk_reserved(&self, index: usize) -> bool {
+        self.static_chunks
+            .get(index)
+            .map(|x| *x.is_reserved.borrow())
+            .unwrap_or(false)
+    }
+
     pub fn clear_chunk(&self, index: usize) {
         ris_error::throw_debug_assert!(index < self.static_chunks.len(), "index was out of bounds",);
         let chunk = &self.static_chunks[index];
@@ -169,20 +210,66 @@ impl Scene {
         *chunk.is_reserved.borrow_mut() = false;
     }
 
+    pub(super) fn queue_activation_change(
+        &self,
+        script: GenericHandle<DynScriptComponent>,
+        enabled: bool,
+    ) {
+        job_system::lock(&self.activation_changes).push((script, enabled));
+    }
+
+    /// calls `on_enable` and `on_disable` on all scripts, whose game object changed its
+    /// `is_active_in_hierarchy` since the last call. these are not called by `set_active`
+    /// directly, because the script that changes the game object may be borrowed at that time.
+    pub fn notify_scripts(&self) {
+        let changes = std::mem::take(&mut *job_system::lock(&self.activation_changes));
+
+        for (script, enabled) in changes {
+            // scripts may be destroyed in the meantime
+            let Ok(ptr) = self.deref(script) else {
+                continue;
+            };
+
+            let mut aref_mut = ptr.borrow_mut();
+
+            // scripts that are not started yet, for example while a scene is being deserialized
+            if aref_mut.type_id().is_none() {
+                continue;
+            }
+
+            let result = if enabled {
+                aref_mut.on_enable(self)
+            } else {
+                aref_mut.on_disable(self)
+            };
+
+            if let Err(e) = result {
+                ris_log::error!("failed to notify script {:?}: {}", aref_mut.type_name(), e);
+            }
+        }
+    }
+
     pub fn deref<T: EcsObject + 'static>(
         &self,
         handle: GenericHandle<T>,
     ) -> EcsResult<EcsWeakPtr<T>> {
-        let chunk = self.find_chunk(handle.scene_id().kind)?;
+        let pool = self.find_chunk(handle.scene_id().kind)?;
         let index = handle.scene_id().index;
-        let ptr = &chunk[index];
+        let Some(ptr) = pool.get(index) else {
+            return Err(EcsError::InvalidOperation(format!(
+                "index {} is out of bounds. pool length: {}",
+                index,
+                pool.len(),
+            )));
+        };
         let aref = ptr.borrow();
 
         let is_alive = aref.is_alive;
         let generation_matches = aref.handle.generation() == handle.generation();
+        drop(aref);
 
         if is_alive && generation_matches {
-            Ok(ptr.to_weak())
+            Ok(ptr)
         } else {
             Err(EcsError::ObjectIsDestroyed)
         }
@@ -192,42 +279,20 @@ impl Scene {
         &self,
         kind: SceneKind,
     ) -> EcsResult<EcsWeakPtr<T>> {
-        let chunk = self.find_chunk(kind)?;
-
-        let Some(position) = chunk.iter().position(|x| !x.borrow().is_alive) else {
-            return Err(EcsError::OutOfMemory);
-        };
-
-        let ptr = &chunk[position];
-        let old_handle = ptr.borrow().handle;
-        let new_generation = old_handle.generation().wrapping_add(1);
-        let new_handle = GenericHandle::new(old_handle.scene_id(), new_generation)?;
-
-        let mut aref_mut = ptr.borrow_mut();
-        aref_mut.handle = new_handle;
-        aref_mut.is_alive = true;
-        aref_mut.value = T::default();
-        drop(aref_mut);
-
-        Ok(ptr.to_weak())
+        self.find_chunk(kind)?.allocate()
     }
 
     pub fn mark_as_destroyed(&self, handle: DynHandle) -> EcsResult<()> {
         let SceneId { kind, index } = handle.scene_id();
         let type_id = handle.type_id();
 
-        if type_id == TypeId::of::<GameObject>() {
-            let chunk = self.find_chunk::<GameObject>(kind)?;
-            chunk[index].borrow_mut().is_alive = false;
-        } else if type_id == TypeId::of::<MeshRendererComponent>() {
-            let chunk = self.find_chunk::<MeshRendererComponent>(kind)?;
-            chunk[index].borrow_mut().is_alive = false;
-        } else if type_id == TypeId::of::<DynScriptComponent>() {
-            let chunk = self.find_chunk::<DynScriptComponent>(kind)?;
-            chunk[index].borrow_mut().is_alive = false;
+        if kind == SceneKind::Component {
+            let pool = self.find_component_pool(type_id)?;
+            pool.mark_as_destroyed(index)?;
+        } else if type_id == TypeId::of::<GameObject>() {
+            self.find_chunk::<GameObject>(kind)?.free(index)?;
         } else if type_id == TypeId::of::<VideoMesh>() {
-            let chunk = self.find_chunk::<VideoMesh>(kind)?;
-            chunk[index].borrow_mut().is_alive = false;
+            self.find_chunk::<VideoMesh>(kind)?.free(index)?;
         } else {
             return Err(EcsError::InvalidCast);
         }
@@ -241,23 +306,22 @@ impl Scene {
         callback: impl FnOnce(&dyn Component) -> T,
     ) -> EcsResult<T> {
         let SceneId { kind, index } = handle.scene_id();
-        let type_id = handle.type_id();
 
         if kind != SceneKind::Component {
             return Err(EcsError::InvalidCast);
         }
 
-        let retval = if type_id == TypeId::of::<MeshRendererComponent>() {
-            let aref = self.mesh_renderer_components[index].borrow();
-            callback(&aref.value)
-        } else if type_id == TypeId::of::<DynScriptComponent>() {
-            let aref = self.script_components[index].borrow();
-            callback(&aref.value)
-        } else {
-            return Err(EcsError::InvalidCast);
-        };
+        let pool = self.find_component_pool(handle.type_id())?;
+
+        let mut callback = Some(callback);
+        let mut retval = None;
+        pool.deref_component(index, &mut |component| {
+            if let Some(callback) = callback.take() {
+                retval = Some(callback(component));
+            }
+        })?;
 
-        Ok(retval)
+        retval.ok_or(EcsError::InvalidCast)
     }
 
     pub fn deref_mut_component<T>(
@@ -266,40 +330,60 @@ impl Scene {
         callback: impl FnOnce(&mut dyn Component) -> T,
     ) -> EcsResult<T> {
         let SceneId { kind, index } = handle.scene_id();
-        let type_id = handle.type_id();
 
         if kind != SceneKind::Component {
             return Err(EcsError::InvalidCast);
         }
 
-        let retval = if type_id == TypeId::of::<MeshRendererComponent>() {
-            let mut aref = self.mesh_renderer_components[index].borrow_mut();
-            callback(&mut aref.value)
-        } else if type_id == TypeId::of::<DynScriptComponent>() {
-            let mut aref = self.script_components[index].borrow_mut();
-            callback(&mut aref.value)
-        } else {
-            return Err(EcsError::InvalidCast);
-        };
+        let pool = self.find_component_pool(handle.type_id())?;
+
+        let mut callback = Some(callback);
+        let mut retval = None;
+        pool.deref_mut_component(index, &mut |component| {
+            if let Some(callback) = callback.take() {
+                retval = Some(callback(component));
+            }
+        })?;
+
+        retval.ok_or(EcsError::InvalidCast)
+    }
+
+    /// returns the storage of all components of type `T`. fails if `T` is not registered in the
+    /// registry of this scene.
+    pub fn component_pool<T: Component + 'static>(&self) -> EcsResult<&Pool<T>> {
+        self.find_chunk::<T>(SceneKind::Component)
+    }
+
+    /// returns the storage of all game objects of the given kind
+    pub fn game_object_pool(&self, kind: GameObjectKind) -> EcsResult<&Pool<GameObject>> {
+        self.find_chunk::<GameObject>(kind.into())
+    }
 
-        Ok(retval)
+    fn find_component_pool(&self, type_id: TypeId) -> EcsResult<&dyn IComponentPool> {
+        self.component_pools
+            .iter()
+            .find(|x| x.component_id() == type_id)
+            .map(|x| x.as_ref())
+            .ok_or(EcsError::TypeDoesNotMatchSceneKind)
     }
 
-    fn find_chunk<T: EcsObject + 'static>(&self, kind: SceneKind) -> EcsResult<&[EcsPtr<T>]> {
+    fn find_chunk<T: EcsObject + 'static>(&self, kind: SceneKind) -> EcsResult<&Pool<T>> {
         match kind {
             SceneKind::Null => Err(EcsError::IsNull),
             SceneKind::DynamicGameObject => cast_chunk(&self.dynamic_game_objects),
-            SceneKind::StaticGameObjct { chunk } => {
-                cast_chunk(&self.static_chunks[chunk].game_objects)
-            }
+            SceneKind::StaticGameObjct { chunk } => match self.static_chunks.get(chunk) {
+                Some(chunk) => cast_chunk(&chunk.game_objects),
+                None => Err(EcsError::InvalidOperation(format!(
+                    "chunk {} is out of bounds. chunk count: {}",
+                    chunk,
+                    self.static_chunks.len(),
+                ))),
+            },
             SceneKind::Component => {
-                let type_id = TypeId::of::<T>();
-                if type_id == TypeId::of::<MeshRendererComponent>() {
-                    cast_chunk(&self.mesh_renderer_components)
-                } else if type_id == TypeId::of::<DynScriptComponent>() {
-                    cast_chunk(&self.script_components)
-                } else {
-                    Err(EcsError::TypeDoesNotMatchSceneKind)
+                let pool = self.find_component_pool(TypeId::of::<T>())?;
+                match pool.as_any().downcast_ref::<ComponentPool<T>>() {
+                    Some(pool) => Ok(&pool.pool),
+                    None => Err(EcsError::InvalidCast),
                 }
             }
             SceneKind::Other => {
@@ -314,31 +398,15 @@ impl Scene {
     }
 }
 
-fn create_chunk<T: EcsObject + Default + 'static>(
-    kind: SceneKind,
-    capacity: usize,
-) -> EcsResult<Vec<EcsPtr<T>>> {
-    let mut result = Vec::with_capacity(capacity);
-    for i in 0..capacity {
-        let id = SceneId { kind, index: i };
-        let handle = GenericHandle::new(id, 0)?;
-        let instance = EcsInstance::new(handle);
-        let ptr = StrongPtr::new(ArefCell::new(instance));
-        result.push(ptr);
-    }
-
-    Ok(result)
-}
-
 fn cast_chunk<T: EcsObject + 'static, U: EcsObject + 'static>(
-    chunk: &[EcsPtr<T>],
-) -> EcsResult<&[EcsPtr<U>]> {
+    chunk: &Pool<T>,
+) -> EcsResult<&Pool<U>> {
     if TypeId::of::<T>() != TypeId::of::<U>() {
         return Err(EcsError::InvalidCast);
     }
 
     // transmute is safe, because T is equal to U
-    let result = unsafe { std::mem::transmute::<&[EcsPtr<T>], &[EcsPtr<U>]>(chunk) };
+    let result = unsafe { std::mem::transmute::<&Pool<T>, &Pool<U>>(chunk) };
 
     Ok(result)
 }
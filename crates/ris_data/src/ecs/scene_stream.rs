@@ -10,12 +10,12 @@ use ris_io::FatPtr;
 
 use crate::asset_id::AssetId;
 use crate::ecs::decl::GameObjectHandle;
-use crate::ecs::id::SceneKind;
+use crate::ecs::id::GameObjectKind;
 use crate::ecs::scene::Scene;
 
 pub struct SceneWriter<'a> {
     stream: Cursor<Vec<u8>>,
-    chunk: usize,
+    kind: GameObjectKind,
     pub scene: &'a Scene,
     placeholders: Vec<FatPtr>,
     assets_ids: Vec<AssetId>,
@@ -23,17 +23,19 @@ pub struct SceneWriter<'a> {
 
 pub struct SceneReader<'a> {
     stream: Cursor<Vec<u8>>,
-    chunk: usize,
+    kind: GameObjectKind,
     pub scene: &'a Scene,
     pub lookup: Vec<usize>,
     assets_ids: Vec<AssetId>,
 }
 
 impl<'a> SceneWriter<'a> {
-    pub fn new(chunk: usize, scene: &'a Scene) -> Self {
+    /// `kind` is the kind of the game objects that are serialized. game objects may only
+    /// reference other game objects of the same kind.
+    pub fn new(kind: GameObjectKind, scene: &'a Scene) -> Self {
         Self {
             stream: Cursor::new(Vec::new()),
-            chunk,
+            kind,
             scene,
             placeholders: Vec::new(),
             assets_ids: Vec::new(),
@@ -62,15 +64,10 @@ impl<'a> SceneWriter<'a> {
 
     pub fn write_game_object(&mut self, game_object: GameObjectHandle) -> RisResult<FatPtr> {
         let scene_id = game_object.0.scene_id();
-        let SceneKind::StaticGameObjct { chunk } = scene_id.kind else {
-            return ris_error::new_result!(
-                "can only serialize static game objects. kind was: {:?}",
-                scene_id.kind
-            );
-        };
+        let kind = GameObjectKind::try_from(scene_id.kind)?;
 
-        if self.chunk != chunk {
-            return ris_error::new_result!("during serialization, a chunk may only reference gameobjects in the same chunk. expected: {} actual: {}", self.chunk, chunk);
+        if self.kind != kind {
+            return ris_error::new_result!("during serialization, a game object may only reference game objects of the same kind. expected: {:?} actual: {:?}", self.kind, kind);
         }
 
         let fat_ptr = ris_io::write_uint(self, scene_id.index)?;
@@ -96,10 +93,16 @@ impl<'a> SceneWriter<'a> {
 }
 
 impl<'a> SceneReader<'a> {
-    pub fn new(chunk: usize, scene: &'a Scene, data: Vec<u8>, assets_ids: Vec<AssetId>) -> Self {
+    /// `kind` is the kind of the game objects that are deserialized into.
+    pub fn new(
+        kind: GameObjectKind,
+        scene: &'a Scene,
+        data: Vec<u8>,
+        assets_ids: Vec<AssetId>,
+    ) -> Self {
         Self {
             stream: Cursor::new(data),
-            chunk,
+            kind,
             scene,
             lookup: Vec::new(),
             assets_ids,
@@ -109,11 +112,12 @@ impl<'a> SceneReader<'a> {
     pub fn read_game_object(&mut self) -> RisResult<GameObjectHandle> {
         let index = ris_io::read_uint(self)?;
         let scene_index = self.lookup.get(index).into_ris_error()?;
-        let game_object: GameObjectHandle = self.scene.static_chunks[self.chunk].game_objects
-            [*scene_index]
-            .borrow()
-            .handle
-            .into();
+        let ptr = self
+            .scene
+            .game_object_pool(self.kind)?
+            .get(*scene_index)
+            .into_ris_error()?;
+        let game_object: GameObjectHandle = ptr.borrow().handle.into();
 
         Ok(game_object)
     }
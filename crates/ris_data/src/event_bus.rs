@@ -0,0 +1,184 @@
+use std::any::Any;
+use std::any::TypeId;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use ris_error::RisResult;
+use ris_jobs::job_system;
+
+use crate::ecs::decl::GameObjectHandle;
+use crate::god_state::GodState;
+
+pub trait Event: Any + Send + Sync {}
+impl<T: Any + Send + Sync> Event for T {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubscriptionId(usize);
+
+pub struct EventData<'a, E: Event> {
+    pub event: &'a E,
+    /// the game object the event was published to. `None` if it was published to everyone
+    pub target: Option<GameObjectHandle>,
+    pub state: &'a GodState,
+}
+
+type Callback =
+    Arc<dyn Fn(&dyn Any, Option<GameObjectHandle>, &GodState) -> RisResult<()> + Send + Sync>;
+
+struct PendingEvent {
+    type_id: TypeId,
+    target: Option<GameObjectHandle>,
+    event: Box<dyn Any + Send + Sync>,
+}
+
+struct Subscriber {
+    id: SubscriptionId,
+    type_id: TypeId,
+    target: Option<GameObjectHandle>,
+    callback: Callback,
+}
+
+/// typed events between scripts and engine systems.
+///
+/// events may be published from any thread. they are queued and delivered to their subscribers
+/// when `dispatch` is called, which happens once per frame in `god_job`, after all scripts are
+/// updated. events that are published while dispatching are delivered by the next dispatch.
+#[derive(Default)]
+pub struct EventBus {
+    next_id: AtomicUsize,
+    pending: Mutex<Vec<PendingEvent>>,
+    subscribers: Mutex<Vec<Subscriber>>,
+}
+
+impl std::fmt::Debug for EventBus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventBus")
+            .field("pending", &self.pending_count())
+            .field("subscribers", &self.subscriber_count())
+            .finish()
+    }
+}
+
+impl EventBus {
+    /// publishes an event to everyone that subscribed to its type
+    pub fn publish<E: Event>(&self, event: E) {
+        self.push(None, event);
+    }
+
+    /// publishes an event to the subscribers of `target`, and to everyone that subscribed to its
+    /// type
+    pub fn publish_to<E: Event>(&self, target: GameObjectHandle, event: E) {
+        self.push(Some(target), event);
+    }
+
+    /// receives all events of type `E`, regardless of their target
+    pub fn subscribe<E: Event>(
+        &self,
+        callback: impl Fn(EventData<E>) -> RisResult<()> + Send + Sync + 'static,
+    ) -> SubscriptionId {
+        self.add_subscriber(None, callback)
+    }
+
+    /// receives events of type `E` that were published to `target`. the subscription is removed
+    /// when `target` is destroyed.
+    pub fn subscribe_to<E: Event>(
+        &self,
+        target: GameObjectHandle,
+        callback: impl Fn(EventData<E>) -> RisResult<()> + Send + Sync + 'static,
+    ) -> SubscriptionId {
+        self.add_subscriber(Some(target), callback)
+    }
+
+    pub fn unsubscribe(&self, id: SubscriptionId) {
+        let mut subscribers = job_system::lock(&self.subscribers);
+        subscribers.retain(|x| x.id != id);
+    }
+
+    pub fn pending_count(&self) -> usize {
+        job_system::lock(&self.pending).len()
+    }
+
+    pub fn subscriber_count(&self) -> usize {
+        job_system::lock(&self.subscribers).len()
+    }
+
+    /// delivers all pending events, in the order they were published
+    pub fn dispatch(&self, state: &GodState) -> RisResult<()> {
+        let pending = std::mem::take(&mut *job_system::lock(&self.pending));
+        if pending.is_empty() {
+            return Ok(());
+        }
+
+        // subscribers are cloned, such that callbacks may subscribe and unsubscribe
+        let subscribers = {
+            let mut subscribers = job_system::lock(&self.subscribers);
+            subscribers.retain(|x| x.target.map(|t| t.is_alive(&state.scene)).unwrap_or(true));
+            subscribers
+                .iter()
+                .map(|x| (x.type_id, x.target, x.callback.clone()))
+                .collect::<Vec<_>>()
+        };
+
+        for event in pending {
+            for (type_id, target, callback) in subscribers.iter() {
+                if *type_id != event.type_id {
+                    continue;
+                }
+
+                if target.is_some() && *target != event.target {
+                    continue;
+                }
+
+                callback(event.event.as_ref(), event.target, state)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn push<E: Event>(&self, target: Option<GameObjectHandle>, event: E) {
+        let pending_event = PendingEvent {
+            type_id: TypeId::of::<E>(),
+            target,
+            event: Box::new(event),
+        };
+
+        job_system::lock(&self.pending).push(pending_event);
+    }
+
+    fn add_subscriber<E: Event>(
+        &self,
+        target: Option<GameObjectHandle>,
+        callback: impl Fn(EventData<E>) -> RisResult<()> + Send + Sync + 'static,
+    ) -> SubscriptionId {
+        let id = SubscriptionId(self.next_id.fetch_add(1, Ordering::Relaxed));
+
+        let callback: Callback = Arc::new(move |event, target, state| {
+            let Some(event) = event.downcast_ref::<E>() else {
+                return ris_error::new_result!(
+                    "event is not of type {}",
+                    std::any::type_name::<E>()
+                );
+            };
+
+            callback(EventData {
+                event,
+                target,
+                state,
+            })
+        });
+
+        let subscriber = Subscriber {
+            id,
+            type_id: TypeId::of::<E>(),
+            target,
+            callback,
+        };
+
+        job_system::lock(&self.subscribers).push(subscriber);
+
+        id
+    }
+}
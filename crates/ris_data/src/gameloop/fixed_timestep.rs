@@ -0,0 +1,69 @@
+use std::time::Duration;
+
+pub const DEFAULT_TIMESTEP: Duration = Duration::from_micros(1_000_000 / 50); // 50 hz
+pub const DEFAULT_MAX_STEPS: usize = 5;
+
+/// accumulates variable frame durations and turns them into a number of fixed steps.
+///
+/// time that is left over is carried into the next frame. to prevent a spiral of death, at most
+/// `max_steps` are taken per frame. time that exceeds these steps is discarded.
+#[derive(Debug, Clone)]
+pub struct FixedTimestep {
+    timestep: Duration,
+    max_steps: usize,
+    accumulator: Duration,
+}
+
+impl Default for FixedTimestep {
+    fn default() -> Self {
+        Self {
+            timestep: DEFAULT_TIMESTEP,
+            max_steps: DEFAULT_MAX_STEPS,
+            accumulator: Duration::ZERO,
+        }
+    }
+}
+
+impl FixedTimestep {
+    pub fn new(timestep: Duration, max_steps: usize) -> Self {
+        // a timestep of zero would result in an infinite number of steps
+        let timestep = timestep.max(Duration::from_nanos(1));
+
+        Self {
+            timestep,
+            max_steps,
+            accumulator: Duration::ZERO,
+        }
+    }
+
+    pub fn timestep(&self) -> Duration {
+        self.timestep
+    }
+
+    pub fn max_steps(&self) -> usize {
+        self.max_steps
+    }
+
+    /// adds `delta` to the accumulator and returns how many fixed steps should be taken
+    pub fn advance(&mut self, delta: Duration) -> usize {
+        self.accumulator += delta;
+
+        let mut steps = 0;
+        while self.accumulator >= self.timestep && steps < self.max_steps {
+            self.accumulator -= self.timestep;
+            steps += 1;
+        }
+
+        if steps == self.max_steps && self.accumulator >= self.timestep {
+            self.accumulator = Duration::ZERO;
+        }
+
+        steps
+    }
+
+    /// how far the accumulator is between the last and the next step, in the range [0, 1).
+    /// useful to interpolate between fixed steps
+    pub fn alpha(&self) -> f32 {
+        self.accumulator.as_secs_f32() / self.timestep.as_secs_f32()
+    }
+}
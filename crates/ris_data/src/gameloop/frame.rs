@@ -82,6 +82,15 @@ impl FrameCalculator {
 }
 
 impl Frame {
+    /// creates a frame with known durations, for example when replaying recorded frames
+    pub fn new(number: usize, previous: Duration, average: Duration) -> Self {
+        Self {
+            number,
+            previous,
+            average,
+        }
+    }
+
     pub fn number(&self) -> usize {
         self.number
     }
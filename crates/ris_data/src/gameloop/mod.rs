@@ -1,2 +1,3 @@
+pub mod fixed_timestep;
 pub mod frame;
 pub mod gameloop_state;
@@ -3,8 +3,11 @@ use std::sync::Arc;
 use ris_error::RisResult;
 use ris_math::camera::Camera;
 
+use crate::ecs::command_buffer::CommandBuffer;
 use crate::ecs::scene::Scene;
 use crate::ecs::scene::SceneCreateInfo;
+use crate::event_bus::EventBus;
+use crate::input::player_data::RumbleQueue;
 use crate::input::Input;
 use crate::settings::Settings;
 
@@ -13,10 +16,13 @@ pub struct GodState {
     // events
     pub event_rebuild_renderers: bool,
     pub event_window_resized: Option<(u32, u32)>,
+    pub events: Arc<EventBus>,
 
     // general
     pub input: Input,
+    pub rumble: Arc<RumbleQueue>,
     pub scene: Arc<Scene>,
+    pub commands: Arc<CommandBuffer>,
     pub camera: Camera,
 
     pub debug_ui_is_focused: bool,
@@ -31,10 +37,13 @@ impl GodState {
             // events
             event_rebuild_renderers: false,
             event_window_resized: None,
+            events: Arc::new(EventBus::default()),
 
             // general
             input: Input::default(),
+            rumble: Arc::new(RumbleQueue::default()),
             scene: Arc::new(Scene::new(info)?),
+            commands: Arc::new(CommandBuffer::default()),
             camera: Camera::default(),
 
             debug_ui_is_focused: false,
@@ -5,6 +5,8 @@ use ris_error::RisResult;
 pub const NO_RESTART_ARG: &str = "--no-restart";
 pub const WORKERS_ARG: &str = "--workers";
 pub const ASSETS_ARG: &str = "--assets";
+pub const RECORD_INPUT_ARG: &str = "--record-input";
+pub const REPLAY_INPUT_ARG: &str = "--replay-input";
 
 pub const DEFAULT_ASSETS_VALUE: &str = "assets/in_use";
 
@@ -15,6 +17,10 @@ pub struct ArgsInfo {
     pub no_restart: bool,
     pub workers: Option<usize>,
     pub assets: String,
+    /// file to which the input of every frame is recorded
+    pub record_input: Option<String>,
+    /// file from which recorded input is replayed, instead of polling devices
+    pub replay_input: Option<String>,
 }
 
 #[cfg(debug_assertions)]
@@ -25,6 +31,8 @@ fn create_with_default_values(raw_args: Vec<String>, executable_path: String) ->
         no_restart: false,
         workers: None,
         assets: String::from(DEFAULT_ASSETS_VALUE),
+        record_input: None,
+        replay_input: None,
     }
 }
 
@@ -36,6 +44,8 @@ fn create_with_default_values(raw_args: Vec<String>, executable_path: String) ->
         no_restart: false,
         workers: None,
         assets: String::from("ris_assets"),
+        record_input: None,
+        replay_input: None,
     }
 }
 
@@ -99,12 +109,30 @@ impl ArgsInfo {
                     let second_arg = result.get_arg(i)?;
                     result.assets = String::from(second_arg);
                 }
+                RECORD_INPUT_ARG => {
+                    i += 1;
+                    let second_arg = result.get_arg(i)?;
+                    result.record_input = Some(String::from(second_arg));
+                }
+                REPLAY_INPUT_ARG => {
+                    i += 1;
+                    let second_arg = result.get_arg(i)?;
+                    result.replay_input = Some(String::from(second_arg));
+                }
                 _ => return ris_error::new_result!("unexpected argument: [{}] -> {}", i, arg),
             };
 
             i += 1;
         }
 
+        if result.record_input.is_some() && result.replay_input.is_some() {
+            return ris_error::new_result!(
+                "{} and {} cannot be used at the same time",
+                RECORD_INPUT_ARG,
+                REPLAY_INPUT_ARG,
+            );
+        }
+
         Ok(result)
     }
 
@@ -125,6 +153,16 @@ impl ArgsInfo {
         result.push(String::from(ASSETS_ARG));
         result.push(String::from(&self.assets));
 
+        if let Some(record_input) = &self.record_input {
+            result.push(String::from(RECORD_INPUT_ARG));
+            result.push(String::from(record_input));
+        }
+
+        if let Some(replay_input) = &self.replay_input {
+            result.push(String::from(REPLAY_INPUT_ARG));
+            result.push(String::from(replay_input));
+        }
+
         result
     }
 
@@ -32,3 +32,76 @@ pub const A29: u32 = 0x20000000;
 pub const A30: u32 = 0x40000000;
 pub const A31: u32 = 0x80000000;
 pub const ANY: u32 = 0xffffffff;
+
+/// the names of the actions, indexed by their bit. used to store bindings in the settings
+pub const NAMES: [&str; 32] = [
+    "ok",
+    "a1",
+    "a2",
+    "a3",
+    "a4",
+    "a5",
+    "a6",
+    "a7",
+    "a8",
+    "a9",
+    "a10",
+    "a11",
+    "a12",
+    "a13",
+    "a14",
+    "move_up",
+    "move_down",
+    "move_left",
+    "move_right",
+    "camera_up",
+    "camera_down",
+    "camera_left",
+    "camera_right",
+    "a23",
+    "a24",
+    "a25",
+    "a26",
+    "a27",
+    "a28",
+    "a29",
+    "a30",
+    "a31",
+];
+
+/// returns the index of the action with the given name
+pub fn index_of(name: &str) -> Option<usize> {
+    NAMES.iter().position(|x| *x == name)
+}
+
+/// formats all actions in `actions` as names, seperated by `|`. returns `none` if no action is
+/// set
+pub fn format(actions: u32) -> String {
+    if actions == NONE {
+        return "none".to_string();
+    }
+
+    NAMES
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| actions & (1 << i) != 0)
+        .map(|(_, name)| *name)
+        .collect::<Vec<_>>()
+        .join(" | ")
+}
+
+/// the reverse of `format`
+pub fn parse(value: &str) -> Option<u32> {
+    let value = value.trim();
+    if value == "none" {
+        return Some(NONE);
+    }
+
+    let mut actions = NONE;
+    for name in value.split('|') {
+        let index = index_of(name.trim())?;
+        actions |= 1 << index;
+    }
+
+    Some(actions)
+}
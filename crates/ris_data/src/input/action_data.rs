@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use ris_math::vector::Vec2;
+
+#[derive(Default, Clone, Copy)]
+struct DigitalState {
+    prev: bool,
+    state: bool,
+}
+
+/// the values of the named actions of an action map. actions that do not exist are never pressed
+/// and have a value of zero
+#[derive(Default, Clone)]
+pub struct ActionData {
+    digital: HashMap<String, DigitalState>,
+    analog: HashMap<String, Vec2>,
+}
+
+impl ActionData {
+    pub fn update_digital(&mut self, name: &str, value: bool) {
+        match self.digital.get_mut(name) {
+            Some(digital) => {
+                digital.prev = digital.state;
+                digital.state = value;
+            }
+            None => {
+                let digital = DigitalState {
+                    prev: false,
+                    state: value,
+                };
+                self.digital.insert(name.to_string(), digital);
+            }
+        }
+    }
+
+    pub fn set_analog(&mut self, name: &str, value: Vec2) {
+        match self.analog.get_mut(name) {
+            Some(analog) => *analog = value,
+            None => {
+                self.analog.insert(name.to_string(), value);
+            }
+        }
+    }
+
+    pub fn is_up(&self, name: &str) -> bool {
+        let digital = self.digital(name);
+        !digital.state && digital.prev
+    }
+
+    pub fn is_down(&self, name: &str) -> bool {
+        let digital = self.digital(name);
+        digital.state && !digital.prev
+    }
+
+    pub fn is_hold(&self, name: &str) -> bool {
+        self.digital(name).state
+    }
+
+    /// the value of a one dimensional action
+    pub fn axis(&self, name: &str) -> f32 {
+        self.vec2(name).0
+    }
+
+    pub fn vec2(&self, name: &str) -> Vec2 {
+        self.analog.get(name).copied().unwrap_or_default()
+    }
+
+    fn digital(&self, name: &str) -> DigitalState {
+        self.digital.get(name).copied().unwrap_or_default()
+    }
+}
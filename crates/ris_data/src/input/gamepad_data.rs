@@ -1,6 +1,43 @@
 use crate::input::buttons::Buttons;
 use crate::input::rebind_matrix::RebindMatrix;
 
+/// the names of the gamepad buttons, indexed by their bit. sticks and triggers are buttons as
+/// well, when they are pushed past `GamepadData::axis_button_threshhold`
+pub const BUTTON_NAMES: [&str; 32] = [
+    "a",
+    "b",
+    "x",
+    "y",
+    "back",
+    "guide",
+    "start",
+    "left_stick",
+    "right_stick",
+    "left_shoulder",
+    "right_shoulder",
+    "dpad_up",
+    "dpad_down",
+    "dpad_left",
+    "dpad_right",
+    "left_stick_up",
+    "left_stick_down",
+    "left_stick_left",
+    "left_stick_right",
+    "right_stick_up",
+    "right_stick_down",
+    "right_stick_left",
+    "right_stick_right",
+    "left_trigger",
+    "right_trigger",
+    "button25",
+    "button26",
+    "button27",
+    "button28",
+    "button29",
+    "button30",
+    "button31",
+];
+
 #[derive(Clone)]
 pub struct GamepadData {
     pub buttons: Buttons,
@@ -1,6 +1,8 @@
 use super::buttons::Buttons;
+use super::gestures::Gestures;
 
 #[derive(Default, Clone)]
 pub struct GeneralData {
     pub buttons: Buttons,
+    pub gestures: Gestures,
 }
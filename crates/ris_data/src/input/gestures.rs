@@ -0,0 +1,186 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use crate::input::buttons::Buttons;
+
+/// presses older than this are forgotten
+pub const DEFAULT_HISTORY: Duration = Duration::from_secs(2);
+const MAX_PRESSES: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Press {
+    action: u32,
+    time: Duration,
+    consumed: bool,
+}
+
+/// remembers when buttons were pressed, to detect gestures that span multiple frames. time is the
+/// sum of all frame durations since the first update.
+///
+/// queries take a mask of actions. a query is true if it is true for any action in the mask.
+#[derive(Debug, Clone)]
+pub struct Gestures {
+    time: Duration,
+    history: Duration,
+    held_since: [Option<Duration>; 32],
+    presses: VecDeque<Press>,
+}
+
+impl Default for Gestures {
+    fn default() -> Self {
+        Self::new(DEFAULT_HISTORY)
+    }
+}
+
+impl Gestures {
+    /// `history` limits the windows of all queries
+    pub fn new(history: Duration) -> Self {
+        Self {
+            time: Duration::ZERO,
+            history,
+            held_since: [None; 32],
+            presses: VecDeque::new(),
+        }
+    }
+
+    /// `delta` is the duration of the previous frame
+    pub fn update(&mut self, buttons: &Buttons, delta: Duration) {
+        self.time += delta;
+
+        let down = buttons.down();
+        let hold = buttons.hold();
+
+        for (i, held_since) in self.held_since.iter_mut().enumerate() {
+            let action = 1 << i;
+
+            if down & action != 0 {
+                *held_since = Some(self.time);
+                self.presses.push_back(Press {
+                    action,
+                    time: self.time,
+                    consumed: false,
+                });
+            } else if hold & action == 0 {
+                *held_since = None;
+            }
+        }
+
+        while let Some(press) = self.presses.front() {
+            let expired = self.time - press.time > self.history;
+            if !expired && self.presses.len() <= MAX_PRESSES {
+                break;
+            }
+
+            self.presses.pop_front();
+        }
+    }
+
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+
+    /// how long the action is held, or zero if it is not held. a press in this frame is held for
+    /// zero.
+    pub fn hold_duration(&self, actions: u32) -> Duration {
+        self.held_since
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| actions & (1 << i) != 0)
+            .filter_map(|(_, x)| x.map(|since| self.time - since))
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// true while the action is held for at least `duration`
+    pub fn is_held_for(&self, actions: u32, duration: Duration) -> bool {
+        self.held_since
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| actions & (1 << i) != 0)
+            .any(|(_, x)| x.is_some_and(|since| self.time - since >= duration))
+    }
+
+    /// input buffering: true if the action was pressed within `window` and the press was not
+    /// consumed yet
+    pub fn is_buffered(&self, actions: u32, window: Duration) -> bool {
+        self.presses_within(window)
+            .any(|x| x.action & actions != 0 && !x.consumed)
+    }
+
+    /// consumes all buffered presses of the action, such that a single press does not trigger
+    /// twice. returns whether a press was buffered.
+    pub fn consume(&mut self, actions: u32, window: Duration) -> bool {
+        let mut result = false;
+
+        for press in self.presses.iter_mut().rev() {
+            if self.time - press.time > window {
+                break;
+            }
+
+            if press.action & actions != 0 && !press.consumed {
+                press.consumed = true;
+                result = true;
+            }
+        }
+
+        result
+    }
+
+    /// true in the frame the action is pressed the second time, if the first press was at most
+    /// `window` before it
+    pub fn is_double_tap(&self, actions: u32, window: Duration) -> bool {
+        for i in 0..32 {
+            let action = 1 << i;
+            if actions & action == 0 {
+                continue;
+            }
+
+            let mut presses = self
+                .presses
+                .iter()
+                .rev()
+                .filter(|x| x.action == action)
+                .take(2);
+
+            let (Some(second), Some(first)) = (presses.next(), presses.next()) else {
+                continue;
+            };
+
+            if second.time == self.time && second.time - first.time <= window {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// true in the frame the last step of `sequence` is pressed, if the most recent presses
+    /// match the sequence and all of them happened within `window`. every step is a mask of
+    /// actions, of which any may be pressed.
+    pub fn is_combo(&self, sequence: &[u32], window: Duration) -> bool {
+        if sequence.is_empty() || sequence.len() > self.presses.len() {
+            return false;
+        }
+
+        let recent = self.presses.iter().rev().take(sequence.len());
+        let mut first_time = self.time;
+
+        for (press, step) in recent.zip(sequence.iter().rev()) {
+            if press.action & step == 0 {
+                return false;
+            }
+
+            first_time = press.time;
+        }
+
+        let last = &self.presses[self.presses.len() - 1];
+        last.time == self.time && self.time - first_time <= window
+    }
+
+    fn presses_within(&self, window: Duration) -> impl Iterator<Item = &Press> {
+        self.presses
+            .iter()
+            .rev()
+            .take_while(move |x| self.time - x.time <= window)
+    }
+}
@@ -1,14 +1,27 @@
 use sdl2::keyboard::Mod;
 use sdl2::keyboard::Scancode;
 
+use crate::input::action;
 use crate::input::buttons::Buttons;
 use crate::input::keys::Keys;
 use crate::input::rebind_matrix::RebindMatrix;
 
+// the values of all scancodes. `Scancode::from_i32` must not be called with values that are not
+// a scancode
+const SCANCODE_RANGES: [std::ops::RangeInclusive<i32>; 5] =
+    [4..=129, 133..=164, 176..=221, 224..=231, 257..=286];
+
+/// maps every action bit to the key that triggers it. `None` means the action is unbound
+pub type Keymask = [Option<Scancode>; 32];
+
+/// the names of the keyboard buttons, indexed by their bit. a keyboard button is a slot of the
+/// keymask, thus it shares the name of the action that the slot triggers by default
+pub const BUTTON_NAMES: [&str; 32] = action::NAMES;
+
 #[derive(Clone)]
 pub struct KeyboardData {
     pub buttons: Buttons,
-    pub keymask: [Scancode; 32],
+    pub keymask: Keymask,
     pub keys: Keys,
     pub mod_state: Mod,
     pub text_input: Vec<String>,
@@ -16,7 +29,7 @@ pub struct KeyboardData {
 }
 
 impl KeyboardData {
-    pub fn new(keymask: [Scancode; 32]) -> Self {
+    pub fn new(keymask: Keymask) -> Self {
         Self {
             buttons: Buttons::default(),
             keymask,
@@ -30,6 +43,46 @@ impl KeyboardData {
 
 impl Default for KeyboardData {
     fn default() -> Self {
-        Self::new([Scancode::A; 32])
+        Self::new(default_keymask())
     }
 }
+
+pub fn default_keymask() -> Keymask {
+    let mut keymask = [None; 32];
+
+    let bindings = [
+        (action::OK, Scancode::Return),
+        (action::MOVE_UP, Scancode::W),
+        (action::MOVE_DOWN, Scancode::S),
+        (action::MOVE_LEFT, Scancode::A),
+        (action::MOVE_RIGHT, Scancode::D),
+        (action::CAMERA_UP, Scancode::Up),
+        (action::CAMERA_DOWN, Scancode::Down),
+        (action::CAMERA_LEFT, Scancode::Left),
+        (action::CAMERA_RIGHT, Scancode::Right),
+        (action::A28, Scancode::Kp8),
+        (action::A29, Scancode::Kp2),
+        (action::A30, Scancode::Kp4),
+        (action::A31, Scancode::Kp6),
+    ];
+
+    for (action, scancode) in bindings {
+        keymask[action.trailing_zeros() as usize] = Some(scancode);
+    }
+
+    keymask
+}
+
+/// the name of the scancode, as it is written in the settings
+pub fn scancode_name(scancode: Scancode) -> String {
+    format!("{:?}", scancode)
+}
+
+/// the reverse of `scancode_name`
+pub fn scancode_from_name(name: &str) -> Option<Scancode> {
+    SCANCODE_RANGES
+        .into_iter()
+        .flatten()
+        .filter_map(Scancode::from_i32)
+        .find(|x| scancode_name(*x) == name)
+}
@@ -1,21 +1,41 @@
 pub mod action;
+pub mod action_data;
 pub mod buttons;
 pub mod gamepad_data;
 pub mod general_data;
+pub mod gestures;
 pub mod keyboard_data;
 pub mod keys;
 pub mod mouse_data;
+pub mod player_data;
 pub mod rebind_matrix;
 
+use crate::input::action_data::ActionData;
 use crate::input::gamepad_data::GamepadData;
 use crate::input::general_data::GeneralData;
 use crate::input::keyboard_data::KeyboardData;
 use crate::input::mouse_data::MouseData;
+use crate::input::player_data::PlayerData;
 
 #[derive(Default, Clone)]
 pub struct Input {
     pub mouse: MouseData,
     pub keyboard: KeyboardData,
+    /// the controller that was used most recently
     pub gamepad: GamepadData,
     pub general: GeneralData,
+    pub actions: ActionData,
+    /// every controller is bound to its own player. players keep their index when their
+    /// controller is removed
+    pub players: Vec<PlayerData>,
+}
+
+impl Input {
+    pub fn player(&self, index: usize) -> Option<&PlayerData> {
+        self.players.get(index)
+    }
+
+    pub fn player_mut(&mut self, index: usize) -> Option<&mut PlayerData> {
+        self.players.get_mut(index)
+    }
 }
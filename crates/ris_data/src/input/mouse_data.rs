@@ -1,6 +1,14 @@
 use crate::input::buttons::Buttons;
 use crate::input::rebind_matrix::RebindMatrix;
 
+/// the names of the mouse buttons, indexed by their bit
+pub const BUTTON_NAMES: [&str; 32] = [
+    "left", "middle", "right", "x1", "x2", "button5", "button6", "button7", "button8", "button9",
+    "button10", "button11", "button12", "button13", "button14", "button15", "button16", "button17",
+    "button18", "button19", "button20", "button21", "button22", "button23", "button24", "button25",
+    "button26", "button27", "button28", "button29", "button30", "button31",
+];
+
 #[derive(Default, Clone)]
 pub struct MouseData {
     pub buttons: Buttons,
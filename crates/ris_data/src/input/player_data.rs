@@ -0,0 +1,110 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use ris_jobs::job_system;
+
+use crate::input::gamepad_data::GamepadData;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ControllerType {
+    #[default]
+    Unknown,
+    Xbox,
+    PlayStation,
+    NintendoSwitch,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ControllerInfo {
+    pub instance_id: u32,
+    pub name: String,
+    pub controller_type: ControllerType,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rumble {
+    /// 0 to 1
+    pub low_frequency: f32,
+    /// 0 to 1
+    pub high_frequency: f32,
+    pub duration: Duration,
+}
+
+/// the input of a single local player
+#[derive(Default, Clone)]
+pub struct PlayerData {
+    /// `None` while no controller is bound to this player, for example after it was removed
+    pub controller: Option<ControllerInfo>,
+    pub gamepad: GamepadData,
+
+    /// true in the frame a controller was bound to this player
+    pub joined: bool,
+    /// true in the frame the controller of this player was removed
+    pub left: bool,
+}
+
+/// rumble requests for the controllers of all players. requests may be made from any thread,
+/// for example by scripts through `GodState::rumble`. they are sent to the controllers when the
+/// devices are polled the next time
+#[derive(Debug, Default)]
+pub struct RumbleQueue {
+    pending: Mutex<Vec<(usize, Rumble)>>,
+}
+
+impl ControllerType {
+    /// guesses the type by the name of the controller
+    pub fn from_name(name: &str) -> Self {
+        let name = name.to_lowercase();
+        let contains_any = |patterns: &[&str]| patterns.iter().any(|x| name.contains(x));
+
+        if contains_any(&["xbox", "xinput"]) {
+            Self::Xbox
+        } else if contains_any(&["playstation", "ps3", "ps4", "ps5", "dualshock", "dualsense"]) {
+            Self::PlayStation
+        } else if contains_any(&["nintendo", "switch", "joy-con"]) {
+            Self::NintendoSwitch
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
+impl PlayerData {
+    pub fn is_connected(&self) -> bool {
+        self.controller.is_some()
+    }
+}
+
+impl RumbleQueue {
+    /// a later request for the same player replaces an earlier one
+    pub fn request(
+        &self,
+        player: usize,
+        low_frequency: f32,
+        high_frequency: f32,
+        duration: Duration,
+    ) {
+        let rumble = Rumble {
+            low_frequency,
+            high_frequency,
+            duration,
+        };
+
+        job_system::lock(&self.pending).push((player, rumble));
+    }
+
+    /// removes all requests and returns the latest one of each player, indexed by the player.
+    /// requests for players beyond `player_count` are discarded
+    pub fn take(&self, player_count: usize) -> Vec<Option<Rumble>> {
+        let pending = std::mem::take(&mut *job_system::lock(&self.pending));
+
+        let mut result = vec![None; player_count];
+        for (player, rumble) in pending {
+            if let Some(entry) = result.get_mut(player) {
+                *entry = Some(rumble);
+            }
+        }
+
+        result
+    }
+}
@@ -5,6 +5,7 @@ pub mod input;
 pub mod settings;
 
 pub mod asset_id;
+pub mod event_bus;
 pub mod god_state;
 pub mod ris_map;
 pub mod ris_yaml;
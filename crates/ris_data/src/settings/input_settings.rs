@@ -0,0 +1,92 @@
+use crate::input::keyboard_data;
+use crate::input::keyboard_data::Keymask;
+use crate::input::rebind_matrix::RebindMatrix;
+use crate::input::Input;
+
+#[derive(Clone)]
+pub struct InputSettings {
+    changed: bool,
+
+    keymask: Keymask,
+    mouse_rebind_matrix: RebindMatrix,
+    keyboard_rebind_matrix: RebindMatrix,
+    gamepad_rebind_matrix: RebindMatrix,
+}
+
+impl Default for InputSettings {
+    fn default() -> Self {
+        Self {
+            changed: false,
+
+            keymask: keyboard_data::default_keymask(),
+            mouse_rebind_matrix: RebindMatrix::default(),
+            keyboard_rebind_matrix: RebindMatrix::default(),
+            gamepad_rebind_matrix: RebindMatrix::default(),
+        }
+    }
+}
+
+impl InputSettings {
+    pub fn changed(&self) -> bool {
+        self.changed
+    }
+
+    pub fn reset(&mut self) {
+        self.changed = false;
+    }
+
+    pub fn restore_default(&mut self) {
+        *self = Self::default();
+        self.changed = true;
+    }
+
+    /// copies the bindings into the input
+    pub fn apply(&self, input: &mut Input) {
+        input.keyboard.keymask = self.keymask;
+        RebindMatrix::copy(&self.mouse_rebind_matrix, &mut input.mouse.rebind_matrix);
+        RebindMatrix::copy(
+            &self.keyboard_rebind_matrix,
+            &mut input.keyboard.rebind_matrix,
+        );
+        RebindMatrix::copy(
+            &self.gamepad_rebind_matrix,
+            &mut input.gamepad.rebind_matrix,
+        );
+    }
+
+    pub fn get_keymask(&self) -> &Keymask {
+        &self.keymask
+    }
+
+    pub fn set_keymask(&mut self, value: Keymask) {
+        self.changed = true;
+        self.keymask = value;
+    }
+
+    pub fn get_mouse_rebind_matrix(&self) -> &RebindMatrix {
+        &self.mouse_rebind_matrix
+    }
+
+    pub fn set_mouse_rebind_matrix(&mut self, value: RebindMatrix) {
+        self.changed = true;
+        self.mouse_rebind_matrix = value;
+    }
+
+    pub fn get_keyboard_rebind_matrix(&self) -> &RebindMatrix {
+        &self.keyboard_rebind_matrix
+    }
+
+    pub fn set_keyboard_rebind_matrix(&mut self, value: RebindMatrix) {
+        self.changed = true;
+        self.keyboard_rebind_matrix = value;
+    }
+
+    pub fn get_gamepad_rebind_matrix(&self) -> &RebindMatrix {
+        &self.gamepad_rebind_matrix
+    }
+
+    pub fn set_gamepad_rebind_matrix(&mut self, value: RebindMatrix) {
+        self.changed = true;
+        self.gamepad_rebind_matrix = value;
+    }
+}
@@ -1 +1,7 @@
 pub const JOB_WORKERS: &str = "job.workers";
+
+// input keys are followed by the name of an action, for example `input.keymask.move_up`
+pub const INPUT_KEYMASK: &str = "input.keymask.";
+pub const INPUT_MOUSE_REBIND: &str = "input.mouse_rebind.";
+pub const INPUT_KEYBOARD_REBIND: &str = "input.keyboard_rebind.";
+pub const INPUT_GAMEPAD_REBIND: &str = "input.gamepad_rebind.";
@@ -1,7 +1,9 @@
+pub mod input_settings;
 pub mod job_settings;
 pub mod key;
 pub mod serializer;
 
+use input_settings::InputSettings;
 use job_settings::JobSettings;
 
 use crate::info::app_info::AppInfo;
@@ -12,6 +14,7 @@ pub struct Settings {
     save_requested: bool,
 
     job: JobSettings,
+    input: InputSettings,
 }
 
 impl Settings {
@@ -21,11 +24,12 @@ impl Settings {
             save_requested: false,
 
             job: JobSettings::new(app_info),
+            input: InputSettings::default(),
         }
     }
 
     pub fn changed(&self) -> bool {
-        self.changed || self.job.changed()
+        self.changed || self.job.changed() || self.input.changed()
     }
 
     pub fn reset(&mut self) {
@@ -35,6 +39,7 @@ impl Settings {
         }
 
         self.job.reset();
+        self.input.reset();
     }
 
     pub fn save_requested(&self) -> bool {
@@ -53,4 +58,12 @@ impl Settings {
     pub fn job_mut(&mut self) -> &mut JobSettings {
         &mut self.job
     }
+
+    pub fn input(&self) -> &InputSettings {
+        &self.input
+    }
+
+    pub fn input_mut(&mut self) -> &mut InputSettings {
+        &mut self.input
+    }
 }
@@ -4,6 +4,10 @@ use ris_error::RisResult;
 use ris_io::fallback_file::FallbackFileOverwrite;
 
 use crate::info::app_info::AppInfo;
+use crate::input::action;
+use crate::input::gamepad_data;
+use crate::input::keyboard_data;
+use crate::input::mouse_data;
 use crate::ris_yaml::RisYaml;
 use crate::settings::key;
 use crate::settings::Settings;
@@ -75,6 +79,49 @@ fn write_bytes(settings: &Settings) -> RisResult<Vec<u8>> {
     );
     yaml.add_entry(None, None);
 
+    let input = settings.input();
+    yaml.add_entry(None, Some("input: the key that triggers each action"));
+    for (i, scancode) in input.get_keymask().iter().enumerate() {
+        let value = match scancode {
+            Some(scancode) => keyboard_data::scancode_name(*scancode),
+            None => "none".to_string(),
+        };
+
+        let key = format!("{}{}", key::INPUT_KEYMASK, action::NAMES[i]);
+        yaml.add_entry(Some((&key, &value)), None);
+    }
+    yaml.add_entry(None, None);
+
+    yaml.add_entry(
+        None,
+        Some("input: the actions that each button triggers, seperated by `|`"),
+    );
+    let rebind_matrices = [
+        (
+            key::INPUT_MOUSE_REBIND,
+            mouse_data::BUTTON_NAMES,
+            input.get_mouse_rebind_matrix(),
+        ),
+        (
+            key::INPUT_KEYBOARD_REBIND,
+            keyboard_data::BUTTON_NAMES,
+            input.get_keyboard_rebind_matrix(),
+        ),
+        (
+            key::INPUT_GAMEPAD_REBIND,
+            gamepad_data::BUTTON_NAMES,
+            input.get_gamepad_rebind_matrix(),
+        ),
+    ];
+    for (prefix, button_names, rebind_matrix) in rebind_matrices {
+        for (i, actions) in rebind_matrix.data.iter().enumerate() {
+            let key = format!("{}{}", prefix, button_names[i]);
+            let value = action::format(*actions);
+            yaml.add_entry(Some((&key, &value)), None);
+        }
+    }
+    yaml.add_entry(None, None);
+
     let string = yaml.serialize()?;
 
     let bytes = string.as_bytes().to_vec();
@@ -87,17 +134,85 @@ fn read_bytes(bytes: &[u8], app_info: &AppInfo) -> RisResult<Settings> {
     let mut result = Settings::new(app_info);
     let yaml = RisYaml::deserialize(string)?;
 
+    let mut keymask = *result.input.get_keymask();
+    let mut mouse_rebind_matrix = result.input.get_mouse_rebind_matrix().clone();
+    let mut keyboard_rebind_matrix = result.input.get_keyboard_rebind_matrix().clone();
+    let mut gamepad_rebind_matrix = result.input.get_gamepad_rebind_matrix().clone();
+
     for (i, entry) in yaml.entries.iter().enumerate() {
         let (key, value) = match entry.key_value.as_ref() {
             Some(key_value) => key_value,
             None => continue,
         };
 
+        if let Some(name) = key.strip_prefix(key::INPUT_KEYMASK) {
+            let index = parse_action(name, i)?;
+            keymask[index] = match value.as_str() {
+                "none" => None,
+                value => match keyboard_data::scancode_from_name(value) {
+                    Some(scancode) => Some(scancode),
+                    None => {
+                        return ris_error::new_result!("unknown key \"{}\" at line {}", value, i)
+                    }
+                },
+            };
+            continue;
+        }
+
+        let rebind_matrix = [
+            (
+                key::INPUT_MOUSE_REBIND,
+                mouse_data::BUTTON_NAMES,
+                &mut mouse_rebind_matrix,
+            ),
+            (
+                key::INPUT_KEYBOARD_REBIND,
+                keyboard_data::BUTTON_NAMES,
+                &mut keyboard_rebind_matrix,
+            ),
+            (
+                key::INPUT_GAMEPAD_REBIND,
+                gamepad_data::BUTTON_NAMES,
+                &mut gamepad_rebind_matrix,
+            ),
+        ]
+        .into_iter()
+        .find_map(|(prefix, names, x)| key.strip_prefix(prefix).map(|name| (name, names, x)));
+
+        if let Some((name, button_names, rebind_matrix)) = rebind_matrix {
+            let Some(index) = button_names.iter().position(|x| *x == name) else {
+                return ris_error::new_result!("unknown button \"{}\" at line {}", name, i);
+            };
+            rebind_matrix.data[index] = match action::parse(value) {
+                Some(actions) => actions,
+                None => {
+                    return ris_error::new_result!("unknown actions \"{}\" at line {}", value, i)
+                }
+            };
+            continue;
+        }
+
         match key.as_str() {
             key::JOB_WORKERS => result.job.set_workers(value.parse()?),
             _ => return ris_error::new_result!("unkown key at line {}", i),
         }
     }
 
+    result.input.set_keymask(keymask);
+    result.input.set_mouse_rebind_matrix(mouse_rebind_matrix);
+    result
+        .input
+        .set_keyboard_rebind_matrix(keyboard_rebind_matrix);
+    result
+        .input
+        .set_gamepad_rebind_matrix(gamepad_rebind_matrix);
+
     Ok(result)
 }
+
+fn parse_action(name: &str, line: usize) -> RisResult<usize> {
+    match action::index_of(name) {
+        Some(index) => Ok(index),
+        None => ris_error::new_result!("unknown action \"{}\" at line {}", name, line),
+    }
+}
@@ -6,6 +6,18 @@ pub struct Sid {
 }
 
 impl Sid {
+    /// hashes the value at runtime. produces the same hash as `sid!`, for values that are not
+    /// known at compile time
+    pub fn new(value: impl Into<String>) -> Self {
+        let value = value.into();
+        let bytes = value.as_bytes();
+
+        let mut hash = PRIME;
+        crate::const_hash!(hash, bytes);
+
+        Self::from(hash, value)
+    }
+
     pub fn from(hash: u32, value: String) -> Self {
         #[cfg(feature = "store_sid_values")]
         {
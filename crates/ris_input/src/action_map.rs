@@ -0,0 +1,347 @@
+use sdl2::controller::Axis;
+use sdl2::keyboard::Scancode;
+
+use ris_data::input::action;
+use ris_data::input::Input;
+use ris_error::RisResult;
+use ris_math::vector::Vec2;
+
+pub const MOVE: &str = "move";
+pub const LOOK: &str = "look";
+
+pub const DEFAULT_DEAD_ZONE: f32 = 0.15;
+pub const DEFAULT_THRESHOLD: f32 = 0.5;
+
+/// a single value of a device
+#[derive(Debug, Clone, PartialEq)]
+pub enum AxisSource {
+    /// 1 while the key is held, 0 otherwise
+    Key(Scancode),
+    /// 1 while the bit of the general buttons is held. these combine all devices through their
+    /// rebind matrices, thus respect rebinds from the settings
+    General(usize),
+    /// 1 while the bit of the keyboard buttons is held. the keymask binds keys to these bits
+    KeyboardButton(usize),
+    MouseButton(usize),
+    GamepadButton(usize),
+    /// -1 to 1. the y axes of the sticks point down
+    GamepadAxis(Axis),
+    /// the distance the mouse moved this frame, in pixels
+    MouseX,
+    MouseY,
+    /// the distance the mouse wheel moved this frame
+    WheelX,
+    WheelY,
+    /// `positive` minus `negative`, for example two keys that form an axis
+    Composite {
+        negative: Box<AxisSource>,
+        positive: Box<AxisSource>,
+    },
+    Invert(Box<AxisSource>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DigitalBinding {
+    /// pressed while the absolute value of the source is at least `threshold`
+    Source { source: AxisSource, threshold: f32 },
+    /// pressed while the general button is held
+    General(u32),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnalogBinding {
+    Axis(AxisSource),
+    Vec2 { x: AxisSource, y: AxisSource },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ResponseCurve {
+    Linear,
+    /// raises the magnitude to the power of the exponent. exponents greater than 1 make small
+    /// movements more precise
+    Power(f32),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DigitalAction {
+    pub name: String,
+    pub bindings: Vec<DigitalBinding>,
+}
+
+/// the value of an analog action is the value of the binding with the largest magnitude.
+///
+/// values of mouse movements are multiplied by `sensitivity`. all other values are normalized:
+/// their magnitude is at most 1, and the `dead_zone` and `curve` are applied to it. the dead zone
+/// is radial, such that diagonal stick movement is not cut off.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnalogAction {
+    pub name: String,
+    pub bindings: Vec<AnalogBinding>,
+    pub dead_zone: f32,
+    pub curve: ResponseCurve,
+    pub sensitivity: f32,
+}
+
+/// maps the devices to named actions, which are written to `Input::actions`
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ActionMap {
+    digital: Vec<DigitalAction>,
+    analog: Vec<AnalogAction>,
+}
+
+impl AxisSource {
+    pub fn keys(negative: Scancode, positive: Scancode) -> Self {
+        Self::composite(Self::Key(negative), Self::Key(positive))
+    }
+
+    /// `negative` and `positive` are single actions, like `action::MOVE_LEFT`
+    pub fn general(negative: u32, positive: u32) -> Self {
+        Self::composite(
+            Self::General(negative.trailing_zeros() as usize),
+            Self::General(positive.trailing_zeros() as usize),
+        )
+    }
+
+    pub fn composite(negative: Self, positive: Self) -> Self {
+        Self::Composite {
+            negative: Box::new(negative),
+            positive: Box::new(positive),
+        }
+    }
+
+    pub fn invert(self) -> Self {
+        Self::Invert(Box::new(self))
+    }
+
+    /// whether the value is a relative mouse movement, instead of a value from -1 to 1
+    pub fn is_relative(&self) -> bool {
+        match self {
+            Self::MouseX | Self::MouseY | Self::WheelX | Self::WheelY => true,
+            Self::Composite { negative, positive } => {
+                negative.is_relative() || positive.is_relative()
+            }
+            Self::Invert(source) => source.is_relative(),
+            _ => false,
+        }
+    }
+
+    pub fn value(&self, input: &Input) -> f32 {
+        match self {
+            Self::Key(scancode) => button_value(input.keyboard.keys.is_hold(*scancode)),
+            Self::General(index) => bit_value(input.general.buttons.hold(), *index),
+            Self::KeyboardButton(index) => bit_value(input.keyboard.buttons.hold(), *index),
+            Self::MouseButton(index) => bit_value(input.mouse.buttons.hold(), *index),
+            Self::GamepadButton(index) => bit_value(input.gamepad.buttons.hold(), *index),
+            Self::GamepadAxis(axis) => {
+                let value = input.gamepad.axis[axis_index(*axis)];
+                f32::clamp(value as f32 / i16::MAX as f32, -1.0, 1.0)
+            }
+            Self::MouseX => input.mouse.xrel as f32,
+            Self::MouseY => input.mouse.yrel as f32,
+            Self::WheelX => input.mouse.wheel_xrel as f32,
+            Self::WheelY => input.mouse.wheel_yrel as f32,
+            Self::Composite { negative, positive } => positive.value(input) - negative.value(input),
+            Self::Invert(source) => -source.value(input),
+        }
+    }
+}
+
+impl DigitalBinding {
+    pub fn source(source: AxisSource) -> Self {
+        Self::Source {
+            source,
+            threshold: DEFAULT_THRESHOLD,
+        }
+    }
+
+    pub fn is_hold(&self, input: &Input) -> bool {
+        match self {
+            Self::Source { source, threshold } => f32::abs(source.value(input)) >= *threshold,
+            Self::General(actions) => input.general.buttons.is_hold(*actions),
+        }
+    }
+}
+
+impl AnalogBinding {
+    pub fn is_relative(&self) -> bool {
+        match self {
+            Self::Axis(source) => source.is_relative(),
+            Self::Vec2 { x, y } => x.is_relative() || y.is_relative(),
+        }
+    }
+
+    pub fn value(&self, input: &Input) -> Vec2 {
+        match self {
+            Self::Axis(source) => Vec2(source.value(input), 0.0),
+            Self::Vec2 { x, y } => Vec2(x.value(input), y.value(input)),
+        }
+    }
+}
+
+impl ResponseCurve {
+    /// `value` is expected to be between 0 and 1
+    pub fn apply(self, value: f32) -> f32 {
+        match self {
+            Self::Linear => value,
+            Self::Power(exponent) => f32::powf(value, exponent),
+        }
+    }
+}
+
+impl AnalogAction {
+    pub fn new(name: &str, bindings: Vec<AnalogBinding>) -> Self {
+        Self {
+            name: name.to_string(),
+            bindings,
+            dead_zone: DEFAULT_DEAD_ZONE,
+            curve: ResponseCurve::Linear,
+            sensitivity: 1.0,
+        }
+    }
+
+    pub fn value(&self, input: &Input) -> Vec2 {
+        let mut result = Vec2::default();
+
+        for binding in self.bindings.iter() {
+            let value = binding.value(input);
+            let value = if binding.is_relative() {
+                self.sensitivity * value
+            } else {
+                self.normalize(value)
+            };
+
+            if value.length_squared() > result.length_squared() {
+                result = value;
+            }
+        }
+
+        result
+    }
+
+    fn normalize(&self, value: Vec2) -> Vec2 {
+        let magnitude = value.length();
+        if magnitude <= self.dead_zone || magnitude <= f32::EPSILON {
+            return Vec2::default();
+        }
+
+        let range = 1.0 - self.dead_zone;
+        let scaled = if range > 0.0 {
+            f32::min((magnitude - self.dead_zone) / range, 1.0)
+        } else {
+            1.0
+        };
+
+        self.curve.apply(scaled) / magnitude * value
+    }
+}
+
+impl ActionMap {
+    /// moves with the `MOVE_*` actions and the left stick, and looks with the `CAMERA_*` actions
+    /// and the right stick. the actions are read from the general buttons, such that rebinds from
+    /// the settings apply to them
+    pub fn new_default() -> Self {
+        let mut result = Self::default();
+
+        result.analog.push(AnalogAction::new(
+            MOVE,
+            vec![
+                AnalogBinding::Vec2 {
+                    x: AxisSource::general(action::MOVE_LEFT, action::MOVE_RIGHT),
+                    y: AxisSource::general(action::MOVE_DOWN, action::MOVE_UP),
+                },
+                AnalogBinding::Vec2 {
+                    x: AxisSource::GamepadAxis(Axis::LeftX),
+                    y: AxisSource::GamepadAxis(Axis::LeftY).invert(),
+                },
+            ],
+        ));
+
+        result.analog.push(AnalogAction::new(
+            LOOK,
+            vec![
+                AnalogBinding::Vec2 {
+                    x: AxisSource::general(action::CAMERA_LEFT, action::CAMERA_RIGHT),
+                    y: AxisSource::general(action::CAMERA_DOWN, action::CAMERA_UP),
+                },
+                AnalogBinding::Vec2 {
+                    x: AxisSource::GamepadAxis(Axis::RightX),
+                    y: AxisSource::GamepadAxis(Axis::RightY).invert(),
+                },
+            ],
+        ));
+
+        result
+    }
+
+    pub fn add_digital(&mut self, action: DigitalAction) -> RisResult<()> {
+        self.assert_unique(&action.name)?;
+        self.digital.push(action);
+        Ok(())
+    }
+
+    pub fn add_analog(&mut self, action: AnalogAction) -> RisResult<()> {
+        self.assert_unique(&action.name)?;
+        self.analog.push(action);
+        Ok(())
+    }
+
+    pub fn remove(&mut self, name: &str) {
+        self.digital.retain(|x| x.name != name);
+        self.analog.retain(|x| x.name != name);
+    }
+
+    pub fn digital(&self) -> &[DigitalAction] {
+        &self.digital
+    }
+
+    pub fn analog(&self) -> &[AnalogAction] {
+        &self.analog
+    }
+
+    /// evaluates all actions. call this after the devices were polled
+    pub fn update(&self, input: &mut Input) {
+        for action in self.digital.iter() {
+            let value = action.bindings.iter().any(|x| x.is_hold(input));
+            input.actions.update_digital(&action.name, value);
+        }
+
+        for action in self.analog.iter() {
+            let value = action.value(input);
+            input.actions.set_analog(&action.name, value);
+        }
+    }
+
+    fn assert_unique(&self, name: &str) -> RisResult<()> {
+        let digital_exists = self.digital.iter().any(|x| x.name == name);
+        let analog_exists = self.analog.iter().any(|x| x.name == name);
+        if digital_exists || analog_exists {
+            return ris_error::new_result!("action \"{}\" exists already", name);
+        }
+
+        Ok(())
+    }
+}
+
+fn button_value(is_hold: bool) -> f32 {
+    if is_hold {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+fn bit_value(buttons: u32, index: usize) -> f32 {
+    button_value(index < 32 && buttons & (1 << index) != 0)
+}
+
+/// the index into `GamepadData::axis`
+pub fn axis_index(axis: Axis) -> usize {
+    match axis {
+        Axis::LeftX => 0,
+        Axis::LeftY => 1,
+        Axis::RightX => 2,
+        Axis::RightY => 3,
+        Axis::TriggerLeft => 4,
+        Axis::TriggerRight => 5,
+    }
+}
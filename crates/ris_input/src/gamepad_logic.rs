@@ -3,12 +3,19 @@ use sdl2::event::Event;
 use sdl2::GameControllerSubsystem;
 
 use ris_data::input::gamepad_data::GamepadData;
+use ris_data::input::player_data::ControllerInfo;
+use ris_data::input::player_data::ControllerType;
+use ris_data::input::player_data::PlayerData;
+use ris_data::input::player_data::Rumble;
+use ris_data::input::player_data::RumbleQueue;
 
 use crate::gamepad_util::{get_button_index, ALL_BUTTONS};
+use crate::player_slots::PlayerSlots;
 
 pub struct GamepadLogic {
     subsystem: GameControllerSubsystem,
     open_controllers: Vec<GameController>,
+    player_slots: PlayerSlots,
 
     last_controller_event_instance_id: u32,
     current_controller: Option<usize>,
@@ -19,6 +26,7 @@ impl GamepadLogic {
         Self {
             subsystem,
             open_controllers: Vec::new(),
+            player_slots: PlayerSlots::default(),
             last_controller_event_instance_id: u32::MAX,
             current_controller: None,
         }
@@ -50,13 +58,64 @@ impl GamepadLogic {
         }
     }
 
-    pub fn post_events(&mut self, gamepad_data: &mut GamepadData) {
+    pub fn player_slots(&self) -> &PlayerSlots {
+        &self.player_slots
+    }
+
+    /// sends the rumble requests to the controllers of their players. requests for players
+    /// without a controller are discarded
+    pub fn post_events(
+        &mut self,
+        gamepad_data: &mut GamepadData,
+        players: &mut Vec<PlayerData>,
+        rumble: &RumbleQueue,
+    ) {
         if let Some(controller_index) = self.current_controller {
             let controller_to_use = &self.open_controllers[controller_index];
             compute_state(gamepad_data, controller_to_use)
         } else {
             reset_state(gamepad_data)
         }
+
+        if players.len() < self.player_slots.len() {
+            players.resize_with(self.player_slots.len(), PlayerData::default);
+        }
+
+        let rumbles = rumble.take(players.len());
+
+        for (player_index, (player, rumble)) in players.iter_mut().zip(rumbles).enumerate() {
+            let instance_id = self.player_slots.controller_of(player_index);
+            let previous_instance_id = player.controller.as_ref().map(|x| x.instance_id);
+            let controller = instance_id.and_then(|id| {
+                self.open_controllers
+                    .iter_mut()
+                    .find(|x| x.instance_id() == id)
+            });
+
+            player.joined = instance_id.is_some() && instance_id != previous_instance_id;
+            player.left = previous_instance_id.is_some() && instance_id != previous_instance_id;
+
+            let Some(controller) = controller else {
+                player.controller = None;
+                reset_state(&mut player.gamepad);
+                continue;
+            };
+
+            if player.joined {
+                let name = controller.name();
+                player.controller = Some(ControllerInfo {
+                    instance_id: controller.instance_id(),
+                    controller_type: ControllerType::from_name(&name),
+                    name,
+                });
+            }
+
+            compute_state(&mut player.gamepad, controller);
+
+            if let Some(rumble) = rumble {
+                set_rumble(controller, rumble);
+            }
+        }
     }
 
     fn update_current_controller(&mut self, instance_id: u32) {
@@ -86,6 +145,17 @@ impl GamepadLogic {
 
         self.open_controllers.push(game_controller);
 
+        match self.player_slots.join(instance_id) {
+            Some(player) => {
+                ris_log::info!("controller \"{}\" joined as player {}", instance_id, player)
+            }
+            None => ris_log::info!(
+                "controller \"{}\" did not join, all {} players have a controller",
+                instance_id,
+                self.player_slots.max_players(),
+            ),
+        }
+
         self.last_controller_event_instance_id = instance_id;
         self.current_controller = Some(self.open_controllers.len() - 1);
 
@@ -110,6 +180,10 @@ impl GamepadLogic {
             self.open_controllers.remove(remove_at);
         }
 
+        if let Some(player) = self.player_slots.leave(instance_id) {
+            ris_log::info!("player {} left", player);
+        }
+
         if self.open_controllers.is_empty() {
             self.current_controller = None;
             self.last_controller_event_instance_id = u32::MAX;
@@ -202,6 +276,21 @@ fn reset_state(gamepad: &mut GamepadData) {
     gamepad.axis[5] = 0;
 }
 
+fn set_rumble(controller: &mut GameController, rumble: Rumble) {
+    let to_intensity = |x: f32| (f32::clamp(x, 0.0, 1.0) * u16::MAX as f32) as u16;
+    let low_frequency = to_intensity(rumble.low_frequency);
+    let high_frequency = to_intensity(rumble.high_frequency);
+    let duration_ms = u32::try_from(rumble.duration.as_millis()).unwrap_or(u32::MAX / 2);
+
+    if let Err(e) = controller.set_rumble(low_frequency, high_frequency, duration_ms) {
+        ris_log::warning!(
+            "failed to rumble controller \"{}\": {}",
+            controller.instance_id(),
+            e
+        );
+    }
+}
+
 fn apply_deadzone_stick(axis_x: &mut i16, axis_y: &mut i16, deadzone: i16) {
     if *axis_x != i16::MIN
         && *axis_y != i16::MIN
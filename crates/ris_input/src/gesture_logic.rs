@@ -0,0 +1,9 @@
+use ris_data::gameloop::frame::Frame;
+use ris_data::input::general_data::GeneralData;
+
+/// call this after `general_logic::update_general`, once per frame
+pub fn update_gestures(general: &mut GeneralData, frame: Frame) {
+    general
+        .gestures
+        .update(&general.buttons, frame.previous_duration());
+}
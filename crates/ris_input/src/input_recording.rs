@@ -0,0 +1,404 @@
+use std::fs::File;
+use std::io::Cursor;
+use std::io::SeekFrom;
+use std::path::Path;
+use std::time::Duration;
+
+use sdl2::keyboard::Mod;
+use sdl2::keyboard::Scancode;
+
+use ris_data::gameloop::frame::Frame;
+use ris_data::input::player_data::ControllerInfo;
+use ris_data::input::player_data::ControllerType;
+use ris_data::input::Input;
+use ris_error::Extensions;
+use ris_error::RisResult;
+
+// # File Format
+//
+// encoding: little-endian
+//
+// - [u8; 16]: magic `ris_input\0\0\0\0\0\0` followed by the version
+// - [frame; ?]: frames, until the end of the file
+//   - u64: previous duration in nanoseconds
+//   - u64: average duration in nanoseconds
+//   - mouse
+//     - u32: buttons
+//     - [i32; 6]: x, y, xrel, yrel, wheel_xrel, wheel_yrel
+//   - keyboard
+//     - u32: buttons
+//     - u32: mod state
+//     - u32: key_count
+//     - [u32; key_count]: scancodes of all pressed keys
+//     - u32: text_input_count
+//     - [sized String; text_input_count]: text input
+//   - gamepad
+//     - u32: buttons
+//     - [i32; 6]: axis
+//   - since version 1: players
+//     - u32: player_count
+//     - [player; player_count]
+//       - bool: has_controller
+//       - if has_controller
+//         - u32: instance_id
+//         - sized String: name
+//         - u8: controller_type
+//       - bool: joined
+//       - bool: left
+//       - u32: buttons
+//       - [i32; 6]: axis
+//
+// # Versions
+//
+// files of older versions can still be replayed, but are always written in the current version.
+//
+// - 0: only the gamepad that was used last is recorded
+// - 1: the gamepads of all players are recorded
+
+// ris_input\0\0\0\0\0\0\x01
+pub const MAGIC: [u8; 16] = [
+    0x72, 0x69, 0x73, 0x5F, 0x69, 0x6E, 0x70, 0x75, 0x74, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+];
+
+/// the input of a single frame, as it is after polling all devices and before the general
+/// buttons are computed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedFrame {
+    pub previous: Duration,
+    pub average: Duration,
+
+    pub mouse_buttons: u32,
+    pub mouse_position: (i32, i32),
+    pub mouse_rel: (i32, i32),
+    pub mouse_wheel_rel: (i32, i32),
+
+    pub keyboard_buttons: u32,
+    pub keys: Vec<Scancode>,
+    pub mod_state: Mod,
+    pub text_input: Vec<String>,
+
+    pub gamepad_buttons: u32,
+    pub gamepad_axis: [i16; 6],
+
+    pub players: Vec<RecordedPlayer>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedPlayer {
+    pub controller: Option<ControllerInfo>,
+    pub joined: bool,
+    pub left: bool,
+    pub gamepad_buttons: u32,
+    pub gamepad_axis: [i16; 6],
+}
+
+/// writes the input of every frame to a file
+pub struct InputRecorder {
+    file: File,
+}
+
+/// reads the frames written by an `InputRecorder`
+pub struct InputReplay {
+    stream: Cursor<Vec<u8>>,
+    version: u8,
+    frame_number: usize,
+}
+
+impl RecordedFrame {
+    pub fn capture(frame: Frame, input: &Input) -> Self {
+        let keys = input
+            .keyboard
+            .keys
+            .hold()
+            .iter()
+            .enumerate()
+            .filter(|(_, x)| **x)
+            .filter_map(|(i, _)| Scancode::from_i32(i as i32))
+            .collect();
+
+        Self {
+            previous: frame.previous_duration(),
+            average: frame.average_duration(),
+
+            mouse_buttons: input.mouse.buttons.hold(),
+            mouse_position: (input.mouse.x, input.mouse.y),
+            mouse_rel: (input.mouse.xrel, input.mouse.yrel),
+            mouse_wheel_rel: (input.mouse.wheel_xrel, input.mouse.wheel_yrel),
+
+            keyboard_buttons: input.keyboard.buttons.hold(),
+            keys,
+            mod_state: input.keyboard.mod_state,
+            text_input: input.keyboard.text_input.clone(),
+
+            gamepad_buttons: input.gamepad.buttons.hold(),
+            gamepad_axis: input.gamepad.axis,
+
+            players: input
+                .players
+                .iter()
+                .map(|x| RecordedPlayer {
+                    controller: x.controller.clone(),
+                    joined: x.joined,
+                    left: x.left,
+                    gamepad_buttons: x.gamepad.buttons.hold(),
+                    gamepad_axis: x.gamepad.axis,
+                })
+                .collect(),
+        }
+    }
+
+    /// updates the input, as if the recorded devices were polled
+    pub fn apply(&self, input: &mut Input) {
+        let mouse = &mut input.mouse;
+        mouse.buttons.update(self.mouse_buttons);
+        (mouse.x, mouse.y) = self.mouse_position;
+        (mouse.xrel, mouse.yrel) = self.mouse_rel;
+        (mouse.wheel_xrel, mouse.wheel_yrel) = self.mouse_wheel_rel;
+
+        let keyboard = &mut input.keyboard;
+        keyboard.keys.clear();
+        for scancode in self.keys.iter() {
+            keyboard.keys.set(*scancode);
+        }
+        keyboard.buttons.update(self.keyboard_buttons);
+        keyboard.mod_state = self.mod_state;
+        keyboard.text_input = self.text_input.clone();
+
+        let gamepad = &mut input.gamepad;
+        gamepad.buttons.update(self.gamepad_buttons);
+        gamepad.axis = self.gamepad_axis;
+
+        // players keep their index, thus they are never removed
+        if input.players.len() < self.players.len() {
+            input
+                .players
+                .resize_with(self.players.len(), Default::default);
+        }
+
+        for (player, recorded) in input.players.iter_mut().zip(self.players.iter()) {
+            player.controller = recorded.controller.clone();
+            player.joined = recorded.joined;
+            player.left = recorded.left;
+            player.gamepad.buttons.update(recorded.gamepad_buttons);
+            player.gamepad.axis = recorded.gamepad_axis;
+        }
+    }
+
+    pub fn frame(&self, number: usize) -> Frame {
+        Frame::new(number, self.previous, self.average)
+    }
+
+    pub fn serialize(&self) -> RisResult<Vec<u8>> {
+        let mut stream = Cursor::new(Vec::new());
+        let f = &mut stream;
+
+        ris_io::write_u64(f, self.previous.as_nanos() as u64)?;
+        ris_io::write_u64(f, self.average.as_nanos() as u64)?;
+
+        ris_io::write_uint(f, self.mouse_buttons as usize)?;
+        for value in [
+            self.mouse_position.0,
+            self.mouse_position.1,
+            self.mouse_rel.0,
+            self.mouse_rel.1,
+            self.mouse_wheel_rel.0,
+            self.mouse_wheel_rel.1,
+        ] {
+            ris_io::write_int(f, value as isize)?;
+        }
+
+        ris_io::write_uint(f, self.keyboard_buttons as usize)?;
+        ris_io::write_uint(f, self.mod_state.bits() as usize)?;
+        ris_io::write_uint(f, self.keys.len())?;
+        for scancode in self.keys.iter() {
+            ris_io::write_uint(f, *scancode as usize)?;
+        }
+        ris_io::write_uint(f, self.text_input.len())?;
+        for text in self.text_input.iter() {
+            ris_io::write_string(f, text)?;
+        }
+
+        ris_io::write_uint(f, self.gamepad_buttons as usize)?;
+        for value in self.gamepad_axis {
+            ris_io::write_int(f, value as isize)?;
+        }
+
+        ris_io::write_uint(f, self.players.len())?;
+        for player in self.players.iter() {
+            ris_io::write_bool(f, player.controller.is_some())?;
+            if let Some(controller) = player.controller.as_ref() {
+                ris_io::write_uint(f, controller.instance_id as usize)?;
+                ris_io::write_string(f, &controller.name)?;
+                ris_io::write_u8(f, controller_type_to_u8(controller.controller_type))?;
+            }
+
+            ris_io::write_bool(f, player.joined)?;
+            ris_io::write_bool(f, player.left)?;
+            ris_io::write_uint(f, player.gamepad_buttons as usize)?;
+            for value in player.gamepad_axis {
+                ris_io::write_int(f, value as isize)?;
+            }
+        }
+
+        Ok(stream.into_inner())
+    }
+
+    /// `version` is the last byte of the magic of the file
+    pub fn deserialize(f: &mut Cursor<Vec<u8>>, version: u8) -> RisResult<Self> {
+        let previous = Duration::from_nanos(ris_io::read_u64(f)?);
+        let average = Duration::from_nanos(ris_io::read_u64(f)?);
+
+        let mouse_buttons = ris_io::read_uint(f)? as u32;
+        let mut mouse_values = [0; 6];
+        for value in mouse_values.iter_mut() {
+            *value = ris_io::read_int(f)? as i32;
+        }
+
+        let keyboard_buttons = ris_io::read_uint(f)? as u32;
+        let mod_state = Mod::from_bits_truncate(ris_io::read_uint(f)? as u16);
+        let key_count = ris_io::read_uint(f)?;
+        let mut keys = Vec::with_capacity(key_count);
+        for _ in 0..key_count {
+            let value = ris_io::read_uint(f)?;
+            let scancode = Scancode::from_i32(value as i32).into_ris_error()?;
+            keys.push(scancode);
+        }
+        let text_input_count = ris_io::read_uint(f)?;
+        let mut text_input = Vec::with_capacity(text_input_count);
+        for _ in 0..text_input_count {
+            text_input.push(ris_io::read_string(f)?);
+        }
+
+        let gamepad_buttons = ris_io::read_uint(f)? as u32;
+        let mut gamepad_axis = [0; 6];
+        for value in gamepad_axis.iter_mut() {
+            *value = ris_io::read_int(f)? as i16;
+        }
+
+        let player_count = match version {
+            0 => 0,
+            _ => ris_io::read_uint(f)?,
+        };
+        let mut players = Vec::with_capacity(player_count);
+        for _ in 0..player_count {
+            let controller = if ris_io::read_bool(f)? {
+                let instance_id = ris_io::read_uint(f)? as u32;
+                let name = ris_io::read_string(f)?;
+                let controller_type = controller_type_from_u8(ris_io::read_u8(f)?)?;
+                Some(ControllerInfo {
+                    instance_id,
+                    name,
+                    controller_type,
+                })
+            } else {
+                None
+            };
+
+            let joined = ris_io::read_bool(f)?;
+            let left = ris_io::read_bool(f)?;
+            let gamepad_buttons = ris_io::read_uint(f)? as u32;
+            let mut gamepad_axis = [0; 6];
+            for value in gamepad_axis.iter_mut() {
+                *value = ris_io::read_int(f)? as i16;
+            }
+
+            players.push(RecordedPlayer {
+                controller,
+                joined,
+                left,
+                gamepad_buttons,
+                gamepad_axis,
+            });
+        }
+
+        Ok(Self {
+            previous,
+            average,
+            mouse_buttons,
+            mouse_position: (mouse_values[0], mouse_values[1]),
+            mouse_rel: (mouse_values[2], mouse_values[3]),
+            mouse_wheel_rel: (mouse_values[4], mouse_values[5]),
+            keyboard_buttons,
+            keys,
+            mod_state,
+            text_input,
+            gamepad_buttons,
+            gamepad_axis,
+            players,
+        })
+    }
+}
+
+impl InputRecorder {
+    /// creates the file. if it exists already, it will be overwritten
+    pub fn new(path: &Path) -> RisResult<Self> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let mut file = File::create(path)?;
+        ris_io::write(&mut file, &MAGIC)?;
+
+        ris_log::info!("recording input to \"{}\"", path.display());
+        Ok(Self { file })
+    }
+
+    pub fn record(&mut self, frame: Frame, input: &Input) -> RisResult<()> {
+        let bytes = RecordedFrame::capture(frame, input).serialize()?;
+        ris_io::write(&mut self.file, &bytes)?;
+        Ok(())
+    }
+}
+
+impl InputReplay {
+    pub fn new(path: &Path) -> RisResult<Self> {
+        let bytes = std::fs::read(path)?;
+        let mut stream = Cursor::new(bytes);
+
+        let mut magic = [0; 16];
+        ris_io::read(&mut stream, &mut magic)?;
+        if magic[..15] != MAGIC[..15] || magic[15] > MAGIC[15] {
+            return ris_error::new_result!("unknown magic value: {:?}", magic);
+        }
+
+        ris_log::info!("replaying input from \"{}\"", path.display());
+        Ok(Self {
+            stream,
+            version: magic[15],
+            frame_number: 0,
+        })
+    }
+
+    /// returns `None` when all frames were replayed
+    pub fn next_frame(&mut self) -> RisResult<Option<(Frame, RecordedFrame)>> {
+        let position = ris_io::seek(&mut self.stream, SeekFrom::Current(0))?;
+        if position >= self.stream.get_ref().len() as u64 {
+            return Ok(None);
+        }
+
+        let recorded = RecordedFrame::deserialize(&mut self.stream, self.version)?;
+        self.frame_number += 1;
+        let frame = recorded.frame(self.frame_number);
+
+        Ok(Some((frame, recorded)))
+    }
+}
+
+fn controller_type_to_u8(value: ControllerType) -> u8 {
+    match value {
+        ControllerType::Unknown => 0,
+        ControllerType::Xbox => 1,
+        ControllerType::PlayStation => 2,
+        ControllerType::NintendoSwitch => 3,
+    }
+}
+
+fn controller_type_from_u8(value: u8) -> RisResult<ControllerType> {
+    match value {
+        0 => Ok(ControllerType::Unknown),
+        1 => Ok(ControllerType::Xbox),
+        2 => Ok(ControllerType::PlayStation),
+        3 => Ok(ControllerType::NintendoSwitch),
+        value => ris_error::new_result!("unknown controller type {}", value),
+    }
+}
@@ -29,7 +29,7 @@ pub fn post_events(
         keyboard_data.keys.set(scancode);
 
         for i in 0..32 {
-            if keyboard_data.keymask[i] == scancode {
+            if keyboard_data.keymask[i] == Some(scancode) {
                 new_button_state |= 1 << i;
             }
         }
@@ -1,5 +1,9 @@
+pub mod action_map;
 pub mod gamepad_logic;
 pub mod gamepad_util;
 pub mod general_logic;
+pub mod gesture_logic;
+pub mod input_recording;
 pub mod keyboard_logic;
 pub mod mouse_logic;
+pub mod player_slots;
@@ -0,0 +1,72 @@
+pub const DEFAULT_MAX_PLAYERS: usize = 4;
+
+/// binds controllers to players. a controller joins as the first player without a controller.
+/// when a controller is removed, its player keeps the slot, such that a reconnected controller
+/// joins as the same player, unless another controller joined in the meantime.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayerSlots {
+    slots: Vec<Option<u32>>,
+    max_players: usize,
+}
+
+impl Default for PlayerSlots {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_PLAYERS)
+    }
+}
+
+impl PlayerSlots {
+    pub fn new(max_players: usize) -> Self {
+        Self {
+            slots: Vec::new(),
+            max_players,
+        }
+    }
+
+    /// returns the player of the controller, or `None` if all players have a controller
+    pub fn join(&mut self, instance_id: u32) -> Option<usize> {
+        if let Some(player) = self.player_of(instance_id) {
+            return Some(player);
+        }
+
+        if let Some(player) = self.slots.iter().position(|x| x.is_none()) {
+            self.slots[player] = Some(instance_id);
+            return Some(player);
+        }
+
+        if self.slots.len() < self.max_players {
+            self.slots.push(Some(instance_id));
+            return Some(self.slots.len() - 1);
+        }
+
+        None
+    }
+
+    /// returns the player that the controller was bound to
+    pub fn leave(&mut self, instance_id: u32) -> Option<usize> {
+        let player = self.player_of(instance_id)?;
+        self.slots[player] = None;
+        Some(player)
+    }
+
+    pub fn player_of(&self, instance_id: u32) -> Option<usize> {
+        self.slots.iter().position(|x| *x == Some(instance_id))
+    }
+
+    pub fn controller_of(&self, player: usize) -> Option<u32> {
+        self.slots.get(player).copied().flatten()
+    }
+
+    /// the number of players that ever joined, including the ones without a controller
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    pub fn max_players(&self) -> usize {
+        self.max_players
+    }
+}
@@ -133,26 +133,31 @@ pub fn to_scale(m: Mat3) -> Vec3 {
 }
 
 /// returns a translation-rotation-scale matrix
-pub fn trs_compose(t: Vec3, r: Quat, s: f32) -> Mat4 {
-    ris_error::throw_debug_assert!(s > 0.0, "non-positive scale is not supported");
+pub fn trs_compose(t: Vec3, r: Quat, s: Vec3) -> Mat4 {
+    ris_error::throw_debug_assert!(
+        s.0 > 0.0 && s.1 > 0.0 && s.2 > 0.0,
+        "non-positive scale is not supported"
+    );
 
     let t = from_translation(t);
     let r = Mat4::from(from_rotation(r));
-    let s = Mat4::from(from_scale(Vec3::init(s)));
+    let s = Mat4::from(from_scale(s));
 
     t * r * s
 }
 
-/// decomposes a trandlation-rotation-scale matrix
-pub fn trs_decompose(m: Mat4) -> (Vec3, Quat, f32) {
+/// decomposes a trandlation-rotation-scale matrix. assumes the matrix contains no skew. use
+/// `decompose_fully` for matrices that may be skewed, for example the result of multiplying
+/// rotated non-uniformly scaled matrices.
+pub fn trs_decompose(m: Mat4) -> (Vec3, Quat, Vec3) {
     // compute translation
     let translation = to_translation(m);
 
     // for the next steps we only care bout the top left 3x3 matrix
     let mut m = Mat3::from(m);
 
-    // compute scale, assume scale is uniform
-    let scale = m.0.length();
+    // compute scale
+    let scale = Vec3(m.0.length(), m.1.length(), m.2.length());
 
     // normalize columns
     m.0 = m.0.normalize();
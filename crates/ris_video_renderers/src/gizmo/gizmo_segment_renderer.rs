@@ -3,6 +3,7 @@ use std::ptr;
 use ash::vk;
 
 use ris_asset::RisGodAsset;
+use ris_data::asset_id::AssetId;
 use ris_debug::gizmo::GizmoSegmentVertex;
 use ris_error::Extensions;
 use ris_error::RisResult;
@@ -69,6 +70,15 @@ impl GizmoSegmentRenderer {
         device.destroy_render_pass(self.render_pass, None);
     }
 
+    /// the assets that `alloc` depends on
+    pub fn asset_ids(god_asset: &RisGodAsset) -> Vec<AssetId> {
+        vec![
+            god_asset.gizmo_segment_vert_spv.clone(),
+            god_asset.gizmo_segment_geom_spv.clone(),
+            god_asset.gizmo_segment_frag_spv.clone(),
+        ]
+    }
+
     /// # Safety
     ///
     /// `free()` must be called, or you are leaking memory.
@@ -2,8 +2,10 @@ use std::ptr;
 
 use ash::vk;
 
-use ris_asset::codecs::qoi;
+use ris_asset::asset_cache;
+use ris_asset::asset_cache::ImageLoader;
 use ris_asset::RisGodAsset;
+use ris_data::asset_id::AssetId;
 use ris_debug::gizmo::GizmoTextVertex;
 use ris_error::Extensions;
 use ris_error::RisResult;
@@ -77,6 +79,16 @@ impl GizmoTextRenderer {
         self.font_texture.free(device);
     }
 
+    /// the assets that `alloc` depends on
+    pub fn asset_ids(god_asset: &RisGodAsset) -> Vec<AssetId> {
+        vec![
+            god_asset.gizmo_text_vert_spv.clone(),
+            god_asset.gizmo_text_geom_spv.clone(),
+            god_asset.gizmo_text_frag_spv.clone(),
+            god_asset.debug_font_texture.clone(),
+        ]
+    }
+
     /// # Safety
     ///
     /// `free()` must be called, or you are leaking memory.
@@ -496,14 +508,9 @@ impl GizmoTextRenderer {
         unsafe { device.destroy_shader_module(fs_module, None) };
 
         // texture
-        let font_future = ris_asset::load_async(god_asset.debug_font_texture.clone());
-        let font_data = font_future.wait(None)??;
-        let (pixels, desc) = qoi::decode(&font_data, None)?;
-
-        let pixels_rgba = match desc.channels {
-            qoi::Channels::RGB => ris_asset::util::add_alpha_channel(&pixels)?,
-            qoi::Channels::RGBA => pixels,
-        };
+        let font = asset_cache::global()
+            .get::<ImageLoader>(god_asset.debug_font_texture.clone())
+            .wait()?;
 
         let font_texture = unsafe {
             Texture::alloc(TextureCreateInfo {
@@ -512,11 +519,11 @@ impl GizmoTextRenderer {
                 transient_command_pool: *transient_command_pool,
                 physical_device_memory_properties,
                 physical_device_properties,
-                width: desc.width,
-                height: desc.height,
+                width: font.desc.width,
+                height: font.desc.height,
                 format: vk::Format::R8G8B8A8_SRGB,
                 filter: vk::Filter::NEAREST,
-                pixels_rgba: &pixels_rgba,
+                pixels_rgba: &font.pixels_rgba,
             })
         }?;
 
@@ -11,6 +11,7 @@ use imgui::TextureId;
 use imgui::Textures;
 
 use ris_asset::RisGodAsset;
+use ris_data::asset_id::AssetId;
 use ris_error::Extensions;
 use ris_error::RisResult;
 use ris_math::matrix::Mat4;
@@ -73,6 +74,14 @@ impl ImguiRenderer {
         }
     }
 
+    /// the assets that `alloc` depends on
+    pub fn asset_ids(god_asset: &RisGodAsset) -> Vec<AssetId> {
+        vec![
+            god_asset.imgui_vert_spv.clone(),
+            god_asset.imgui_frag_spv.clone(),
+        ]
+    }
+
     /// # Safety
     ///
     /// `free()` must be called, or you are leaking memory.
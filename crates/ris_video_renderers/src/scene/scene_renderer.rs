@@ -2,8 +2,14 @@ use std::ptr;
 
 use ash::vk;
 
-use ris_asset::codecs::qoi;
+use ris_asset::asset_cache;
+use ris_asset::asset_cache::ImageLoader;
+use ris_asset::asset_cache::MeshLoader;
 use ris_asset::RisGodAsset;
+use ris_data::asset_id::AssetId;
+use ris_data::ecs::components::mesh_renderer::MeshRendererComponent;
+use ris_data::ecs::decl::MeshRendererComponentHandle;
+use ris_data::ecs::decl::VideoMeshHandle;
 use ris_data::ecs::mesh::VERTEX_ATTRIBUTE_DESCRIPTIONS;
 use ris_data::ecs::mesh::VERTEX_BINDING_DESCRIPTIONS;
 use ris_data::ecs::scene::Scene;
@@ -65,6 +71,7 @@ pub struct SceneRenderer {
     pipeline_layout: vk::PipelineLayout,
     frames: Vec<SceneFrame>,
     texture: Texture,
+    failed_mesh_assets: Vec<AssetId>,
 }
 
 impl SceneRenderer {
@@ -96,8 +103,6 @@ impl SceneRenderer {
             instance,
             suitable_device,
             device,
-            graphics_queue,
-            transient_command_pool,
             swapchain,
             ..
         } = core;
@@ -105,33 +110,9 @@ impl SceneRenderer {
         let physical_device_memory_properties = unsafe {
             instance.get_physical_device_memory_properties(suitable_device.physical_device)
         };
-        let physical_device_properties =
-            unsafe { instance.get_physical_device_properties(suitable_device.physical_device) };
 
         // texture
-        let texture_asset_id = god_asset.texture.clone();
-        let content = ris_asset::load_async(texture_asset_id.clone()).wait(None)??;
-        let (pixels, desc) = qoi::decode(&content, None)?;
-
-        let pixels_rgba = match desc.channels {
-            qoi::Channels::RGB => ris_asset::util::add_alpha_channel(&pixels)?,
-            qoi::Channels::RGBA => pixels,
-        };
-
-        let texture = unsafe {
-            Texture::alloc(TextureCreateInfo {
-                device,
-                queue: *graphics_queue,
-                transient_command_pool: *transient_command_pool,
-                physical_device_memory_properties,
-                physical_device_properties,
-                width: desc.width,
-                height: desc.height,
-                format: vk::Format::R8G8B8A8_SRGB,
-                filter: vk::Filter::LINEAR,
-                pixels_rgba: &pixels_rgba,
-            })
-        }?;
+        let texture = unsafe { Self::alloc_texture(core, god_asset) }?;
 
         // push constants
         let push_constant_range = [vk::PushConstantRange {
@@ -539,9 +520,175 @@ impl SceneRenderer {
             pipeline_layout,
             frames,
             texture,
+            failed_mesh_assets: Vec::new(),
         })
     }
 
+    /// the assets that `alloc` depends on
+    pub fn asset_ids(god_asset: &RisGodAsset) -> Vec<AssetId> {
+        vec![
+            god_asset.default_vert_spv.clone(),
+            god_asset.default_frag_spv.clone(),
+            god_asset.texture.clone(),
+        ]
+    }
+
+    /// reloads the texture, without rebuilding the pipeline
+    ///
+    /// # Safety
+    ///
+    /// The texture must not be in use by the gpu.
+    pub unsafe fn reload_texture(
+        &mut self,
+        core: &VulkanCore,
+        god_asset: &RisGodAsset,
+    ) -> RisResult<()> {
+        let texture = unsafe { Self::alloc_texture(core, god_asset) }?;
+        let old_texture = std::mem::replace(&mut self.texture, texture);
+        unsafe { old_texture.free(&core.device) };
+
+        Ok(())
+    }
+
+    /// frees the video meshes that were uploaded from the given assets, such that they are
+    /// uploaded again on the next draw.
+    ///
+    /// # Safety
+    ///
+    /// The video meshes must not be in use by the gpu.
+    pub unsafe fn reload_meshes(
+        &mut self,
+        core: &VulkanCore,
+        scene: &Scene,
+        asset_ids: &[AssetId],
+    ) -> RisResult<()> {
+        self.failed_mesh_assets.retain(|x| !asset_ids.contains(x));
+
+        for ptr in scene.video_meshes.iter() {
+            let aref = ptr.borrow();
+            if !aref.is_alive {
+                continue;
+            }
+
+            let handle: VideoMeshHandle = aref.handle.into();
+            drop(aref);
+
+            let Some(asset_id) = handle.asset_id(scene)? else {
+                continue;
+            };
+
+            if asset_ids.contains(&asset_id) && handle.is_uploaded(scene)? {
+                handle.free(scene, &core.device)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    unsafe fn alloc_texture(core: &VulkanCore, god_asset: &RisGodAsset) -> RisResult<Texture> {
+        let VulkanCore {
+            instance,
+            suitable_device,
+            device,
+            graphics_queue,
+            transient_command_pool,
+            ..
+        } = core;
+
+        let physical_device_memory_properties = unsafe {
+            instance.get_physical_device_memory_properties(suitable_device.physical_device)
+        };
+        let physical_device_properties =
+            unsafe { instance.get_physical_device_properties(suitable_device.physical_device) };
+
+        let image = asset_cache::global()
+            .get::<ImageLoader>(god_asset.texture.clone())
+            .wait()?;
+
+        unsafe {
+            Texture::alloc(TextureCreateInfo {
+                device,
+                queue: *graphics_queue,
+                transient_command_pool: *transient_command_pool,
+                physical_device_memory_properties,
+                physical_device_properties,
+                width: image.desc.width,
+                height: image.desc.height,
+                format: vk::Format::R8G8B8A8_SRGB,
+                filter: vk::Filter::LINEAR,
+                pixels_rgba: &image.pixels_rgba,
+            })
+        }
+    }
+
+    /// uploads all video meshes that reference a mesh asset, but are not uploaded yet. assets
+    /// that fail to load are remembered and not attempted again, to avoid spamming the log every
+    /// frame.
+    fn upload_pending_meshes(&mut self, core: &VulkanCore, scene: &Scene) -> RisResult<()> {
+        let mut pending = Vec::new();
+        for ptr in scene.video_meshes.iter() {
+            let aref = ptr.borrow();
+            if !aref.is_alive {
+                continue;
+            }
+
+            let handle: VideoMeshHandle = aref.handle.into();
+            drop(aref);
+
+            if handle.is_uploaded(scene)? {
+                continue;
+            }
+
+            let Some(asset_id) = handle.asset_id(scene)? else {
+                continue;
+            };
+
+            if self.failed_mesh_assets.contains(&asset_id) {
+                continue;
+            }
+
+            pending.push((handle, asset_id));
+        }
+
+        if pending.is_empty() {
+            return Ok(());
+        }
+
+        let physical_device_memory_properties = unsafe {
+            core.instance
+                .get_physical_device_memory_properties(core.suitable_device.physical_device)
+        };
+
+        // request all meshes first, such that meshes which are used multiple times are only loaded
+        // once and are kept alive until all are uploaded
+        let cache = asset_cache::global();
+        let pending = pending
+            .into_iter()
+            .map(|(handle, asset_id)| (handle, cache.get::<MeshLoader>(asset_id)))
+            .collect::<Vec<_>>();
+
+        for (handle, mesh_handle) in pending {
+            let asset_id = mesh_handle.id().clone();
+            let mesh = match mesh_handle.wait() {
+                Ok(mesh) => mesh,
+                Err(e) => {
+                    ris_log::error!("failed to load mesh {:?}: {}", asset_id, e);
+                    self.failed_mesh_assets.push(asset_id);
+                    continue;
+                }
+            };
+
+            handle.upload(
+                scene,
+                &core.device,
+                physical_device_memory_properties,
+                &mesh,
+            )?;
+        }
+
+        Ok(())
+    }
+
     pub fn draw(
         &mut self,
         core: &VulkanCore,
@@ -550,6 +697,8 @@ impl SceneRenderer {
         camera: &Camera,
         scene: &Scene,
     ) -> RisResult<()> {
+        self.upload_pending_meshes(core, scene)?;
+
         let VulkanCore {
             device, swapchain, ..
         } = core;
@@ -710,22 +859,15 @@ impl SceneRenderer {
                 &[],
             );
 
-            for mesh_renderer_component in scene.mesh_renderer_components.iter() {
-                let aref = mesh_renderer_component.borrow();
-                if !aref.is_alive {
-                    continue;
-                }
-
-                let game_object = aref.game_object();
-                if game_object.is_active_in_hierarchy(scene) != Ok(true) {
-                    continue;
-                }
-
+            for (game_object, mesh_renderer) in
+                scene.query::<MeshRendererComponent>()?.active_only()
+            {
                 let Ok(model) = game_object.model(scene) else {
                     continue;
                 };
 
-                let Some(video_mesh_handle) = aref.video_mesh() else {
+                let mesh_renderer = MeshRendererComponentHandle::from(mesh_renderer);
+                let Ok(Some(video_mesh_handle)) = mesh_renderer.video_mesh(scene) else {
                     continue;
                 };
 
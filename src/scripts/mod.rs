@@ -51,7 +51,7 @@ pub fn spawn_many_objects(god_object: &GodObject) -> RisResult<()> {
             &god_object.state.scene,
             &god_object.output_frame.core.device,
             physical_device_memory_properties,
-            mesh,
+            &mesh,
         )?;
         let mesh_renderer: MeshRendererComponentHandle =
             game_object.add_component(&god_object.state.scene)?.into();
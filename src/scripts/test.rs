@@ -58,7 +58,7 @@ impl Script for TestRotationScript {
         Ok(())
     }
 
-    fn deserialize(&mut self, f: &mut SceneReader) -> RisResult<()> {
+    fn deserialize(&mut self, f: &mut SceneReader, _version: u32) -> RisResult<()> {
         self.rotation_axis = ris_io::read_vec3(f)?;
         Ok(())
     }
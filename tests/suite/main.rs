@@ -1,4 +1,5 @@
 pub mod ris_asset;
+pub mod ris_core;
 pub mod ris_data;
 pub mod ris_debug;
 pub mod ris_input;
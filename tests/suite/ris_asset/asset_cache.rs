@@ -0,0 +1,134 @@
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use ris_asset::asset_cache::AssetCache;
+use ris_asset::asset_cache::IAssetLoader;
+use ris_asset::asset_cache::RawLoader;
+use ris_asset::asset_loader::LoadError;
+use ris_data::asset_id::AssetId;
+use ris_error::RisResult;
+use ris_jobs::job_future::SettableJobFuture;
+
+type Pending = Arc<Mutex<Vec<(AssetId, SettableJobFuture<Result<Vec<u8>, LoadError>>)>>>;
+
+struct LenLoader;
+
+impl IAssetLoader for LenLoader {
+    type Asset = usize;
+
+    fn decode(bytes: Vec<u8>) -> RisResult<Self::Asset> {
+        if bytes.is_empty() {
+            return ris_error::new_result!("empty");
+        }
+
+        Ok(bytes.len())
+    }
+}
+
+fn path(value: &str) -> AssetId {
+    AssetId::Path(value.to_string())
+}
+
+fn create_cache() -> (AssetCache, Pending) {
+    let pending = Pending::default();
+    let pending_copy = pending.clone();
+    let cache = AssetCache::new(Box::new(move |id| {
+        let (settable, future) = SettableJobFuture::new();
+        pending_copy.lock().unwrap().push((id, settable));
+        future
+    }));
+
+    (cache, pending)
+}
+
+fn complete(pending: &Pending, bytes: &[u8]) {
+    for (_, settable) in pending.lock().unwrap().drain(..) {
+        settable.set(Ok(bytes.to_vec()));
+    }
+}
+
+#[test]
+fn should_share_in_flight_loads() {
+    let (cache, pending) = create_cache();
+
+    let first = cache.get::<LenLoader>(path("a"));
+    let second = cache.get::<LenLoader>(path("a"));
+    let other = cache.get::<LenLoader>(path("b"));
+    assert_eq!(pending.lock().unwrap().len(), 2);
+    assert_eq!(cache.len(), 2);
+
+    assert!(first.try_get().is_none());
+    complete(&pending, &[1, 2, 3]);
+
+    let first_asset = first.try_get().unwrap().unwrap();
+    let second_asset = second.wait().unwrap();
+    assert_eq!(*first_asset, 3);
+    assert!(Arc::ptr_eq(&first_asset, &second_asset));
+    assert_eq!(*other.wait().unwrap(), 3);
+}
+
+#[test]
+fn should_share_asset_per_loader() {
+    let (cache, pending) = create_cache();
+
+    let len = cache.get::<LenLoader>(path("a"));
+    let raw = cache.get::<RawLoader>(path("a"));
+    assert_eq!(pending.lock().unwrap().len(), 2);
+    complete(&pending, &[7, 8]);
+
+    assert_eq!(*len.wait().unwrap(), 2);
+    assert_eq!(*raw.wait().unwrap(), vec![7, 8]);
+}
+
+#[test]
+fn should_evict_when_last_handle_drops() {
+    let (cache, pending) = create_cache();
+
+    let handle = cache.get::<LenLoader>(path("a"));
+    let clone = handle.clone();
+    complete(&pending, &[1]);
+    handle.wait().unwrap();
+
+    drop(handle);
+    assert_eq!(cache.len(), 1);
+    let _same = cache.get::<LenLoader>(path("a"));
+    assert!(pending.lock().unwrap().is_empty());
+
+    drop(clone);
+    drop(_same);
+    assert!(cache.is_empty());
+
+    let _reloaded = cache.get::<LenLoader>(path("a"));
+    assert_eq!(pending.lock().unwrap().len(), 1);
+}
+
+#[test]
+fn should_reload_invalidated_asset() {
+    let (cache, pending) = create_cache();
+
+    let old = cache.get::<LenLoader>(path("a"));
+    complete(&pending, &[1]);
+    cache.invalidate(&path("a"));
+
+    let new = cache.get::<LenLoader>(path("a"));
+    complete(&pending, &[1, 2]);
+
+    assert_eq!(*old.wait().unwrap(), 1);
+    assert_eq!(*new.wait().unwrap(), 2);
+}
+
+#[test]
+fn should_report_failed_loads() {
+    let (cache, pending) = create_cache();
+
+    let undecodable = cache.get::<LenLoader>(path("a"));
+    complete(&pending, &[]);
+    assert!(undecodable.wait().is_err());
+    assert!(undecodable.try_get().unwrap().is_err());
+
+    let missing = cache.get::<LenLoader>(path("b"));
+    for (_, settable) in pending.lock().unwrap().drain(..) {
+        settable.set(Err(LoadError::LoadFailed));
+    }
+    assert!(missing.wait().is_err());
+}
@@ -0,0 +1,184 @@
+use std::path::Path;
+
+use ris_asset::asset_compiler;
+use ris_asset::asset_compiler::CompileOptions;
+use ris_asset::asset_graph::AssetGraph;
+use ris_asset::asset_importer;
+use ris_asset::RisHeader;
+use ris_data::asset_id::AssetId;
+
+const MAGIC: [u8; 16] = [
+    0x72, 0x69, 0x73, 0x5F, 0x74, 0x65, 0x73, 0x74, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+];
+
+const TRIANGLE: &str = "v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n";
+
+fn write(path: &Path, content: impl AsRef<[u8]>) {
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, content).unwrap();
+}
+
+fn write_ris_asset(path: &Path, references: &[&str]) {
+    let references = references
+        .iter()
+        .map(|x| AssetId::Path(x.to_string()))
+        .collect();
+    let header = RisHeader::new(MAGIC, references);
+    write(path, header.serialize().unwrap());
+}
+
+fn write_shaders(dir: &Path) {
+    write(
+        &dir.join("shader.glsl"),
+        "#ris_glsl 450 vertex\n#include util/a.glsl\n",
+    );
+    write(
+        &dir.join("other.glsl"),
+        "#ris_glsl 450 vertex\n#include util/b.glsl\n",
+    );
+    write(
+        &dir.join("util/a.glsl"),
+        "#ris_glsl header\n#include util/b.glsl\n",
+    );
+    write(&dir.join("util/b.glsl"), "#ris_glsl header\n");
+}
+
+#[test]
+fn should_find_glsl_includes_and_ris_header_references() {
+    let test_dir = ris_util::prep_test_dir!();
+    write_shaders(&test_dir);
+    write_ris_asset(&test_dir.join("scene"), &["mesh", "texture"]);
+    write_ris_asset(&test_dir.join("mesh"), &[]);
+    write(&test_dir.join("texture"), [1, 2, 3]);
+
+    let graph = AssetGraph::scan(&test_dir).unwrap();
+
+    assert_eq!(graph.nodes.len(), 7);
+    assert_eq!(
+        graph.nodes["shader.glsl"].dependencies,
+        vec!["util/a.glsl", "util/b.glsl"],
+    );
+    assert_eq!(graph.nodes["other.glsl"].dependencies, vec!["util/b.glsl"]);
+    assert!(graph.nodes["util/a.glsl"].dependencies.is_empty());
+    assert_eq!(graph.nodes["scene"].dependencies, vec!["mesh", "texture"]);
+    assert!(graph.nodes["texture"].dependencies.is_empty());
+
+    assert_eq!(
+        graph.dependents("util/b.glsl"),
+        vec!["other.glsl", "shader.glsl"],
+    );
+    assert_eq!(graph.dependents("mesh"), vec!["scene"]);
+}
+
+#[test]
+fn should_mark_dependents_of_changed_assets_as_changed() {
+    let test_dir = ris_util::prep_test_dir!();
+    write_shaders(&test_dir);
+
+    let previous = AssetGraph::scan(&test_dir).unwrap();
+    let changed = AssetGraph::scan(&test_dir).unwrap().changed(&previous);
+    assert!(changed.is_empty());
+
+    write(
+        &test_dir.join("util/a.glsl"),
+        "#ris_glsl header\n// changed\n",
+    );
+    let changed = AssetGraph::scan(&test_dir).unwrap().changed(&previous);
+    assert_eq!(
+        changed.into_iter().collect::<Vec<_>>(),
+        vec!["shader.glsl", "util/a.glsl"],
+    );
+
+    std::fs::remove_file(test_dir.join("util/b.glsl")).unwrap();
+    let changed = AssetGraph::scan(&test_dir).unwrap().changed(&previous);
+    assert_eq!(
+        changed.into_iter().collect::<Vec<_>>(),
+        vec!["other.glsl", "shader.glsl", "util/a.glsl"],
+    );
+
+    let changed = AssetGraph::scan(&test_dir)
+        .unwrap()
+        .changed(&AssetGraph::default());
+    assert_eq!(changed.len(), 3);
+}
+
+#[test]
+fn should_save_and_load() {
+    let test_dir = ris_util::prep_test_dir!();
+    write_shaders(&test_dir.join("source"));
+    let graph_path = test_dir.join("graph");
+
+    assert_eq!(
+        AssetGraph::load(&graph_path).unwrap(),
+        AssetGraph::default()
+    );
+
+    let graph = AssetGraph::scan(&test_dir.join("source")).unwrap();
+    graph.save(&graph_path).unwrap();
+    let loaded = AssetGraph::load(&graph_path).unwrap();
+
+    assert_eq!(loaded, graph);
+}
+
+#[test]
+fn should_only_import_changed_source_files() {
+    let test_dir = ris_util::prep_test_dir!();
+    let source = test_dir.join("source");
+    let imported = test_dir.join("imported");
+    let in_use = test_dir.join("in_use");
+    write(&source.join("a.obj"), TRIANGLE);
+    write(&source.join("b.obj"), TRIANGLE);
+
+    let import = || {
+        asset_importer::import_all(
+            source.to_str().unwrap(),
+            imported.to_str().unwrap(),
+            in_use.to_str().unwrap(),
+            None,
+        )
+        .unwrap()
+    };
+
+    import();
+    assert!(imported.join("a.ris_mesh").exists());
+    assert!(imported.join("b.ris_mesh").exists());
+
+    std::fs::remove_file(imported.join("a.ris_mesh")).unwrap();
+    std::fs::remove_file(imported.join("b.ris_mesh")).unwrap();
+    write(&source.join("b.obj"), format!("{}f 3 2 1\n", TRIANGLE));
+    import();
+
+    assert!(!imported.join("a.ris_mesh").exists());
+    assert!(imported.join("b.ris_mesh").exists());
+}
+
+#[test]
+fn should_skip_compilation_when_no_asset_changed() {
+    let test_dir = ris_util::prep_test_dir!();
+    let source = test_dir.join("in_use");
+    let target = test_dir.join("ris_assets");
+    write_ris_asset(&source.join("scene"), &["texture"]);
+    write(&source.join("texture"), [1; 32]);
+
+    let compile = |include_original_paths| {
+        let options = CompileOptions {
+            include_original_paths,
+        };
+        asset_compiler::compile(source.to_str().unwrap(), target.to_str().unwrap(), options)
+            .unwrap();
+        std::fs::read(&target).unwrap()
+    };
+
+    // append a byte, to detect whether the file was written again
+    let mut compiled = compile(false);
+    compiled.push(0);
+    write(&target, &compiled);
+
+    assert_eq!(compile(false), compiled);
+
+    let recompiled = compile(true);
+    assert_ne!(recompiled, compiled);
+
+    write(&source.join("texture"), [2; 32]);
+    assert_ne!(compile(true), recompiled);
+}
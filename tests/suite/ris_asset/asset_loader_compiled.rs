@@ -0,0 +1,101 @@
+use std::path::Path;
+use std::path::PathBuf;
+
+use ris_asset::asset_compiler;
+use ris_asset::asset_compiler::CompileOptions;
+use ris_asset::asset_loader::LoadError;
+use ris_asset::asset_loader_compiled::AssetLoaderCompiled;
+
+fn compile(test_dir: &Path, assets: &[Vec<u8>]) -> PathBuf {
+    let source = test_dir.join("in_use");
+    let target = test_dir.join("ris_assets");
+    std::fs::create_dir_all(&source).unwrap();
+    for (i, asset) in assets.iter().enumerate() {
+        std::fs::write(source.join(format!("asset_{}", i)), asset).unwrap();
+    }
+
+    asset_compiler::compile(
+        source.to_str().unwrap(),
+        target.to_str().unwrap(),
+        CompileOptions::default(),
+    )
+    .unwrap();
+
+    target
+}
+
+fn test_assets() -> Vec<Vec<u8>> {
+    let compressible = vec![42; 4096];
+    let incompressible = (0..4096u32)
+        .map(|x| (x.wrapping_mul(2_654_435_761) >> 24) as u8)
+        .collect();
+    vec![compressible, incompressible]
+}
+
+#[test]
+fn should_compress_and_load_assets() {
+    let test_dir = ris_util::prep_test_dir!();
+    let assets = test_assets();
+    let target = compile(&test_dir, &assets);
+
+    let compiled_len = std::fs::metadata(&target).unwrap().len() as usize;
+    assert!(compiled_len < assets[0].len() + assets[1].len());
+
+    let mut loader = AssetLoaderCompiled::new(&target).unwrap();
+    let mut loaded = vec![loader.load(0).unwrap(), loader.load(1).unwrap()];
+    loaded.sort();
+    let mut expected = assets;
+    expected.sort();
+    assert_eq!(loaded, expected);
+
+    assert!(matches!(loader.load(2), Err(LoadError::LoadFailed)));
+}
+
+#[test]
+fn should_detect_corrupted_asset() {
+    let test_dir = ris_util::prep_test_dir!();
+    let target = compile(&test_dir, &test_assets());
+
+    let mut bytes = std::fs::read(&target).unwrap();
+    let len = bytes.len();
+    bytes[len - 100] ^= 0xFF;
+    std::fs::write(&target, bytes).unwrap();
+
+    let mut loader = AssetLoaderCompiled::new(&target).unwrap();
+    let results = [loader.load(0), loader.load(1)];
+    let corrupted = results
+        .iter()
+        .filter(|x| matches!(x, Err(LoadError::Corrupted)))
+        .count();
+    assert_eq!(corrupted, 1);
+}
+
+#[test]
+fn should_not_open_truncated_file() {
+    let test_dir = ris_util::prep_test_dir!();
+    let target = compile(&test_dir, &test_assets());
+
+    let bytes = std::fs::read(&target).unwrap();
+    std::fs::write(&target, &bytes[..bytes.len() - 1]).unwrap();
+
+    assert!(AssetLoaderCompiled::new(&target).is_err());
+}
+
+#[test]
+fn should_decompile_compressed_assets() {
+    let test_dir = ris_util::prep_test_dir!();
+    let assets = test_assets();
+    let target = compile(&test_dir, &assets);
+    let decompiled = test_dir.join("decompiled");
+
+    asset_compiler::decompile(target.to_str().unwrap(), decompiled.to_str().unwrap()).unwrap();
+
+    let mut decompiled_assets = std::fs::read_dir(&decompiled)
+        .unwrap()
+        .map(|x| std::fs::read(x.unwrap().path()).unwrap())
+        .collect::<Vec<_>>();
+    decompiled_assets.sort();
+    let mut expected = assets;
+    expected.sort();
+    assert_eq!(decompiled_assets, expected);
+}
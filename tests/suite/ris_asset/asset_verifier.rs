@@ -0,0 +1,150 @@
+use std::path::Path;
+
+use ris_asset::asset_compiler;
+use ris_asset::asset_compiler::CompileOptions;
+use ris_asset::asset_verifier;
+use ris_asset::asset_verifier::Problem;
+use ris_asset::codecs::qoi;
+use ris_asset::codecs::qoi::Channels;
+use ris_asset::codecs::qoi::ColorSpace;
+use ris_asset::codecs::qoi::QoiDesc;
+use ris_asset::RisGodAsset;
+use ris_asset::RisHeader;
+use ris_data::asset_id::AssetId;
+
+fn spirv() -> Vec<u8> {
+    // header, followed by `OpCapability Shader`
+    [0x0723_0203u32, 0x0001_0000, 0, 8, 0, (2 << 16) | 17, 1]
+        .iter()
+        .flat_map(|x| x.to_le_bytes())
+        .collect()
+}
+
+fn qoi() -> Vec<u8> {
+    let desc = QoiDesc {
+        width: 2,
+        height: 2,
+        channels: Channels::RGBA,
+        color_space: ColorSpace::SRGB,
+    };
+    qoi::encode(&[255; 16], desc).unwrap()
+}
+
+fn write(path: &Path, content: impl AsRef<[u8]>) {
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, content).unwrap();
+}
+
+fn write_god_asset(dir: &Path, texture: &str) {
+    let spv = AssetId::Path("shader.spv".to_string());
+    let god_asset = RisGodAsset {
+        default_vert_spv: spv.clone(),
+        default_frag_spv: spv.clone(),
+        imgui_vert_spv: spv.clone(),
+        imgui_frag_spv: spv.clone(),
+        gizmo_segment_vert_spv: spv.clone(),
+        gizmo_segment_geom_spv: spv.clone(),
+        gizmo_segment_frag_spv: spv.clone(),
+        gizmo_text_vert_spv: spv.clone(),
+        gizmo_text_geom_spv: spv.clone(),
+        gizmo_text_frag_spv: spv.clone(),
+        debug_font_texture: AssetId::Path("font.qoi".to_string()),
+        texture: AssetId::Path(texture.to_string()),
+    };
+
+    write(
+        &dir.join("god_asset.ris_god_asset"),
+        god_asset.serialize().unwrap(),
+    );
+    write(&dir.join("shader.spv"), spirv());
+    write(&dir.join("font.qoi"), qoi());
+}
+
+#[test]
+fn should_verify_valid_assets() {
+    let test_dir = ris_util::prep_test_dir!();
+    let source = test_dir.join("in_use");
+    let target = test_dir.join("ris_assets");
+    write_god_asset(&source, "font.qoi");
+
+    let report = asset_verifier::verify(&source).unwrap();
+    assert_eq!(report.asset_count, 3);
+    assert!(report.problems.is_empty(), "{:?}", report.problems);
+
+    asset_compiler::compile(
+        source.to_str().unwrap(),
+        target.to_str().unwrap(),
+        CompileOptions::default(),
+    )
+    .unwrap();
+
+    let report = asset_verifier::verify(&target).unwrap();
+    assert_eq!(report.asset_count, 3);
+    assert!(report.problems.is_empty(), "{:?}", report.problems);
+}
+
+#[test]
+fn should_report_problems() {
+    let test_dir = ris_util::prep_test_dir!();
+    write_god_asset(&test_dir, "missing.qoi");
+
+    let mut spirv = spirv();
+    spirv.truncate(spirv.len() - 4);
+    write(&test_dir.join("broken.spv"), spirv);
+    write(&test_dir.join("broken.qoi"), [0; 32]);
+
+    let unknown_magic = *b"ris_unknown\0\0\0\0\0";
+    let unknown = RisHeader::new(unknown_magic, Vec::new());
+    write(&test_dir.join("unknown"), unknown.serialize().unwrap());
+
+    let report = asset_verifier::verify(&test_dir).unwrap();
+    let mut problems = report
+        .problems
+        .iter()
+        .map(|x| x.to_string())
+        .collect::<Vec<_>>();
+    problems.sort();
+
+    assert_eq!(report.error_count(), 4, "{:?}", problems);
+    assert_eq!(report.warning_count(), 3, "{:?}", problems);
+
+    let has_problem = |f: &dyn Fn(&Problem) -> bool| report.problems.iter().any(f);
+    assert!(has_problem(&|x| matches!(
+        x,
+        Problem::DanglingReference { reference: AssetId::Path(path), .. } if path == "missing.qoi"
+    )));
+    assert!(has_problem(&|x| matches!(
+        x,
+        Problem::Undecodable { asset: AssetId::Path(path), .. } if path == "broken.spv"
+    )));
+    assert!(has_problem(&|x| matches!(
+        x,
+        Problem::Undecodable { asset: AssetId::Path(path), .. } if path == "broken.qoi"
+    )));
+    assert!(has_problem(&|x| matches!(
+        x,
+        Problem::UnknownMagic { asset: AssetId::Path(path), .. } if path == "unknown"
+    )));
+    assert!(has_problem(&|x| matches!(
+        x,
+        Problem::Orphaned { asset: AssetId::Path(path) } if path == "unknown"
+    )));
+}
+
+#[test]
+fn should_report_missing_god_asset() {
+    let test_dir = ris_util::prep_test_dir!();
+    write(&test_dir.join("image.qoi"), qoi());
+
+    let report = asset_verifier::verify(&test_dir).unwrap();
+
+    assert_eq!(report.error_count(), 1);
+    assert!(matches!(report.problems[0], Problem::MissingGodAsset));
+}
+
+#[test]
+fn should_parse_spirv() {
+    assert!(asset_verifier::parse_spirv(&spirv()).is_ok());
+    assert!(asset_verifier::parse_spirv(&spirv()[..6]).is_err());
+    assert!(asset_verifier::parse_spirv(&[0; 20]).is_err());
+}
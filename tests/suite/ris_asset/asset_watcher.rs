@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+use ris_asset::asset_watcher::AssetWatcher;
+use ris_data::asset_id::AssetId;
+
+fn path(value: &str) -> AssetId {
+    AssetId::Path(value.to_string())
+}
+
+#[test]
+fn should_report_created_modified_and_deleted_assets() {
+    let test_dir = ris_util::prep_test_dir!();
+    std::fs::write(test_dir.join("modified"), [0]).unwrap();
+    std::fs::write(test_dir.join("deleted"), [0]).unwrap();
+    std::fs::write(test_dir.join("unchanged"), [0]).unwrap();
+
+    let mut watcher = AssetWatcher::new(&test_dir, Duration::ZERO).unwrap();
+    assert!(watcher.poll().unwrap().is_empty());
+
+    std::fs::create_dir_all(test_dir.join("sub")).unwrap();
+    std::fs::write(test_dir.join("sub").join("created"), [0]).unwrap();
+    std::fs::write(test_dir.join("modified"), [0, 1]).unwrap();
+    std::fs::remove_file(test_dir.join("deleted")).unwrap();
+
+    let mut changed = watcher.poll().unwrap();
+    changed.sort_by_key(|x| format!("{:?}", x));
+    assert_eq!(
+        changed,
+        vec![path("deleted"), path("modified"), path("sub/created")],
+    );
+
+    assert!(watcher.poll().unwrap().is_empty());
+}
+
+#[test]
+fn should_notify_subscriptions_about_their_assets() {
+    let test_dir = ris_util::prep_test_dir!();
+    std::fs::write(test_dir.join("a"), [0]).unwrap();
+    std::fs::write(test_dir.join("b"), [0]).unwrap();
+
+    let mut watcher = AssetWatcher::new(&test_dir, Duration::ZERO).unwrap();
+    let subscription_a = watcher.subscribe(vec![path("a")]);
+    let subscription_ab = watcher.subscribe(vec![path("a"), path("b")]);
+
+    std::fs::write(test_dir.join("b"), [0, 1]).unwrap();
+    watcher.poll().unwrap();
+
+    assert!(!subscription_a.has_changed());
+    assert!(subscription_ab.has_changed());
+    assert_eq!(subscription_ab.take_changed(), vec![path("b")]);
+    assert!(!subscription_ab.has_changed());
+
+    std::fs::write(test_dir.join("a"), [0, 1]).unwrap();
+    watcher.poll().unwrap();
+    std::fs::write(test_dir.join("a"), [0, 1, 2]).unwrap();
+    watcher.poll().unwrap();
+
+    assert_eq!(subscription_a.take_changed(), vec![path("a")]);
+    assert_eq!(subscription_ab.take_changed(), vec![path("a")]);
+}
+
+#[test]
+fn should_not_poll_before_interval_has_passed() {
+    let test_dir = ris_util::prep_test_dir!();
+    std::fs::write(test_dir.join("a"), [0]).unwrap();
+
+    let mut watcher = AssetWatcher::new(&test_dir, Duration::from_secs(3600)).unwrap();
+    std::fs::write(test_dir.join("a"), [0, 1]).unwrap();
+
+    assert!(watcher.poll().unwrap().is_empty());
+    assert_eq!(watcher.poll_now().unwrap(), vec![path("a")]);
+}
@@ -0,0 +1,120 @@
+use std::path::Path;
+
+use ris_asset::importer::gltf_to_ris_mesh_importer;
+use ris_math::vector::Vec2;
+use ris_math::vector::Vec3;
+
+// a single triangle with positions (0,0,0), (1,0,0), (0,1,0), uvs and u16 indices, embedded as
+// base64. layout: 36 bytes positions, 24 bytes uvs, 6 bytes indices
+const TRIANGLE_BUFFER: &str =
+    "AAAAAAAAAAAAAAAAAACAPwAAAAAAAAAAAAAAAAAAgD8AAAAAAAAAAAAAAAAAAIA/AAAAAAAAAAAAAIA/AAABAAIA";
+
+fn triangle_gltf(node: &str) -> String {
+    format!(
+        r#"{{
+            "asset": {{ "version": "2.0" }},
+            "scene": 0,
+            "scenes": [ {{ "nodes": [0] }} ],
+            "nodes": [ {node} ],
+            "meshes": [ {{ "primitives": [ {{
+                "attributes": {{ "POSITION": 0, "TEXCOORD_0": 1 }},
+                "indices": 2
+            }} ] }} ],
+            "buffers": [ {{
+                "byteLength": 66,
+                "uri": "data:application/octet-stream;base64,{TRIANGLE_BUFFER}"
+            }} ],
+            "bufferViews": [
+                {{ "buffer": 0, "byteOffset": 0, "byteLength": 36 }},
+                {{ "buffer": 0, "byteOffset": 36, "byteLength": 24 }},
+                {{ "buffer": 0, "byteOffset": 60, "byteLength": 6 }}
+            ],
+            "accessors": [
+                {{ "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3" }},
+                {{ "bufferView": 1, "componentType": 5126, "count": 3, "type": "VEC2" }},
+                {{ "bufferView": 2, "componentType": 5123, "count": 3, "type": "SCALAR" }}
+            ]
+        }}"#
+    )
+}
+
+#[test]
+fn should_decode_embedded_triangle() {
+    let gltf = triangle_gltf(r#"{ "mesh": 0 }"#);
+    let mesh = gltf_to_ris_mesh_importer::decode(&gltf, Path::new("")).unwrap();
+
+    assert_eq!(mesh.vertices.len(), 3);
+    assert_eq!(mesh.indices, vec![0, 1, 2]);
+
+    // gltf (x, y, z) is converted to (-x, z, y)
+    assert_eq!(mesh.vertices[0].pos, Vec3(0.0, 0.0, 0.0));
+    assert_eq!(mesh.vertices[1].pos, Vec3(-1.0, 0.0, 0.0));
+    assert_eq!(mesh.vertices[2].pos, Vec3(0.0, 0.0, 1.0));
+
+    assert_eq!(mesh.vertices[1].uv, Vec2(1.0, 0.0));
+    assert_eq!(mesh.vertices[2].uv, Vec2(0.0, 1.0));
+}
+
+#[test]
+fn should_apply_node_transforms() {
+    let gltf = triangle_gltf(
+        r#"{ "children": [1], "translation": [0, 0, 5] },
+           { "mesh": 0, "scale": [2, 2, 2] }"#,
+    );
+    let mesh = gltf_to_ris_mesh_importer::decode(&gltf, Path::new("")).unwrap();
+
+    assert_eq!(mesh.vertices[0].pos, Vec3(0.0, 5.0, 0.0));
+    assert_eq!(mesh.vertices[1].pos, Vec3(-2.0, 5.0, 0.0));
+    assert_eq!(mesh.vertices[2].pos, Vec3(0.0, 5.0, 2.0));
+}
+
+#[test]
+fn should_load_external_buffer() {
+    let directory = std::env::temp_dir().join("ris_engine_gltf_test");
+    std::fs::create_dir_all(&directory).unwrap();
+
+    let gltf = triangle_gltf(r#"{ "mesh": 0 }"#).replace(
+        &format!("data:application/octet-stream;base64,{}", TRIANGLE_BUFFER),
+        "triangle%20buffer.bin",
+    );
+
+    let mut bytes = Vec::new();
+    for value in [
+        0.0f32, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0,
+    ] {
+        bytes.extend_from_slice(&value.to_le_bytes());
+    }
+    for index in [0u16, 1, 2] {
+        bytes.extend_from_slice(&index.to_le_bytes());
+    }
+    std::fs::write(directory.join("triangle buffer.bin"), &bytes).unwrap();
+
+    let mesh = gltf_to_ris_mesh_importer::decode(&gltf, &directory).unwrap();
+
+    let embedded = triangle_gltf(r#"{ "mesh": 0 }"#);
+    let expected = gltf_to_ris_mesh_importer::decode(&embedded, Path::new("")).unwrap();
+
+    assert_eq!(mesh.vertices.len(), expected.vertices.len());
+    for (expected, actual) in expected.vertices.iter().zip(mesh.vertices.iter()) {
+        assert_eq!(expected.pos, actual.pos);
+        assert_eq!(expected.uv, actual.uv);
+    }
+    assert_eq!(mesh.indices, expected.indices);
+}
+
+#[test]
+fn should_not_decode_non_triangle_primitives() {
+    let gltf = triangle_gltf(r#"{ "mesh": 0 }"#).replace("\"indices\": 2", "\"mode\": 1");
+    let result = gltf_to_ris_mesh_importer::decode(&gltf, Path::new(""));
+    assert!(result.is_err());
+}
+
+#[test]
+fn should_not_download_buffers() {
+    let gltf = triangle_gltf(r#"{ "mesh": 0 }"#).replace(
+        &format!("data:application/octet-stream;base64,{}", TRIANGLE_BUFFER),
+        "https://example.com/buffer.bin",
+    );
+    let result = gltf_to_ris_mesh_importer::decode(&gltf, Path::new(""));
+    assert!(result.is_err());
+}
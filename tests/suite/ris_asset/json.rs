@@ -0,0 +1,67 @@
+use ris_asset::codecs::json;
+use ris_asset::codecs::json::JsonValue;
+
+#[test]
+fn should_decode_primitives() {
+    assert_eq!(json::decode("null").unwrap(), JsonValue::Null);
+    assert_eq!(json::decode("true").unwrap(), JsonValue::Bool(true));
+    assert_eq!(json::decode(" false ").unwrap(), JsonValue::Bool(false));
+    assert_eq!(json::decode("-12.5e1").unwrap(), JsonValue::Number(-125.0));
+    assert_eq!(json::decode("0").unwrap(), JsonValue::Number(0.0));
+}
+
+#[test]
+fn should_decode_strings_with_escapes() {
+    let value = json::decode(r#""a\"b\\c\/d\n\u00e4\ud83d\ude00""#).unwrap();
+    assert_eq!(
+        value,
+        JsonValue::String(String::from("a\"b\\c/d\n\u{e4}\u{1f600}"))
+    );
+}
+
+#[test]
+fn should_decode_nested_values() {
+    let value = json::decode(r#"{"a": [1, 2, {"b": "c"}], "d": {}, "e": []}"#).unwrap();
+
+    let a = value.get("a").unwrap().as_array().unwrap();
+    assert_eq!(a.len(), 3);
+    assert_eq!(a[0].as_usize(), Some(1));
+    assert_eq!(a[1].as_usize(), Some(2));
+    assert_eq!(a[2].get("b").unwrap().as_str(), Some("c"));
+    assert_eq!(value.get("d"), Some(&JsonValue::Object(Vec::new())));
+    assert_eq!(value.get("e"), Some(&JsonValue::Array(Vec::new())));
+    assert_eq!(value.get("f"), None);
+}
+
+#[test]
+fn should_not_decode_invalid_json() {
+    let inputs = [
+        "",
+        "nul",
+        "01",
+        "1.",
+        "-",
+        "[1,]",
+        "[1 2]",
+        "{\"a\" 1}",
+        "{a: 1}",
+        "\"unterminated",
+        "\"\\x\"",
+        "1 2",
+    ];
+
+    for input in inputs {
+        assert!(
+            json::decode(input).is_err(),
+            "{:?} should be invalid",
+            input
+        );
+    }
+}
+
+#[test]
+fn should_not_convert_fractions_to_usize() {
+    assert_eq!(JsonValue::Number(1.5).as_usize(), None);
+    assert_eq!(JsonValue::Number(-1.0).as_usize(), None);
+    assert_eq!(JsonValue::Number(7.0).as_usize(), Some(7));
+}
@@ -1,2 +1,12 @@
+pub mod asset_cache;
+pub mod asset_graph;
+pub mod asset_loader_compiled;
+pub mod asset_verifier;
+pub mod asset_watcher;
+pub mod gltf_to_ris_mesh_importer;
+pub mod json;
+pub mod obj_to_ris_mesh_importer;
 pub mod qoi;
+pub mod ris_mesh;
+pub mod ris_prefab;
 pub mod ris_scene;
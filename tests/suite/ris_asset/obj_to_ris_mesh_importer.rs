@@ -0,0 +1,71 @@
+use ris_asset::importer::obj_to_ris_mesh_importer;
+use ris_math::vector::Vec2;
+use ris_math::vector::Vec3;
+
+#[test]
+fn should_decode_quad() {
+    let obj = "
+        # a quad in the xy plane, facing +z
+        o quad
+        v -1.0 -1.0 0.0
+        v 1.0 -1.0 0.0
+        v 1.0 1.0 0.0
+        v -1.0 1.0 0.0
+        vt 0.0 0.0
+        vt 1.0 0.0
+        vt 1.0 1.0
+        vt 0.0 1.0
+        vn 0.0 0.0 1.0
+        f 1/1/1 2/2/1 3/3/1 4/4/1
+    ";
+
+    let mesh = obj_to_ris_mesh_importer::decode(obj).unwrap();
+
+    assert_eq!(mesh.vertices.len(), 4);
+    assert_eq!(mesh.indices, vec![0, 1, 2, 0, 2, 3]);
+
+    // obj y-up is converted to z-up
+    assert_eq!(mesh.vertices[0].pos, Vec3(-1.0, 0.0, -1.0));
+    assert_eq!(mesh.vertices[2].pos, Vec3(1.0, 0.0, 1.0));
+
+    // v is flipped
+    assert_eq!(mesh.vertices[0].uv, Vec2(0.0, 1.0));
+    assert_eq!(mesh.vertices[2].uv, Vec2(1.0, 0.0));
+}
+
+#[test]
+fn should_decode_negative_indices_and_reuse_vertices() {
+    let obj = "
+        v 0 0 0
+        v 1 0 0
+        v 0 1 0
+        v 0 0 1 1 0 0
+        f -4 -3 -2
+        f 1 3 -1
+    ";
+
+    let mesh = obj_to_ris_mesh_importer::decode(obj).unwrap();
+
+    assert_eq!(mesh.vertices.len(), 4);
+    assert_eq!(mesh.indices, vec![0, 1, 2, 0, 2, 3]);
+    assert_eq!(mesh.vertices[3].pos, Vec3(0.0, -1.0, 0.0));
+    assert_eq!(mesh.vertices[3].color.0, 1.0);
+    assert_eq!(mesh.vertices[3].color.1, 0.0);
+}
+
+#[test]
+fn should_not_decode_invalid_obj() {
+    let inputs = [
+        "v 0 0",
+        "v 0 0 0\nf 1 1",
+        "v 0 0 0\nf 1 1 2",
+        "v 0 0 0\nf 0 1 1",
+        "v 0 0 0\nf 1/1 1/1 1/1",
+        "v a b c",
+    ];
+
+    for input in inputs {
+        let result = obj_to_ris_mesh_importer::decode(input);
+        assert!(result.is_err(), "{:?} should be invalid", input);
+    }
+}
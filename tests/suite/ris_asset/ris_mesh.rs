@@ -0,0 +1,65 @@
+use ris_asset::assets::ris_mesh;
+use ris_data::ecs::mesh::Mesh;
+use ris_data::ecs::mesh::Vertex;
+use ris_math::color::Rgb;
+use ris_math::vector::Vec2;
+use ris_math::vector::Vec3;
+use ris_rng::rng::Rng;
+use ris_rng::rng::Seed;
+
+#[test]
+fn should_serialize_and_load() {
+    let mut rng = Rng::new(Seed::new().unwrap());
+
+    let vertex_count = rng.next_i32_between(1, 100) as usize;
+    let mut vertices = Vec::with_capacity(vertex_count);
+    for _ in 0..vertex_count {
+        vertices.push(Vertex {
+            pos: Vec3(rng.next_f32(), rng.next_f32(), rng.next_f32()),
+            color: Rgb(rng.next_f32(), rng.next_f32(), rng.next_f32()),
+            uv: Vec2(rng.next_f32(), rng.next_f32()),
+        });
+    }
+
+    let index_count = rng.next_i32_between(0, 300) as usize;
+    let mut indices = Vec::with_capacity(index_count);
+    for _ in 0..index_count {
+        indices.push(rng.next_i32_between(0, vertex_count as i32 - 1) as u32);
+    }
+
+    let mesh = Mesh { vertices, indices };
+    let bytes = ris_mesh::serialize(&mesh).unwrap();
+    let loaded = ris_mesh::load(&bytes).unwrap();
+
+    assert_eq!(mesh.vertices.len(), loaded.vertices.len());
+    for (expected, actual) in mesh.vertices.iter().zip(loaded.vertices.iter()) {
+        assert_eq!(expected.pos, actual.pos);
+        assert_eq!(expected.color.0, actual.color.0);
+        assert_eq!(expected.color.1, actual.color.1);
+        assert_eq!(expected.color.2, actual.color.2);
+        assert_eq!(expected.uv, actual.uv);
+    }
+    assert_eq!(mesh.indices, loaded.indices);
+}
+
+#[test]
+fn should_not_load_when_index_is_out_of_bounds() {
+    let mesh = Mesh {
+        vertices: vec![Vertex::default(); 3],
+        indices: vec![0, 1, 3],
+    };
+
+    let bytes = ris_mesh::serialize(&mesh).unwrap();
+    let result = ris_mesh::load(&bytes);
+    assert!(result.is_err());
+}
+
+#[test]
+fn should_not_load_when_magic_is_wrong() {
+    let mesh = Mesh::default();
+    let mut bytes = ris_mesh::serialize(&mesh).unwrap();
+    bytes[4] = b'x';
+
+    let result = ris_mesh::load(&bytes);
+    assert!(result.is_err());
+}
@@ -0,0 +1,186 @@
+use ris_asset::assets::ris_prefab;
+use ris_asset::assets::ris_prefab::PrefabOverrides;
+use ris_asset::assets::ris_scene;
+use ris_data::asset_id::AssetId;
+use ris_data::ecs::components::mesh_renderer::MeshRendererComponent;
+use ris_data::ecs::decl::GameObjectHandle;
+use ris_data::ecs::decl::MeshRendererComponentHandle;
+use ris_data::ecs::decl::VideoMeshHandle;
+use ris_data::ecs::game_object::GetFrom;
+use ris_data::ecs::id::GameObjectKind;
+use ris_data::ecs::id::SceneKind;
+use ris_data::ecs::registry::Registry;
+use ris_data::ecs::scene::Scene;
+use ris_data::ecs::scene::SceneCreateInfo;
+use ris_math::vector::Vec3;
+
+fn create_scene() -> Scene {
+    let registry = Registry::new(Vec::new()).unwrap();
+    let scene_create_info = SceneCreateInfo {
+        dynamic_game_objects: 8,
+        static_chunks: 1,
+        game_objects_per_static_chunk: 8,
+        video_meshes: 4,
+        registry: Some(registry),
+        ..Default::default()
+    };
+    Scene::new(scene_create_info).unwrap()
+}
+
+// root
+// +- child 0 (mesh renderer)
+// |  +- grandchild
+// +- child 1
+fn create_tree(scene: &Scene, kind: GameObjectKind) -> (GameObjectHandle, VideoMeshHandle) {
+    let root = GameObjectHandle::new_with_kind(scene, kind).unwrap();
+    root.set_name(scene, "root").unwrap();
+    root.set_local_position(scene, Vec3(1.0, 2.0, 3.0)).unwrap();
+
+    let child0 = GameObjectHandle::new_with_kind(scene, kind).unwrap();
+    child0.set_name(scene, "child 0").unwrap();
+    child0.set_parent(scene, Some(root), 0, false).unwrap();
+    child0
+        .set_local_position(scene, Vec3(0.0, 0.0, 1.0))
+        .unwrap();
+
+    let grandchild = GameObjectHandle::new_with_kind(scene, kind).unwrap();
+    grandchild.set_name(scene, "grandchild").unwrap();
+    grandchild
+        .set_parent(scene, Some(child0), 0, false)
+        .unwrap();
+
+    let child1 = GameObjectHandle::new_with_kind(scene, kind).unwrap();
+    child1.set_name(scene, "child 1").unwrap();
+    child1.set_parent(scene, Some(root), 1, false).unwrap();
+
+    let video_mesh = VideoMeshHandle::new(scene).unwrap();
+    video_mesh
+        .set_asset_id(
+            scene,
+            Some(AssetId::Path("meshes/cube.ris_mesh".to_string())),
+        )
+        .unwrap();
+    let mesh: MeshRendererComponentHandle = child0.add_component(scene).unwrap().into();
+    mesh.set_video_mesh(scene, video_mesh).unwrap();
+
+    (root, video_mesh)
+}
+
+fn names(scene: &Scene, game_objects: Vec<GameObjectHandle>) -> Vec<String> {
+    game_objects
+        .into_iter()
+        .map(|x| x.name(scene).unwrap())
+        .collect()
+}
+
+#[test]
+fn should_instantiate_subtree() {
+    let scene = create_scene();
+    let (root, video_mesh) = create_tree(&scene, GameObjectKind::Dynamic);
+
+    let bytes = ris_prefab::serialize(&scene, root).unwrap();
+    let prefab = ris_prefab::load(&bytes).unwrap();
+    let instance = prefab
+        .instantiate(&scene, &PrefabOverrides::default())
+        .unwrap();
+
+    assert_ne!(instance, root);
+    assert_eq!(instance.0.scene_id().kind, SceneKind::DynamicGameObject);
+    assert_eq!(instance.name(&scene).unwrap(), "root");
+    assert_eq!(instance.parent(&scene).unwrap(), None);
+    assert_eq!(
+        instance.local_position(&scene).unwrap(),
+        Vec3(1.0, 2.0, 3.0)
+    );
+
+    let children = instance.children(&scene).unwrap();
+    assert_eq!(names(&scene, children.clone()), vec!["child 0", "child 1"]);
+    assert_eq!(
+        children[0].local_position(&scene).unwrap(),
+        Vec3(0.0, 0.0, 1.0)
+    );
+
+    let grandchildren = children[0].children(&scene).unwrap();
+    assert_eq!(names(&scene, grandchildren), vec!["grandchild"]);
+
+    let mesh: MeshRendererComponentHandle = children[0]
+        .get_component(&scene, GetFrom::This)
+        .unwrap()
+        .unwrap()
+        .into();
+    assert_eq!(mesh.video_mesh(&scene).unwrap(), Some(video_mesh));
+    assert!(children[1]
+        .get_component::<MeshRendererComponent>(&scene, GetFrom::This)
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn should_instantiate_multiple_times_with_overrides() {
+    let scene = create_scene();
+    let (root, _) = create_tree(&scene, GameObjectKind::Dynamic);
+    let parent = GameObjectHandle::new(&scene).unwrap();
+
+    let bytes = ris_prefab::serialize(&scene, root).unwrap();
+    let prefab = ris_prefab::load(&bytes).unwrap();
+
+    let overrides = PrefabOverrides {
+        name: Some("instance".to_string()),
+        is_active: Some(false),
+        parent: Some(parent),
+        local_position: Some(Vec3(4.0, 5.0, 6.0)),
+        ..Default::default()
+    };
+    let instance0 = prefab.instantiate(&scene, &overrides).unwrap();
+    let instance1 = prefab
+        .instantiate(&scene, &PrefabOverrides::default())
+        .unwrap();
+
+    assert_eq!(instance0.name(&scene).unwrap(), "instance");
+    assert!(!instance0.is_active(&scene).unwrap());
+    assert_eq!(instance0.parent(&scene).unwrap(), Some(parent));
+    assert_eq!(
+        instance0.local_position(&scene).unwrap(),
+        Vec3(4.0, 5.0, 6.0)
+    );
+
+    assert_eq!(instance1.name(&scene).unwrap(), "root");
+    assert!(instance1.is_active(&scene).unwrap());
+    assert_eq!(instance1.parent(&scene).unwrap(), None);
+
+    // instances don't share game objects
+    let children0 = instance0.children(&scene).unwrap();
+    let children1 = instance1.children(&scene).unwrap();
+    assert_eq!(children0.len(), 2);
+    assert_eq!(children1.len(), 2);
+    assert!(children0.iter().all(|x| !children1.contains(x)));
+}
+
+#[test]
+fn should_instantiate_static_subtree_as_dynamic() {
+    let scene = create_scene();
+    let chunk = scene.reserve_chunk().unwrap();
+    let (root, _) = create_tree(&scene, GameObjectKind::Static { chunk });
+    let child = root.children(&scene).unwrap()[0];
+
+    let bytes = ris_prefab::serialize(&scene, child).unwrap();
+    let prefab = ris_prefab::load(&bytes).unwrap();
+    let instance = prefab
+        .instantiate(&scene, &PrefabOverrides::default())
+        .unwrap();
+
+    assert_eq!(instance.0.scene_id().kind, SceneKind::DynamicGameObject);
+    assert_eq!(instance.name(&scene).unwrap(), "child 0");
+    assert_eq!(instance.parent(&scene).unwrap(), None);
+    assert_eq!(instance.children(&scene).unwrap().len(), 1);
+}
+
+#[test]
+fn should_not_load_scene_as_prefab() {
+    let scene = create_scene();
+    assert_eq!(scene.reserve_chunk().unwrap(), 0);
+    GameObjectHandle::new_static(&scene, 0).unwrap();
+
+    let bytes = ris_scene::serialize(&scene, Some(0)).unwrap();
+    assert!(ris_prefab::load(&bytes).is_err());
+}
@@ -3,14 +3,20 @@ use std::any::TypeId;
 use ris_asset::assets::ris_scene;
 use ris_data::asset_id::AssetId;
 use ris_data::ecs::components::script::DynScriptComponent;
+use ris_data::ecs::decl::declare;
 use ris_data::ecs::decl::GameObjectHandle;
+use ris_data::ecs::decl::MeshRendererComponentHandle;
+use ris_data::ecs::decl::VideoMeshHandle;
+use ris_data::ecs::game_object::GetFrom;
 use ris_data::ecs::handle::DynComponentHandle;
 use ris_data::ecs::id::Component;
+use ris_data::ecs::id::GameObjectKind;
 use ris_data::ecs::registry::Registry;
 use ris_data::ecs::scene::Scene;
 use ris_data::ecs::scene::SceneCreateInfo;
 use ris_data::ecs::script_prelude::*;
 use ris_error::RisResult;
+use ris_math::vector::Vec3;
 use ris_rng::rng::Rng;
 use ris_rng::rng::Seed;
 
@@ -76,7 +82,7 @@ impl Script for TestScript {
         Ok(())
     }
 
-    fn deserialize(&mut self, stream: &mut SceneReader) -> RisResult<()> {
+    fn deserialize(&mut self, stream: &mut SceneReader, _version: u32) -> RisResult<()> {
         self.inner.calls.push(Call::Deserialize);
         let payload_len = ris_io::read_uint(stream)?;
         self.inner.payload = vec![0; payload_len];
@@ -92,6 +98,107 @@ impl Script for TestScript {
     }
 }
 
+#[derive(Debug, Default)]
+struct VersionedScript {
+    value: u32,
+    deserialized_version: Option<u32>,
+}
+
+impl Script for VersionedScript {
+    fn start(&mut self, _data: ScriptStartEndData) -> RisResult<()> {
+        Ok(())
+    }
+
+    fn update(&mut self, _data: ScriptUpdateData) -> RisResult<()> {
+        Ok(())
+    }
+
+    fn end(&mut self, _data: ScriptStartEndData) -> RisResult<()> {
+        Ok(())
+    }
+
+    fn serialize(&mut self, stream: &mut SceneWriter) -> RisResult<()> {
+        ris_io::write_uint(stream, self.value as usize)?;
+        Ok(())
+    }
+
+    fn deserialize(&mut self, stream: &mut SceneReader, version: u32) -> RisResult<()> {
+        self.value = ris_io::read_uint(stream)? as u32;
+        self.deserialized_version = Some(version);
+        Ok(())
+    }
+
+    fn inspect(&mut self, _data: ScriptInspectData) -> RisResult<()> {
+        Ok(())
+    }
+
+    fn version(&self) -> u32 {
+        3
+    }
+}
+
+#[derive(Debug, Default)]
+struct CorruptScript;
+
+impl Script for CorruptScript {
+    fn start(&mut self, _data: ScriptStartEndData) -> RisResult<()> {
+        Ok(())
+    }
+
+    fn update(&mut self, _data: ScriptUpdateData) -> RisResult<()> {
+        Ok(())
+    }
+
+    fn end(&mut self, _data: ScriptStartEndData) -> RisResult<()> {
+        Ok(())
+    }
+
+    fn serialize(&mut self, _stream: &mut SceneWriter) -> RisResult<()> {
+        Ok(())
+    }
+
+    fn deserialize(&mut self, _stream: &mut SceneReader, _version: u32) -> RisResult<()> {
+        ris_error::new_result!("corrupt data")
+    }
+
+    fn inspect(&mut self, _data: ScriptInspectData) -> RisResult<()> {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct UnknownComponent {
+    game_object: GameObjectHandle,
+    payload: Vec<u8>,
+}
+
+declare::component!(UnknownComponentHandle, UnknownComponent);
+
+impl Component for UnknownComponent {
+    fn destroy(&mut self, _scene: &Scene) {}
+
+    fn game_object(&self) -> GameObjectHandle {
+        self.game_object
+    }
+
+    fn game_object_mut(&mut self) -> &mut GameObjectHandle {
+        &mut self.game_object
+    }
+
+    fn serialize(&mut self, stream: &mut SceneWriter) -> RisResult<()> {
+        ris_io::write_uint(stream, self.payload.len())?;
+        ris_io::write(stream, &self.payload)?;
+        Ok(())
+    }
+
+    fn deserialize(&mut self, stream: &mut SceneReader, _version: u32) -> RisResult<()> {
+        let len = ris_io::read_uint(stream)?;
+        self.payload = vec![0; len];
+        ris_io::read(stream, &mut self.payload)?;
+        Ok(())
+    }
+}
+
 #[test]
 fn should_serialize() {
     let mut rng = Rng::new(Seed::new().unwrap());
@@ -117,14 +224,22 @@ fn should_serialize() {
     let mut to_unmark_1 = Vec::new();
     for _ in 0..(count / 2) {
         let index = rng.next_i32_between(0, count as i32 - 1) as usize;
-        let mut aref = scene.static_chunks[0].game_objects[index].borrow_mut();
+        let mut aref = scene.static_chunks[0]
+            .game_objects
+            .get(index)
+            .unwrap()
+            .borrow_mut();
         aref.is_alive = true;
         to_unmark_0.push(index);
     }
 
     for _ in 0..(count / 2) {
         let index = rng.next_i32_between(0, count as i32 - 1) as usize;
-        let mut aref = scene.static_chunks[1].game_objects[index].borrow_mut();
+        let mut aref = scene.static_chunks[1]
+            .game_objects
+            .get(index)
+            .unwrap()
+            .borrow_mut();
         aref.is_alive = true;
         to_unmark_1.push(index);
     }
@@ -142,7 +257,11 @@ fn should_serialize() {
     let g9 = GameObjectHandle::new_static(&scene, 0).unwrap();
 
     for index in to_unmark_0 {
-        let mut aref = scene.static_chunks[0].game_objects[index].borrow_mut();
+        let mut aref = scene.static_chunks[0]
+            .game_objects
+            .get(index)
+            .unwrap()
+            .borrow_mut();
         aref.is_alive = false;
     }
 
@@ -193,7 +312,11 @@ fn should_serialize() {
 
     // cleanup
     for index in to_unmark_1 {
-        let mut aref = scene.static_chunks[1].game_objects[index].borrow_mut();
+        let mut aref = scene.static_chunks[1]
+            .game_objects
+            .get(index)
+            .unwrap()
+            .borrow_mut();
         aref.is_alive = false;
     }
 
@@ -211,7 +334,10 @@ fn should_serialize() {
     assert_eq!(left_count, right_count);
 
     for i in 0..count {
-        let left: GameObjectHandle = scene.static_chunks[0].game_objects[i]
+        let left: GameObjectHandle = scene.static_chunks[0]
+            .game_objects
+            .get(i)
+            .unwrap()
             .borrow()
             .handle
             .into();
@@ -245,7 +371,7 @@ fn should_serialize() {
             left.local_rotation(&scene).unwrap(),
             right.local_rotation(&scene).unwrap(),
         );
-        ris_util::assert_feq!(
+        ris_util::assert_vec3_eq!(
             left.local_scale(&scene).unwrap(),
             right.local_scale(&scene).unwrap(),
         );
@@ -294,6 +420,119 @@ fn should_serialize() {
     }
 }
 
+#[test]
+fn should_serialize_mesh_renderer() {
+    let registry = Registry::new(Vec::new()).unwrap();
+    let scene_create_info = SceneCreateInfo {
+        static_chunks: 2,
+        game_objects_per_static_chunk: 4,
+        video_meshes: 4,
+        registry: Some(registry),
+        ..Default::default()
+    };
+    let scene = Scene::new(scene_create_info).unwrap();
+    assert_eq!(scene.reserve_chunk().unwrap(), 0);
+
+    let asset_id = AssetId::Path("meshes/cube.ris_mesh".to_string());
+    let video_mesh = VideoMeshHandle::new(&scene).unwrap();
+    video_mesh
+        .set_asset_id(&scene, Some(asset_id.clone()))
+        .unwrap();
+
+    let g0 = GameObjectHandle::new_static(&scene, 0).unwrap();
+    g0.set_name(&scene, "with mesh").unwrap();
+    let m0: MeshRendererComponentHandle = g0.add_component(&scene).unwrap().into();
+    m0.set_video_mesh(&scene, video_mesh).unwrap();
+
+    let g1 = GameObjectHandle::new_static(&scene, 0).unwrap();
+    g1.set_name(&scene, "without mesh").unwrap();
+    let _m1: MeshRendererComponentHandle = g1.add_component(&scene).unwrap().into();
+
+    let serialized = ris_scene::serialize(&scene, Some(0)).unwrap();
+    let chunk = ris_scene::load(&scene, &serialized).unwrap().unwrap();
+    assert_eq!(chunk, 1);
+
+    let loaded = scene.static_chunks[1]
+        .game_objects
+        .iter()
+        .filter(|x| x.borrow().is_alive)
+        .map(|x| GameObjectHandle::from(x.borrow().handle))
+        .collect::<Vec<_>>();
+    assert_eq!(loaded.len(), 2);
+
+    for game_object in loaded {
+        let components = game_object.components(&scene).unwrap();
+        assert_eq!(components.len(), 1);
+        let mesh_renderer = MeshRendererComponentHandle::from(
+            ris_data::ecs::handle::GenericHandle::from_dyn(*components[0]).unwrap(),
+        );
+        let loaded_video_mesh = mesh_renderer.video_mesh(&scene).unwrap();
+
+        match game_object.name(&scene).unwrap().as_str() {
+            "with mesh" => {
+                // the mesh of the original game object is still alive, thus it must be reused
+                assert_eq!(loaded_video_mesh, Some(video_mesh));
+                assert_eq!(
+                    loaded_video_mesh.unwrap().asset_id(&scene).unwrap(),
+                    Some(asset_id.clone()),
+                );
+            }
+            "without mesh" => assert_eq!(loaded_video_mesh, None),
+            name => panic!("unexpected game object: {}", name),
+        }
+    }
+}
+
+#[test]
+fn should_resolve_mesh_renderer_into_new_video_mesh() {
+    let registry = Registry::new(Vec::new()).unwrap();
+    let scene_create_info = SceneCreateInfo {
+        static_chunks: 2,
+        game_objects_per_static_chunk: 4,
+        video_meshes: 4,
+        registry: Some(registry),
+        ..Default::default()
+    };
+    let scene = Scene::new(scene_create_info).unwrap();
+    assert_eq!(scene.reserve_chunk().unwrap(), 0);
+
+    let asset_id = AssetId::Path("meshes/cube.ris_mesh".to_string());
+    let video_mesh = VideoMeshHandle::new(&scene).unwrap();
+    video_mesh
+        .set_asset_id(&scene, Some(asset_id.clone()))
+        .unwrap();
+
+    let g = GameObjectHandle::new_static(&scene, 0).unwrap();
+    let m: MeshRendererComponentHandle = g.add_component(&scene).unwrap().into();
+    m.set_video_mesh(&scene, video_mesh).unwrap();
+
+    let serialized = ris_scene::serialize(&scene, Some(0)).unwrap();
+    scene.clear_chunk(0);
+    scene.mark_as_destroyed(*video_mesh.0).unwrap();
+    assert!(!video_mesh.is_alive(&scene));
+
+    let chunk = ris_scene::load(&scene, &serialized).unwrap().unwrap();
+    let loaded: GameObjectHandle = scene.static_chunks[chunk]
+        .game_objects
+        .iter()
+        .find(|x| x.borrow().is_alive)
+        .unwrap()
+        .borrow()
+        .handle
+        .into();
+
+    let components = loaded.components(&scene).unwrap();
+    let mesh_renderer = MeshRendererComponentHandle::from(
+        ris_data::ecs::handle::GenericHandle::from_dyn(*components[0]).unwrap(),
+    );
+    let loaded_video_mesh = mesh_renderer.video_mesh(&scene).unwrap().unwrap();
+
+    assert_ne!(loaded_video_mesh, video_mesh);
+    assert!(loaded_video_mesh.is_alive(&scene));
+    assert!(!loaded_video_mesh.is_uploaded(&scene).unwrap());
+    assert_eq!(loaded_video_mesh.asset_id(&scene).unwrap(), Some(asset_id));
+}
+
 fn fill_data(
     scene: &Scene,
     game_object: GameObjectHandle,
@@ -304,7 +543,7 @@ fn fill_data(
     let is_active = rng.next_bool();
     let position = rng.next_pos_3();
     let rotation = rng.next_rot();
-    let scale = rng.next_f32();
+    let scale = Vec3(rng.next_f32(), rng.next_f32(), rng.next_f32());
 
     game_object.set_name(scene, name.as_ref()).unwrap();
     game_object.set_active(scene, is_active).unwrap();
@@ -345,3 +584,371 @@ fn get_inner(scene: &Scene, handle: DynComponentHandle) -> RisResult<TestInner>
 
     Ok(inner)
 }
+
+#[test]
+fn should_load_into_chosen_chunk() {
+    let registry = Registry::new(Vec::new()).unwrap();
+    let scene_create_info = SceneCreateInfo {
+        static_chunks: 3,
+        game_objects_per_static_chunk: 4,
+        registry: Some(registry),
+        ..Default::default()
+    };
+    let scene = Scene::new(scene_create_info).unwrap();
+    assert_eq!(scene.reserve_chunk().unwrap(), 0);
+
+    let g = GameObjectHandle::new_static(&scene, 0).unwrap();
+    g.set_name(&scene, "chosen").unwrap();
+    let serialized = ris_scene::serialize(&scene, Some(0)).unwrap();
+
+    ris_scene::load_into_chunk(&scene, &serialized, 2).unwrap();
+    assert!(scene.is_chunk_reserved(2));
+    assert!(!scene.is_chunk_reserved(1));
+
+    let loaded = scene
+        .game_objects(GameObjectKind::Static { chunk: 2 })
+        .map(|x| x.name(&scene).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(loaded, vec!["chosen"]);
+
+    assert!(ris_scene::load_into_chunk(&scene, &serialized, 2).is_err());
+    assert!(ris_scene::load_into_chunk(&scene, &serialized, 3).is_err());
+}
+
+#[test]
+fn should_clear_chunk_when_load_fails() {
+    let registry = Registry::new(vec![Registry::script::<CorruptScript>().unwrap()]).unwrap();
+    let scene_create_info = SceneCreateInfo {
+        static_chunks: 2,
+        game_objects_per_static_chunk: 4,
+        registry: Some(registry),
+        ..Default::default()
+    };
+    let scene = Scene::new(scene_create_info).unwrap();
+    assert_eq!(scene.reserve_chunk().unwrap(), 0);
+
+    // the script fails to deserialize, after the first game object was spawned
+    let g0 = GameObjectHandle::new_static(&scene, 0).unwrap();
+    g0.set_name(&scene, "spawned").unwrap();
+    let g1 = GameObjectHandle::new_static(&scene, 0).unwrap();
+    g1.add_script::<CorruptScript>(&scene).unwrap();
+    let serialized = ris_scene::serialize(&scene, Some(0)).unwrap();
+
+    assert!(ris_scene::load_into_chunk(&scene, &serialized, 1).is_err());
+    assert!(!scene.is_chunk_reserved(1));
+    let loaded = scene
+        .game_objects(GameObjectKind::Static { chunk: 1 })
+        .count();
+    assert_eq!(loaded, 0);
+}
+
+#[test]
+fn should_dump_as_text() {
+    let registry = Registry::new(Vec::new()).unwrap();
+    let scene_create_info = SceneCreateInfo {
+        static_chunks: 1,
+        game_objects_per_static_chunk: 4,
+        video_meshes: 1,
+        registry: Some(registry),
+        ..Default::default()
+    };
+    let scene = Scene::new(scene_create_info).unwrap();
+    assert_eq!(scene.reserve_chunk().unwrap(), 0);
+
+    let video_mesh = VideoMeshHandle::new(&scene).unwrap();
+    let asset_id = AssetId::Path("meshes/cube.ris_mesh".to_string());
+    video_mesh.set_asset_id(&scene, Some(asset_id)).unwrap();
+
+    let parent = GameObjectHandle::new_static(&scene, 0).unwrap();
+    parent.set_name(&scene, "parent").unwrap();
+    parent
+        .set_local_position(&scene, Vec3(1.0, 2.0, 3.0))
+        .unwrap();
+    let m: MeshRendererComponentHandle = parent.add_component(&scene).unwrap().into();
+    m.set_video_mesh(&scene, video_mesh).unwrap();
+
+    let child = GameObjectHandle::new_static(&scene, 0).unwrap();
+    child.set_name(&scene, "child").unwrap();
+    child.set_active(&scene, false).unwrap();
+    child.set_parent(&scene, Some(parent), 0, false).unwrap();
+
+    let serialized = ris_scene::serialize(&scene, Some(0)).unwrap();
+    let dump = ris_scene::dump(&serialized, &scene.registry).unwrap();
+
+    let expected = [
+        "version: 2",
+        "references: 1",
+        "    [0] path \"meshes/cube.ris_mesh\"",
+        "game objects: 2",
+        "    \"parent\"",
+        "      active: true",
+        "      position: (1, 2, 3)",
+        "      rotation: (0, 0, 0, 1)",
+        "      scale: (1, 1, 1)",
+        "      component: MeshRendererComponent",
+        "        \"child\"",
+        "          active: false",
+        "          position: (0, 0, 0)",
+        "          rotation: (0, 0, 0, 1)",
+        "          scale: (1, 1, 1)",
+    ];
+
+    let lines = dump.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), expected.len(), "{}", dump);
+    for (line, expected) in lines.iter().zip(expected.iter()) {
+        assert!(line.starts_with(expected), "{}", dump);
+    }
+}
+
+#[test]
+fn should_not_dump_invalid_scene() {
+    assert!(ris_scene::dump(&[0; 64], &Registry::new(Vec::new()).unwrap()).is_err());
+}
+
+#[test]
+fn should_load_version_0_with_uniform_scale() {
+    // a single game object, written in version 0, where scale was a single f32
+    let mut stream = std::io::Cursor::new(Vec::new());
+    let f = &mut stream;
+    ris_io::write_uint(f, 1).unwrap();
+    ris_io::write_string(f, "old").unwrap();
+    ris_io::write_bool(f, true).unwrap();
+    ris_io::write_vec3(f, Vec3(1.0, 2.0, 3.0)).unwrap();
+    ris_io::write_quat(f, ris_math::quaternion::Quat::identity()).unwrap();
+    ris_io::write_f32(f, 2.5).unwrap();
+    ris_io::write_uint(f, 0).unwrap();
+    ris_io::write_uint(f, 0).unwrap();
+    let content = stream.into_inner();
+
+    let mut magic = ris_scene::MAGIC;
+    magic[15] = 0;
+    let header = ris_asset::assets::ris_header::RisHeader::new(magic, Vec::new());
+    let mut bytes = header.serialize().unwrap();
+    bytes.extend(miniz_oxide::deflate::compress_to_vec(&content, 6));
+
+    let scene_create_info = SceneCreateInfo {
+        static_chunks: 1,
+        game_objects_per_static_chunk: 4,
+        registry: Some(Registry::new(Vec::new()).unwrap()),
+        ..Default::default()
+    };
+    let scene = Scene::new(scene_create_info).unwrap();
+    let chunk = ris_scene::load(&scene, &bytes).unwrap().unwrap();
+
+    let kind = GameObjectKind::Static { chunk };
+    let game_objects = scene.game_objects(kind).collect::<Vec<_>>();
+    assert_eq!(game_objects.len(), 1);
+    assert_eq!(game_objects[0].name(&scene).unwrap(), "old");
+    assert_eq!(
+        game_objects[0].local_scale(&scene).unwrap(),
+        Vec3(2.5, 2.5, 2.5),
+    );
+
+    let dump = ris_scene::dump(&bytes, &scene.registry).unwrap();
+    assert!(dump.starts_with("version: 0"), "{}", dump);
+}
+
+#[test]
+fn should_not_load_newer_version() {
+    let scene_create_info = SceneCreateInfo {
+        static_chunks: 1,
+        game_objects_per_static_chunk: 4,
+        registry: Some(Registry::new(Vec::new()).unwrap()),
+        ..Default::default()
+    };
+    let scene = Scene::new(scene_create_info).unwrap();
+    let mut bytes = ris_scene::serialize(&scene, None).unwrap();
+    bytes[15] += 1;
+
+    assert!(ris_scene::dump(&bytes, &scene.registry).is_err());
+}
+
+#[test]
+fn should_load_when_scripts_are_registered_in_a_different_order() {
+    let registry = Registry::new(vec![
+        Registry::script::<TestScript>().unwrap(),
+        Registry::script::<VersionedScript>().unwrap(),
+    ])
+    .unwrap();
+    let scene_create_info = SceneCreateInfo {
+        static_chunks: 1,
+        game_objects_per_static_chunk: 4,
+        registry: Some(registry),
+        ..Default::default()
+    };
+    let scene = Scene::new(scene_create_info).unwrap();
+    assert_eq!(scene.reserve_chunk().unwrap(), 0);
+
+    let g = GameObjectHandle::new_static(&scene, 0).unwrap();
+    let script = g.add_script::<VersionedScript>(&scene).unwrap();
+    script.script_mut(&scene).unwrap().value = 42;
+    let serialized = ris_scene::serialize(&scene, Some(0)).unwrap();
+
+    let registry = Registry::new(vec![
+        Registry::script::<VersionedScript>().unwrap(),
+        Registry::script::<TestScript>().unwrap(),
+    ])
+    .unwrap();
+    let scene_create_info = SceneCreateInfo {
+        static_chunks: 1,
+        game_objects_per_static_chunk: 4,
+        registry: Some(registry),
+        ..Default::default()
+    };
+    let scene = Scene::new(scene_create_info).unwrap();
+    let chunk = ris_scene::load(&scene, &serialized).unwrap().unwrap();
+
+    let kind = GameObjectKind::Static { chunk };
+    let game_objects = scene.game_objects(kind).collect::<Vec<_>>();
+    assert_eq!(game_objects.len(), 1);
+    let script = game_objects[0]
+        .get_script::<VersionedScript>(&scene, GetFrom::This)
+        .unwrap()
+        .unwrap();
+    let script_ref = script.script(&scene).unwrap();
+    assert_eq!(script_ref.value, 42);
+    assert_eq!(script_ref.deserialized_version, Some(3));
+}
+
+#[test]
+fn should_skip_unregistered_script() {
+    let registry = Registry::new(vec![Registry::script::<VersionedScript>().unwrap()]).unwrap();
+    let scene_create_info = SceneCreateInfo {
+        static_chunks: 1,
+        game_objects_per_static_chunk: 4,
+        registry: Some(registry),
+        ..Default::default()
+    };
+    let scene = Scene::new(scene_create_info).unwrap();
+    assert_eq!(scene.reserve_chunk().unwrap(), 0);
+
+    let g = GameObjectHandle::new_static(&scene, 0).unwrap();
+    g.add_script::<VersionedScript>(&scene).unwrap();
+    let serialized = ris_scene::serialize(&scene, Some(0)).unwrap();
+
+    let registry = Registry::new(vec![Registry::script::<TestScript>().unwrap()]).unwrap();
+    let scene_create_info = SceneCreateInfo {
+        static_chunks: 1,
+        game_objects_per_static_chunk: 4,
+        registry: Some(registry),
+        ..Default::default()
+    };
+    let scene = Scene::new(scene_create_info).unwrap();
+    let chunk = ris_scene::load(&scene, &serialized).unwrap().unwrap();
+
+    let kind = GameObjectKind::Static { chunk };
+    let game_objects = scene.game_objects(kind).collect::<Vec<_>>();
+    assert_eq!(game_objects.len(), 1);
+    assert!(game_objects[0].components(&scene).unwrap().is_empty());
+}
+
+#[test]
+fn should_skip_unregistered_component() {
+    let mut registry = Registry::new(vec![Registry::script::<VersionedScript>().unwrap()]).unwrap();
+    registry
+        .register_component(Registry::component::<UnknownComponent>().unwrap())
+        .unwrap();
+    let scene_create_info = SceneCreateInfo {
+        static_chunks: 1,
+        game_objects_per_static_chunk: 4,
+        registry: Some(registry),
+        ..Default::default()
+    };
+    let scene = Scene::new(scene_create_info).unwrap();
+    assert_eq!(scene.reserve_chunk().unwrap(), 0);
+
+    let g = GameObjectHandle::new_static(&scene, 0).unwrap();
+    g.set_name(&scene, "mixed").unwrap();
+    let unknown: UnknownComponentHandle = g.add_component(&scene).unwrap().into();
+    scene.deref(unknown.0).unwrap().borrow_mut().payload = vec![1, 2, 3, 4, 5];
+    let script = g.add_script::<VersionedScript>(&scene).unwrap();
+    script.script_mut(&scene).unwrap().value = 42;
+    let serialized = ris_scene::serialize(&scene, Some(0)).unwrap();
+
+    let registry = Registry::new(vec![Registry::script::<VersionedScript>().unwrap()]).unwrap();
+    let scene_create_info = SceneCreateInfo {
+        static_chunks: 1,
+        game_objects_per_static_chunk: 4,
+        registry: Some(registry),
+        ..Default::default()
+    };
+    let scene = Scene::new(scene_create_info).unwrap();
+    let chunk = ris_scene::load(&scene, &serialized).unwrap().unwrap();
+
+    let kind = GameObjectKind::Static { chunk };
+    let game_objects = scene.game_objects(kind).collect::<Vec<_>>();
+    assert_eq!(game_objects.len(), 1);
+    assert_eq!(game_objects[0].name(&scene).unwrap(), "mixed");
+    assert_eq!(game_objects[0].components(&scene).unwrap().len(), 1);
+
+    let script = game_objects[0]
+        .get_script::<VersionedScript>(&scene, GetFrom::This)
+        .unwrap()
+        .unwrap();
+    assert_eq!(script.script(&scene).unwrap().value, 42);
+}
+
+#[test]
+fn should_load_version_1_with_component_positions() {
+    // a single game object with a script, written in version 1, where components and scripts were
+    // identified by their position in the registry
+    let mut stream = std::io::Cursor::new(Vec::new());
+    let f = &mut stream;
+    ris_io::write_uint(f, 1).unwrap();
+    ris_io::write_string(f, "old").unwrap();
+    ris_io::write_bool(f, true).unwrap();
+    ris_io::write_vec3(f, Vec3::default()).unwrap();
+    ris_io::write_quat(f, ris_math::quaternion::Quat::identity()).unwrap();
+    ris_io::write_vec3(f, Vec3::init(1.0)).unwrap();
+    ris_io::write_uint(f, 1).unwrap();
+    let ptr_addr = ris_io::write_fat_ptr(f, ris_io::FatPtr::null())
+        .unwrap()
+        .addr;
+    let begin = ris_io::seek(f, std::io::SeekFrom::Current(0)).unwrap();
+    ris_io::write_uint(f, 0).unwrap(); // DynScriptComponent
+    ris_io::write_uint(f, 1).unwrap(); // VersionedScript
+    ris_io::write_uint(f, 7).unwrap();
+    let end = ris_io::seek(f, std::io::SeekFrom::Current(0)).unwrap();
+    ris_io::seek(f, std::io::SeekFrom::Start(ptr_addr)).unwrap();
+    ris_io::write_fat_ptr(f, ris_io::FatPtr::begin_end(begin, end).unwrap()).unwrap();
+    ris_io::seek(f, std::io::SeekFrom::Start(end)).unwrap();
+    ris_io::write_uint(f, 0).unwrap();
+    let content = stream.into_inner();
+
+    let mut magic = ris_scene::MAGIC;
+    magic[15] = 1;
+    let header = ris_asset::assets::ris_header::RisHeader::new(magic, Vec::new());
+    let mut bytes = header.serialize().unwrap();
+    bytes.extend(miniz_oxide::deflate::compress_to_vec(&content, 6));
+
+    let registry = Registry::new(vec![
+        Registry::script::<TestScript>().unwrap(),
+        Registry::script::<VersionedScript>().unwrap(),
+    ])
+    .unwrap();
+    let scene_create_info = SceneCreateInfo {
+        static_chunks: 1,
+        game_objects_per_static_chunk: 4,
+        registry: Some(registry),
+        ..Default::default()
+    };
+    let scene = Scene::new(scene_create_info).unwrap();
+
+    let dump = ris_scene::dump(&bytes, &scene.registry).unwrap();
+    assert!(
+        dump.contains("component: DynScriptComponent VersionedScript"),
+        "{}",
+        dump
+    );
+
+    let chunk = ris_scene::load(&scene, &bytes).unwrap().unwrap();
+    let kind = GameObjectKind::Static { chunk };
+    let game_objects = scene.game_objects(kind).collect::<Vec<_>>();
+    let script = game_objects[0]
+        .get_script::<VersionedScript>(&scene, GetFrom::This)
+        .unwrap()
+        .unwrap();
+    let script_ref = script.script(&scene).unwrap();
+    assert_eq!(script_ref.value, 7);
+    assert_eq!(script_ref.deserialized_version, Some(0));
+}
@@ -0,0 +1,311 @@
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use ris_asset::asset_loader::LoadError;
+use ris_asset::assets::ris_scene;
+use ris_core::chunk_streamer;
+use ris_core::chunk_streamer::ChunkState;
+use ris_core::chunk_streamer::ChunkStreamer;
+use ris_core::chunk_streamer::StreamedChunk;
+use ris_data::asset_id::AssetId;
+use ris_data::ecs::decl::GameObjectHandle;
+use ris_data::ecs::id::GameObjectKind;
+use ris_data::ecs::registry::Registry;
+use ris_data::ecs::scene::Scene;
+use ris_data::ecs::scene::SceneCreateInfo;
+use ris_data::ecs::script_prelude::*;
+use ris_jobs::job_future::SettableJobFuture;
+use ris_math::vector::Vec3;
+
+type Pending = Arc<Mutex<Vec<(AssetId, SettableJobFuture<Result<Vec<u8>, LoadError>>)>>>;
+
+#[derive(Debug, Default)]
+struct CorruptScript;
+
+impl Script for CorruptScript {
+    fn start(&mut self, _data: ScriptStartEndData) -> RisResult<()> {
+        Ok(())
+    }
+
+    fn update(&mut self, _data: ScriptUpdateData) -> RisResult<()> {
+        Ok(())
+    }
+
+    fn end(&mut self, _data: ScriptStartEndData) -> RisResult<()> {
+        Ok(())
+    }
+
+    fn serialize(&mut self, _stream: &mut SceneWriter) -> RisResult<()> {
+        Ok(())
+    }
+
+    fn deserialize(&mut self, _stream: &mut SceneReader, _version: u32) -> RisResult<()> {
+        ris_error::new_result!("corrupt data")
+    }
+
+    fn inspect(&mut self, _data: ScriptInspectData) -> RisResult<()> {
+        Ok(())
+    }
+}
+
+fn create_scene(static_chunks: usize) -> Scene {
+    let info = SceneCreateInfo {
+        dynamic_game_objects: 0,
+        static_chunks,
+        game_objects_per_static_chunk: 4,
+        video_meshes: 0,
+        registry: Some(Registry::new(vec![Registry::script::<CorruptScript>().unwrap()]).unwrap()),
+        ..Default::default()
+    };
+    Scene::new(info).unwrap()
+}
+
+fn serialized_chunk(name: &str) -> Vec<u8> {
+    let scene = create_scene(1);
+    let chunk = scene.reserve_chunk().unwrap();
+    let game_object = GameObjectHandle::new_static(&scene, chunk).unwrap();
+    game_object.set_name(&scene, name).unwrap();
+    ris_scene::serialize(&scene, Some(chunk)).unwrap()
+}
+
+/// the second game object holds a script that fails to deserialize, thus loading fails after the
+/// first game object was spawned
+fn corrupt_chunk() -> Vec<u8> {
+    let scene = create_scene(1);
+    let chunk = scene.reserve_chunk().unwrap();
+    let g0 = GameObjectHandle::new_static(&scene, chunk).unwrap();
+    g0.set_name(&scene, "spawned").unwrap();
+    let g1 = GameObjectHandle::new_static(&scene, chunk).unwrap();
+    g1.add_script::<CorruptScript>(&scene).unwrap();
+    ris_scene::serialize(&scene, Some(chunk)).unwrap()
+}
+
+fn create_streamer() -> (ChunkStreamer, Pending) {
+    let pending = Pending::default();
+    let pending_copy = pending.clone();
+    let streamer = ChunkStreamer::new(Box::new(move |id| {
+        let (settable, future) = SettableJobFuture::new();
+        pending_copy.lock().unwrap().push((id, settable));
+        future
+    }));
+
+    (streamer, pending)
+}
+
+fn complete(pending: &Pending, bytes: &[u8]) {
+    for (_, settable) in pending.lock().unwrap().drain(..) {
+        settable.set(Ok(bytes.to_vec()));
+    }
+}
+
+fn chunk_at(path: &str, x: f32) -> StreamedChunk {
+    StreamedChunk {
+        asset_id: AssetId::Path(path.to_string()),
+        min: Vec3(x, 0.0, 0.0),
+        max: Vec3(x + 10.0, 10.0, 10.0),
+    }
+}
+
+fn loaded_names(scene: &Scene, chunk: usize) -> Vec<String> {
+    scene
+        .game_objects(GameObjectKind::Static { chunk })
+        .map(|x| x.name(scene).unwrap())
+        .collect()
+}
+
+#[test]
+fn should_compute_distance_to_bounds() {
+    let min = Vec3(0.0, 0.0, 0.0);
+    let max = Vec3(1.0, 1.0, 1.0);
+
+    assert_eq!(
+        chunk_streamer::distance_to_bounds(Vec3(0.5, 0.5, 0.5), min, max),
+        0.0
+    );
+    assert_eq!(
+        chunk_streamer::distance_to_bounds(Vec3(3.0, 0.5, 0.5), min, max),
+        2.0
+    );
+    assert_eq!(
+        chunk_streamer::distance_to_bounds(Vec3(-3.0, 1.0, 5.0), min, max),
+        5.0
+    );
+}
+
+#[test]
+fn should_not_add_invalid_bounds() {
+    let (mut streamer, _) = create_streamer();
+    let mut chunk = chunk_at("a.ris_scene", 0.0);
+    chunk.max = Vec3(-1.0, 10.0, 10.0);
+
+    assert!(streamer.add(chunk).is_err());
+}
+
+#[test]
+fn should_not_add_chunk_twice() {
+    let (mut streamer, _) = create_streamer();
+    streamer.add(chunk_at("a.ris_scene", 0.0)).unwrap();
+
+    assert!(streamer.add(chunk_at("a.ris_scene", 50.0)).is_err());
+}
+
+#[test]
+fn should_load_chunk_when_camera_approaches() {
+    let scene = create_scene(2);
+    let bytes = serialized_chunk("streamed");
+    let (mut streamer, pending) = create_streamer();
+    let id = AssetId::Path("a.ris_scene".to_string());
+    streamer.add(chunk_at("a.ris_scene", 200.0)).unwrap();
+
+    streamer.update(&scene, Vec3(0.0, 0.0, 0.0)).unwrap();
+    assert_eq!(streamer.state(&id), Some(ChunkState::Unloaded));
+    assert!(pending.lock().unwrap().is_empty());
+
+    streamer.update(&scene, Vec3(150.0, 0.0, 0.0)).unwrap();
+    assert_eq!(streamer.state(&id), Some(ChunkState::Loading));
+    assert_eq!(pending.lock().unwrap().len(), 1);
+
+    // still loading
+    streamer.update(&scene, Vec3(150.0, 0.0, 0.0)).unwrap();
+    assert_eq!(streamer.state(&id), Some(ChunkState::Loading));
+
+    complete(&pending, &bytes);
+    streamer.update(&scene, Vec3(150.0, 0.0, 0.0)).unwrap();
+    assert_eq!(streamer.state(&id), Some(ChunkState::Loaded { chunk: 0 }));
+    assert_eq!(loaded_names(&scene, 0), vec!["streamed"]);
+}
+
+#[test]
+fn should_unload_with_hysteresis() {
+    let scene = create_scene(2);
+    let bytes = serialized_chunk("streamed");
+    let (mut streamer, pending) = create_streamer();
+    let id = AssetId::Path("a.ris_scene".to_string());
+    streamer.add(chunk_at("a.ris_scene", 200.0)).unwrap();
+
+    streamer.update(&scene, Vec3(150.0, 0.0, 0.0)).unwrap();
+    complete(&pending, &bytes);
+    streamer.update(&scene, Vec3(150.0, 0.0, 0.0)).unwrap();
+    assert_eq!(streamer.state(&id), Some(ChunkState::Loaded { chunk: 0 }));
+
+    // beyond load distance, but within unload distance
+    streamer.update(&scene, Vec3(90.0, 0.0, 0.0)).unwrap();
+    assert_eq!(streamer.state(&id), Some(ChunkState::Loaded { chunk: 0 }));
+
+    // beyond unload distance
+    streamer.update(&scene, Vec3(70.0, 0.0, 0.0)).unwrap();
+    assert_eq!(streamer.state(&id), Some(ChunkState::Unloaded));
+    assert!(loaded_names(&scene, 0).is_empty());
+    assert_eq!(scene.reserve_chunk(), Some(0));
+}
+
+#[test]
+fn should_respect_loads_per_frame() {
+    let scene = create_scene(4);
+    let bytes = serialized_chunk("streamed");
+    let (mut streamer, pending) = create_streamer();
+    streamer.loads_per_frame = 2;
+    streamer.add(chunk_at("a.ris_scene", 0.0)).unwrap();
+    streamer.add(chunk_at("b.ris_scene", 20.0)).unwrap();
+    streamer.add(chunk_at("c.ris_scene", 40.0)).unwrap();
+
+    streamer.update(&scene, Vec3(0.0, 0.0, 0.0)).unwrap();
+    assert_eq!(pending.lock().unwrap().len(), 3);
+    complete(&pending, &bytes);
+
+    let count_loaded = |streamer: &ChunkStreamer| {
+        ["a.ris_scene", "b.ris_scene", "c.ris_scene"]
+            .iter()
+            .filter(|x| {
+                let id = AssetId::Path(x.to_string());
+                matches!(streamer.state(&id), Some(ChunkState::Loaded { .. }))
+            })
+            .count()
+    };
+
+    streamer.update(&scene, Vec3(0.0, 0.0, 0.0)).unwrap();
+    assert_eq!(count_loaded(&streamer), 2);
+
+    // nearest chunks are loaded first
+    let c = AssetId::Path("c.ris_scene".to_string());
+    assert_eq!(streamer.state(&c), Some(ChunkState::Loading));
+
+    streamer.update(&scene, Vec3(0.0, 0.0, 0.0)).unwrap();
+    assert_eq!(count_loaded(&streamer), 3);
+}
+
+#[test]
+fn should_discard_chunk_when_camera_left_while_loading() {
+    let scene = create_scene(2);
+    let bytes = serialized_chunk("streamed");
+    let (mut streamer, pending) = create_streamer();
+    let id = AssetId::Path("a.ris_scene".to_string());
+    streamer.add(chunk_at("a.ris_scene", 200.0)).unwrap();
+
+    streamer.update(&scene, Vec3(150.0, 0.0, 0.0)).unwrap();
+    complete(&pending, &bytes);
+    streamer.update(&scene, Vec3(0.0, 0.0, 0.0)).unwrap();
+
+    assert_eq!(streamer.state(&id), Some(ChunkState::Unloaded));
+    assert!(loaded_names(&scene, 0).is_empty());
+}
+
+#[test]
+fn should_not_retry_failed_chunk() {
+    let scene = create_scene(2);
+    let (mut streamer, pending) = create_streamer();
+    let id = AssetId::Path("a.ris_scene".to_string());
+    streamer.add(chunk_at("a.ris_scene", 0.0)).unwrap();
+
+    streamer.update(&scene, Vec3(0.0, 0.0, 0.0)).unwrap();
+    for (_, settable) in pending.lock().unwrap().drain(..) {
+        settable.set(Err(LoadError::LoadFailed));
+    }
+    streamer.update(&scene, Vec3(0.0, 0.0, 0.0)).unwrap();
+    assert_eq!(streamer.state(&id), Some(ChunkState::Failed));
+
+    streamer.update(&scene, Vec3(0.0, 0.0, 0.0)).unwrap();
+    assert!(pending.lock().unwrap().is_empty());
+}
+
+#[test]
+fn should_wait_for_free_chunk_without_loading_again() {
+    let scene = create_scene(1);
+    let bytes = serialized_chunk("streamed");
+    let (mut streamer, pending) = create_streamer();
+    let id = AssetId::Path("a.ris_scene".to_string());
+    streamer.add(chunk_at("a.ris_scene", 0.0)).unwrap();
+    let occupied = scene.reserve_chunk().unwrap();
+
+    streamer.update(&scene, Vec3(0.0, 0.0, 0.0)).unwrap();
+    complete(&pending, &bytes);
+    streamer.update(&scene, Vec3(0.0, 0.0, 0.0)).unwrap();
+    assert_eq!(streamer.state(&id), Some(ChunkState::WaitingForChunk));
+
+    streamer.update(&scene, Vec3(0.0, 0.0, 0.0)).unwrap();
+    assert_eq!(streamer.state(&id), Some(ChunkState::WaitingForChunk));
+    assert!(pending.lock().unwrap().is_empty());
+
+    scene.clear_chunk(occupied);
+    streamer.update(&scene, Vec3(0.0, 0.0, 0.0)).unwrap();
+    assert_eq!(streamer.state(&id), Some(ChunkState::Loaded { chunk: 0 }));
+    assert_eq!(loaded_names(&scene, 0), vec!["streamed"]);
+    assert!(pending.lock().unwrap().is_empty());
+}
+
+#[test]
+fn should_clear_chunk_that_failed_to_deserialize() {
+    let scene = create_scene(1);
+    let bytes = corrupt_chunk();
+    let (mut streamer, pending) = create_streamer();
+    let id = AssetId::Path("a.ris_scene".to_string());
+    streamer.add(chunk_at("a.ris_scene", 0.0)).unwrap();
+
+    streamer.update(&scene, Vec3(0.0, 0.0, 0.0)).unwrap();
+    complete(&pending, &bytes);
+    streamer.update(&scene, Vec3(0.0, 0.0, 0.0)).unwrap();
+
+    assert_eq!(streamer.state(&id), Some(ChunkState::Failed));
+    assert!(loaded_names(&scene, 0).is_empty());
+    assert!(!scene.is_chunk_reserved(0));
+}
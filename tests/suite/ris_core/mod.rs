@@ -0,0 +1,2 @@
+pub mod chunk_streamer;
+pub mod script_scheduler;
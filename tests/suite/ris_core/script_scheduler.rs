@@ -0,0 +1,421 @@
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use ris_core::script_scheduler::ScriptPhase;
+use ris_core::script_scheduler::ScriptSchedule;
+use ris_core::script_scheduler::SCRIPTS_PER_JOB;
+use ris_data::ecs::components::script::DynScriptComponent;
+use ris_data::ecs::decl::GameObjectHandle;
+use ris_data::ecs::registry::Registry;
+use ris_data::ecs::scene::Scene;
+use ris_data::ecs::scene::SceneCreateInfo;
+use ris_data::ecs::script_prelude::*;
+use ris_data::gameloop::frame::Frame;
+use ris_data::god_state::GodState;
+use ris_data::settings::Settings;
+use ris_jobs::job_system;
+
+type Calls = Arc<Mutex<Vec<(usize, &'static str)>>>;
+
+fn scene_create_info() -> SceneCreateInfo {
+    let mut info = SceneCreateInfo::empty();
+    info.dynamic_game_objects = 8;
+    info.set_component_capacity::<DynScriptComponent>(8);
+    info.registry = Some(
+        Registry::new(vec![
+            Registry::script::<MainThreadScript>().unwrap(),
+            Registry::script::<ParallelScript>().unwrap(),
+            Registry::script::<HierarchyReaderScript>().unwrap(),
+        ])
+        .unwrap(),
+    );
+    info
+}
+
+fn create_scene() -> Scene {
+    Scene::new(scene_create_info()).unwrap()
+}
+
+#[derive(Debug, Default)]
+struct MainThreadScript {
+    id: usize,
+    calls: Calls,
+}
+
+#[derive(Debug, Default)]
+struct ParallelScript {
+    reads: Vec<GameObjectHandle>,
+    writes: Vec<GameObjectHandle>,
+    id: usize,
+    calls: Calls,
+}
+
+#[derive(Debug, Default)]
+struct HierarchyReaderScript {
+    parent: GameObjectHandle,
+}
+
+impl Script for MainThreadScript {
+    fn start(&mut self, _data: ScriptStartEndData) -> RisResult<()> {
+        Ok(())
+    }
+
+    fn fixed_update(&mut self, _data: ScriptFixedUpdateData) -> RisResult<()> {
+        self.calls.lock().unwrap().push((self.id, "fixed_update"));
+        Ok(())
+    }
+
+    fn update(&mut self, _data: ScriptUpdateData) -> RisResult<()> {
+        self.calls.lock().unwrap().push((self.id, "update"));
+        Ok(())
+    }
+
+    fn late_update(&mut self, _data: ScriptUpdateData) -> RisResult<()> {
+        self.calls.lock().unwrap().push((self.id, "late_update"));
+        Ok(())
+    }
+
+    fn end(&mut self, _data: ScriptStartEndData) -> RisResult<()> {
+        Ok(())
+    }
+
+    fn serialize(&mut self, _stream: &mut SceneWriter) -> RisResult<()> {
+        ris_error::new_result!("not implemented")
+    }
+
+    fn deserialize(&mut self, _stream: &mut SceneReader, _version: u32) -> RisResult<()> {
+        ris_error::new_result!("not implemented")
+    }
+
+    fn inspect(&mut self, _data: ScriptInspectData) -> RisResult<()> {
+        ris_error::new_result!("not implemented")
+    }
+}
+
+impl Script for ParallelScript {
+    fn start(&mut self, _data: ScriptStartEndData) -> RisResult<()> {
+        Ok(())
+    }
+
+    fn fixed_update(&mut self, _data: ScriptFixedUpdateData) -> RisResult<()> {
+        self.calls.lock().unwrap().push((self.id, "fixed_update"));
+        Ok(())
+    }
+
+    fn update(&mut self, _data: ScriptUpdateData) -> RisResult<()> {
+        self.calls.lock().unwrap().push((self.id, "update"));
+        Ok(())
+    }
+
+    fn late_update(&mut self, _data: ScriptUpdateData) -> RisResult<()> {
+        self.calls.lock().unwrap().push((self.id, "late_update"));
+        Ok(())
+    }
+
+    fn end(&mut self, _data: ScriptStartEndData) -> RisResult<()> {
+        Ok(())
+    }
+
+    fn serialize(&mut self, _stream: &mut SceneWriter) -> RisResult<()> {
+        ris_error::new_result!("not implemented")
+    }
+
+    fn deserialize(&mut self, _stream: &mut SceneReader, _version: u32) -> RisResult<()> {
+        ris_error::new_result!("not implemented")
+    }
+
+    fn inspect(&mut self, _data: ScriptInspectData) -> RisResult<()> {
+        ris_error::new_result!("not implemented")
+    }
+
+    fn access(&self, _data: ScriptStartEndData) -> ScriptAccess {
+        ScriptAccess::Parallel {
+            reads: self.reads.clone(),
+            writes: self.writes.clone(),
+        }
+    }
+}
+
+impl Script for HierarchyReaderScript {
+    fn start(&mut self, _data: ScriptStartEndData) -> RisResult<()> {
+        Ok(())
+    }
+
+    fn update(&mut self, data: ScriptUpdateData) -> RisResult<()> {
+        let scene = &data.state.scene;
+        for _ in 0..10 {
+            data.game_object.world_position(scene)?;
+            data.game_object.sibling_index(scene)?;
+            self.parent.children(scene)?;
+        }
+
+        Ok(())
+    }
+
+    fn end(&mut self, _data: ScriptStartEndData) -> RisResult<()> {
+        Ok(())
+    }
+
+    fn serialize(&mut self, _stream: &mut SceneWriter) -> RisResult<()> {
+        ris_error::new_result!("not implemented")
+    }
+
+    fn deserialize(&mut self, _stream: &mut SceneReader, _version: u32) -> RisResult<()> {
+        ris_error::new_result!("not implemented")
+    }
+
+    fn inspect(&mut self, _data: ScriptInspectData) -> RisResult<()> {
+        ris_error::new_result!("not implemented")
+    }
+
+    fn access(&self, _data: ScriptStartEndData) -> ScriptAccess {
+        ScriptAccess::Parallel {
+            reads: vec![self.parent],
+            writes: Vec::new(),
+        }
+    }
+}
+
+fn add_parallel_script(
+    scene: &Scene,
+    game_object: GameObjectHandle,
+    reads: Vec<GameObjectHandle>,
+    writes: Vec<GameObjectHandle>,
+) {
+    let script = game_object.add_script::<ParallelScript>(scene).unwrap();
+    let mut script = script.script_mut(scene).unwrap();
+    script.reads = reads;
+    script.writes = writes;
+}
+
+fn add_recording_parallel_script(
+    scene: &Scene,
+    game_object: GameObjectHandle,
+    writes: Vec<GameObjectHandle>,
+    id: usize,
+    calls: &Calls,
+) {
+    let script = game_object.add_script::<ParallelScript>(scene).unwrap();
+    let mut script = script.script_mut(scene).unwrap();
+    script.writes = writes;
+    script.id = id;
+    script.calls = calls.clone();
+}
+
+fn add_recording_main_thread_script(
+    scene: &Scene,
+    game_object: GameObjectHandle,
+    id: usize,
+    calls: &Calls,
+) {
+    let script = game_object.add_script::<MainThreadScript>(scene).unwrap();
+    let mut script = script.script_mut(scene).unwrap();
+    script.id = id;
+    script.calls = calls.clone();
+}
+
+fn create_recording_state(calls: &Calls) -> Arc<GodState> {
+    let state = GodState::new(Settings::default(), scene_create_info()).unwrap();
+    let scene = &state.scene;
+
+    let g0 = GameObjectHandle::new(scene).unwrap();
+    let g1 = GameObjectHandle::new(scene).unwrap();
+    let g2 = GameObjectHandle::new(scene).unwrap();
+
+    // ids 0 and 1 share a batch, id 2 writes g0 as well and thus goes into a second batch
+    add_recording_parallel_script(scene, g0, vec![g0], 0, calls);
+    add_recording_parallel_script(scene, g1, vec![g1], 1, calls);
+    add_recording_parallel_script(scene, g2, vec![g0, g2], 2, calls);
+    add_recording_main_thread_script(scene, g0, 3, calls);
+    add_recording_main_thread_script(scene, g2, 4, calls);
+
+    Arc::new(state)
+}
+
+#[test]
+fn should_run_every_script_once_per_phase() {
+    let job_system = job_system::init(100, 10, 4, false);
+
+    let calls = Calls::default();
+    let state = create_recording_state(&calls);
+    let schedule = ScriptSchedule::build(&state.scene).unwrap();
+    assert_eq!(schedule.batches.len(), 2);
+
+    let frame = Frame::new(0, Duration::ZERO, Duration::ZERO);
+    let phases = [
+        (
+            ScriptPhase::FixedUpdate {
+                timestep: Duration::from_millis(10),
+            },
+            "fixed_update",
+        ),
+        (ScriptPhase::Update, "update"),
+        (ScriptPhase::LateUpdate, "late_update"),
+    ];
+
+    for (phase, name) in phases {
+        calls.lock().unwrap().clear();
+        schedule.run(phase, frame, &state).unwrap();
+
+        let calls = calls.lock().unwrap();
+        assert!(calls.iter().all(|&(_, x)| x == name));
+
+        let mut ids = calls.iter().map(|&(id, _)| id).collect::<Vec<_>>();
+        ids.sort();
+        assert_eq!(ids, vec![0, 1, 2, 3, 4]);
+    }
+
+    drop(job_system);
+}
+
+#[test]
+fn should_run_main_thread_scripts_after_all_batches() {
+    let job_system = job_system::init(100, 10, 4, false);
+
+    let calls = Calls::default();
+    let state = create_recording_state(&calls);
+    let schedule = ScriptSchedule::build(&state.scene).unwrap();
+
+    let frame = Frame::new(0, Duration::ZERO, Duration::ZERO);
+    schedule.run(ScriptPhase::Update, frame, &state).unwrap();
+
+    let ids = calls
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|&(id, _)| id)
+        .collect::<Vec<_>>();
+    assert_eq!(ids.len(), 5);
+
+    // the first batch runs in parallel, thus its order is undefined
+    let mut first_batch = ids[0..2].to_vec();
+    first_batch.sort();
+    assert_eq!(first_batch, vec![0, 1]);
+    assert_eq!(ids[2..], [2, 3, 4]);
+
+    drop(job_system);
+}
+
+#[test]
+fn should_run_readers_of_a_shared_parent_in_parallel() {
+    let job_system = job_system::init(100, 10, 4, false);
+
+    // enough readers to spread them over multiple jobs
+    let reader_count = 4 * SCRIPTS_PER_JOB;
+    let mut info = scene_create_info();
+    info.dynamic_game_objects = reader_count + 2;
+    info.set_component_capacity::<DynScriptComponent>(reader_count);
+    let state = GodState::new(Settings::default(), info).unwrap();
+    let scene = &state.scene;
+
+    let parent = GameObjectHandle::new(scene).unwrap();
+    let destroyed = GameObjectHandle::new(scene).unwrap();
+    destroyed.set_parent(scene, Some(parent), 0, false).unwrap();
+    for _ in 0..reader_count {
+        let child = GameObjectHandle::new(scene).unwrap();
+        child.set_parent(scene, Some(parent), 0, false).unwrap();
+        let script = child.add_script::<HierarchyReaderScript>(scene).unwrap();
+        script.script_mut(scene).unwrap().parent = parent;
+    }
+
+    // the readers must not prune the destroyed child while the others read the parent
+    destroyed.destroy(scene);
+
+    let state = Arc::new(state);
+    let schedule = ScriptSchedule::build(&state.scene).unwrap();
+    assert_eq!(schedule.batches.len(), 1);
+    assert_eq!(schedule.batches[0].len(), reader_count);
+
+    let frame = Frame::new(0, Duration::ZERO, Duration::ZERO);
+    schedule.run(ScriptPhase::Update, frame, &state).unwrap();
+    assert_eq!(parent.children(&state.scene).unwrap().len(), reader_count);
+
+    drop(job_system);
+}
+
+#[test]
+fn should_write_the_game_object_of_the_script() {
+    let scene = create_scene();
+    let g = GameObjectHandle::new(&scene).unwrap();
+    add_parallel_script(&scene, g, vec![], vec![]);
+    add_parallel_script(&scene, g, vec![], vec![]);
+
+    let schedule = ScriptSchedule::build(&scene).unwrap();
+
+    assert_eq!(schedule.batches.len(), 2);
+}
+
+#[test]
+fn should_run_scripts_on_main_thread_by_default() {
+    let scene = create_scene();
+    let g = GameObjectHandle::new(&scene).unwrap();
+    g.add_script::<MainThreadScript>(&scene).unwrap();
+    g.add_script::<MainThreadScript>(&scene).unwrap();
+
+    let schedule = ScriptSchedule::build(&scene).unwrap();
+
+    assert!(schedule.batches.is_empty());
+    assert_eq!(schedule.main_thread.len(), 2);
+}
+
+#[test]
+fn should_batch_scripts_that_write_different_game_objects() {
+    let scene = create_scene();
+    let g0 = GameObjectHandle::new(&scene).unwrap();
+    let g1 = GameObjectHandle::new(&scene).unwrap();
+    let g2 = GameObjectHandle::new(&scene).unwrap();
+    add_parallel_script(&scene, g0, vec![g2], vec![g0]);
+    add_parallel_script(&scene, g1, vec![g2], vec![g1]);
+
+    let schedule = ScriptSchedule::build(&scene).unwrap();
+
+    assert_eq!(schedule.batches.len(), 1);
+    assert_eq!(schedule.batches[0].len(), 2);
+    assert!(schedule.main_thread.is_empty());
+}
+
+#[test]
+fn should_split_scripts_that_write_the_same_game_object() {
+    let scene = create_scene();
+    let g0 = GameObjectHandle::new(&scene).unwrap();
+    let g1 = GameObjectHandle::new(&scene).unwrap();
+    add_parallel_script(&scene, g0, vec![], vec![g0]);
+    add_parallel_script(&scene, g1, vec![], vec![g0, g1]);
+    add_parallel_script(&scene, g1, vec![], vec![g1]);
+
+    let schedule = ScriptSchedule::build(&scene).unwrap();
+
+    assert_eq!(schedule.batches.len(), 2);
+    assert_eq!(schedule.batches[0].len(), 2);
+    assert_eq!(schedule.batches[1].len(), 1);
+}
+
+#[test]
+fn should_split_scripts_that_read_what_another_writes() {
+    let scene = create_scene();
+    let g0 = GameObjectHandle::new(&scene).unwrap();
+    let g1 = GameObjectHandle::new(&scene).unwrap();
+    add_parallel_script(&scene, g0, vec![g1], vec![g0]);
+    add_parallel_script(&scene, g1, vec![], vec![g1]);
+
+    let schedule = ScriptSchedule::build(&scene).unwrap();
+
+    assert_eq!(schedule.batches.len(), 2);
+}
+
+#[test]
+fn should_separate_parallel_and_main_thread_scripts() {
+    let scene = create_scene();
+    let g0 = GameObjectHandle::new(&scene).unwrap();
+    let g1 = GameObjectHandle::new(&scene).unwrap();
+    add_parallel_script(&scene, g0, vec![], vec![g0]);
+    g0.add_script::<MainThreadScript>(&scene).unwrap();
+    add_parallel_script(&scene, g1, vec![], vec![g1]);
+
+    let schedule = ScriptSchedule::build(&scene).unwrap();
+
+    assert_eq!(schedule.batches.len(), 1);
+    assert_eq!(schedule.batches[0].len(), 2);
+    assert_eq!(schedule.main_thread.len(), 1);
+}
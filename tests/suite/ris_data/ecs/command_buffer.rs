@@ -0,0 +1,127 @@
+use ris_data::ecs::command_buffer::CommandBuffer;
+use ris_data::ecs::components::mesh_renderer::MeshRendererComponent;
+use ris_data::ecs::decl::GameObjectHandle;
+use ris_data::ecs::decl::MeshRendererComponentHandle;
+use ris_data::ecs::game_object::GetFrom;
+use ris_data::ecs::registry::Registry;
+use ris_data::ecs::scene::Scene;
+use ris_data::ecs::scene::SceneCreateInfo;
+
+fn create_scene() -> Scene {
+    let mut info = SceneCreateInfo::empty();
+    info.dynamic_game_objects = 8;
+    info.set_component_capacity::<MeshRendererComponent>(8);
+    info.registry = Some(Registry::new(Vec::new()).unwrap());
+    Scene::new(info).unwrap()
+}
+
+#[test]
+fn should_not_apply_commands_before_playback() {
+    let scene = create_scene();
+    let commands = CommandBuffer::default();
+    let g = GameObjectHandle::new(&scene).unwrap();
+
+    let spawned = commands.spawn();
+    commands.destroy(g);
+
+    assert_eq!(commands.len(), 2);
+    assert!(spawned.resolve().is_none());
+    assert!(g.is_alive(&scene));
+}
+
+#[test]
+fn should_resolve_spawned_game_objects_after_playback() {
+    let scene = create_scene();
+    let commands = CommandBuffer::default();
+
+    let spawned = commands.spawn();
+    assert_eq!(commands.playback(&scene), 0);
+
+    let g = spawned.resolve().unwrap();
+    assert!(g.is_alive(&scene));
+    assert!(commands.is_empty());
+}
+
+#[test]
+fn should_apply_commands_to_pending_game_objects() {
+    let scene = create_scene();
+    let commands = CommandBuffer::default();
+    let parent = GameObjectHandle::new(&scene).unwrap();
+
+    let child = commands.spawn();
+    let mesh = commands.add_component::<MeshRendererComponent>(&child);
+    commands.set_parent(&child, Some(parent.into()), 0, false);
+    commands.set_active(&child, false);
+    assert_eq!(commands.playback(&scene), 0);
+
+    let child = child.resolve().unwrap();
+    let mesh: MeshRendererComponentHandle = mesh.resolve().unwrap().into();
+    assert_eq!(parent.children(&scene).unwrap(), vec![child]);
+    assert!(!child.is_active(&scene).unwrap());
+    assert_eq!(
+        child
+            .get_components::<MeshRendererComponent>(&scene, GetFrom::This)
+            .unwrap()
+            .len(),
+        1,
+    );
+    assert!(mesh.is_alive(&scene));
+}
+
+#[test]
+fn should_destroy_and_remove_components() {
+    let scene = create_scene();
+    let commands = CommandBuffer::default();
+    let g0 = GameObjectHandle::new(&scene).unwrap();
+    let g1 = GameObjectHandle::new(&scene).unwrap();
+    let mesh: MeshRendererComponentHandle = g1
+        .add_component::<MeshRendererComponent>(&scene)
+        .unwrap()
+        .into();
+
+    commands.destroy(g0);
+    commands.remove_component(mesh);
+    assert_eq!(commands.playback(&scene), 0);
+
+    assert!(!g0.is_alive(&scene));
+    assert!(g1.is_alive(&scene));
+    assert!(!mesh.is_alive(&scene));
+    assert!(g1.components(&scene).unwrap().is_empty());
+}
+
+#[test]
+fn should_apply_remaining_commands_when_one_fails() {
+    let scene = create_scene();
+    let commands = CommandBuffer::default();
+    let other_commands = CommandBuffer::default();
+
+    let never_spawned = other_commands.spawn();
+    commands.set_active(&never_spawned, false);
+    let spawned = commands.spawn();
+    let failed = commands.playback(&scene);
+
+    assert_eq!(failed, 1);
+    assert!(spawned.resolve().is_some());
+}
+
+#[test]
+fn should_skip_commands_on_game_objects_destroyed_during_playback() {
+    let scene = create_scene();
+    let commands = CommandBuffer::default();
+    let target = GameObjectHandle::new(&scene).unwrap();
+    let parent = GameObjectHandle::new(&scene).unwrap();
+
+    // recorded by different scripts, which do not know about each other
+    commands.destroy(target);
+    let mesh = commands.add_component::<MeshRendererComponent>(target);
+    commands.set_parent(target, Some(parent.into()), 0, false);
+    commands.set_active(target, false);
+    let spawned = commands.spawn();
+    let failed = commands.playback(&scene);
+
+    assert_eq!(failed, 3);
+    assert!(!target.is_alive(&scene));
+    assert!(mesh.resolve().is_none());
+    assert!(parent.children(&scene).unwrap().is_empty());
+    assert!(spawned.resolve().unwrap().is_alive(&scene));
+}
@@ -1,3 +1,4 @@
+use ris_data::ecs::components::mesh_renderer::MeshRendererComponent;
 use ris_data::ecs::decl::GameObjectHandle;
 use ris_data::ecs::decl::MeshRendererComponentHandle;
 use ris_data::ecs::game_object::GetFrom;
@@ -9,7 +10,7 @@ use ris_data::ecs::scene::SceneCreateInfo;
 fn scene_create_info() -> SceneCreateInfo {
     let mut info = SceneCreateInfo::empty();
     info.dynamic_game_objects = 8;
-    info.mesh_renderer_components = 8;
+    info.set_component_capacity::<MeshRendererComponent>(8);
     info.registry = Some(Registry::new(Vec::new()).unwrap());
     info
 }
@@ -22,7 +23,11 @@ fn should_add() {
     let mesh: MeshRendererComponentHandle = g.add_component(&scene).unwrap().into();
 
     let index = mesh.scene_id().index;
-    let ptr = &scene.mesh_renderer_components[index];
+    let ptr = scene
+        .component_pool::<MeshRendererComponent>()
+        .unwrap()
+        .get(index)
+        .unwrap();
     let mesh_: MeshRendererComponentHandle = ptr.borrow().handle.into();
 
     assert!(ptr.borrow().is_alive);
@@ -9,7 +9,6 @@ use ris_math::quaternion::Quat;
 use ris_math::vector::Vec3;
 use ris_rng::rng::Rng;
 use ris_rng::rng::Seed;
-use ris_util::assert_feq;
 use ris_util::assert_quat_eq;
 use ris_util::assert_vec3_eq;
 use ris_util::testing;
@@ -42,7 +41,7 @@ fn should_not_deref_destroyed_handle() {
 }
 
 #[test]
-fn should_not_create_when_out_of_memory() {
+fn should_grow_when_capacity_is_exhausted() {
     let scene = Scene::new(scene_create_info()).unwrap();
     let g0 = GameObjectHandle::new(&scene);
     let g1 = GameObjectHandle::new(&scene);
@@ -56,7 +55,10 @@ fn should_not_create_when_out_of_memory() {
     assert!(g2.is_ok());
     assert!(g3.is_ok());
     assert!(g4.is_ok());
-    assert!(g5.is_err());
+    assert!(g5.is_ok());
+    assert!(scene.dynamic_game_objects.len() > 5);
+    assert!(g0.unwrap().is_alive(&scene));
+    assert!(g5.unwrap().is_alive(&scene));
 }
 
 #[test]
@@ -106,8 +108,8 @@ fn should_get_and_set_local_scale() {
     let scene = Scene::new(scene_create_info()).unwrap();
     let g = GameObjectHandle::new(&scene).unwrap();
 
-    let expected1 = 1.0;
-    let expected2 = 9.0;
+    let expected1 = Vec3(1.0, 1.0, 1.0);
+    let expected2 = Vec3(9.0, 2.0, 0.5);
     let actual1 = g.local_scale(&scene).unwrap();
     g.set_local_scale(&scene, expected2).unwrap();
     let actual2 = g.local_scale(&scene).unwrap();
@@ -125,8 +127,9 @@ fn should_not_set_local_scale_to_0_or_negative() {
     let scene = Scene::new(scene_create_info()).unwrap();
     let g = GameObjectHandle::new(&scene).unwrap();
 
-    assert!(g.set_local_scale(&scene, 0.0).is_err());
-    assert!(g.set_local_scale(&scene, -20.0).is_err());
+    assert!(g.set_local_scale(&scene, Vec3(0.0, 1.0, 1.0)).is_err());
+    assert!(g.set_local_scale(&scene, Vec3(1.0, -20.0, 1.0)).is_err());
+    assert!(g.set_local_scale(&scene, Vec3(1.0, 1.0, -0.1)).is_err());
 }
 
 #[test]
@@ -163,10 +166,10 @@ fn should_keep_position_on_set_parent() {
 
     let position1 = Vec3(0.1, 0.2, 0.3);
     let rotation1 = Quat(0.4, 0.5, 0.6, 0.7).normalize();
-    let scale1 = 0.8;
+    let scale1 = Vec3(0.8, 0.7, 0.6);
     let position2 = Vec3(0.9, 1.0, 1.1);
     let rotation2 = Quat(1.2, 1.3, 1.4, 1.5).normalize();
-    let scale2 = 1.6;
+    let scale2 = Vec3(1.6, 1.7, 1.8);
 
     child1.set_local_position(&scene, position1).unwrap();
     child1.set_local_rotation(&scene, rotation1).unwrap();
@@ -180,10 +183,10 @@ fn should_keep_position_on_set_parent() {
 
     assert_vec3_eq!(position1, child1.local_position(&scene).unwrap());
     assert_quat_eq!(rotation1, child1.local_rotation(&scene).unwrap());
-    assert_feq!(scale1, child1.local_scale(&scene).unwrap());
+    assert_vec3_eq!(scale1, child1.local_scale(&scene).unwrap());
     assert_vec3_eq!(position2, child2.world_position(&scene).unwrap());
     assert_quat_eq!(rotation2, child2.world_rotation(&scene).unwrap());
-    assert_feq!(scale2, child2.world_scale(&scene).unwrap());
+    assert_vec3_eq!(scale2, child2.world_scale(&scene).unwrap());
 }
 
 #[test]
@@ -490,7 +493,7 @@ fn should_get_and_set_world_transform() {
 
         let p = rng.next_pos_3();
         let r = rng.next_rot();
-        let s = rng.next_f32_between(0.000_001, 1.0);
+        let s = Vec3::init(rng.next_f32_between(0.000_001, 1.0));
         g4.set_world_position(&scene, p).unwrap();
         g4.set_world_rotation(&scene, r).unwrap();
         g4.set_world_scale(&scene, s).unwrap();
@@ -500,15 +503,99 @@ fn should_get_and_set_world_transform() {
 
         assert_vec3_eq!(p, p_, 0.000_003);
         assert_quat_eq!(r, r_);
-        assert_feq!(s, s_, 0.000_003);
+        assert_vec3_eq!(s, s_, 0.000_003);
     });
 }
 
 fn set_random_transform(rng: &mut Rng, g: GameObjectHandle, scene: &Scene) {
     let p = rng.next_pos_3();
     let r = rng.next_rot();
-    let s = rng.next_f32_between(0.000_001, 1.0);
+    let s = Vec3::init(rng.next_f32_between(0.000_001, 1.0));
     g.set_local_position(scene, p).unwrap();
     g.set_local_rotation(scene, r).unwrap();
     g.set_local_scale(scene, s).unwrap();
 }
+
+#[test]
+fn should_compose_non_uniform_scale() {
+    let scene = Scene::new(scene_create_info()).unwrap();
+    let parent = GameObjectHandle::new(&scene).unwrap();
+    let child = GameObjectHandle::new(&scene).unwrap();
+    child.set_parent(&scene, Some(parent), 0, false).unwrap();
+
+    parent
+        .set_local_rotation(
+            &scene,
+            Quat::angle_axis(0.5 * std::f32::consts::PI, Vec3::up()),
+        )
+        .unwrap();
+    parent.set_local_scale(&scene, Vec3(2.0, 3.0, 4.0)).unwrap();
+    child
+        .set_local_position(&scene, Vec3(1.0, 1.0, 1.0))
+        .unwrap();
+    child.set_local_scale(&scene, Vec3(0.5, 1.0, 2.0)).unwrap();
+
+    assert_vec3_eq!(Vec3(1.0, 3.0, 8.0), child.world_scale(&scene).unwrap());
+    assert_vec3_eq!(Vec3(-3.0, 2.0, 4.0), child.world_position(&scene).unwrap());
+
+    child
+        .set_world_position(&scene, Vec3(3.0, -4.0, 8.0))
+        .unwrap();
+    child.set_world_scale(&scene, Vec3(4.0, 6.0, 4.0)).unwrap();
+
+    assert_vec3_eq!(Vec3(-2.0, -1.0, 2.0), child.local_position(&scene).unwrap());
+    assert_vec3_eq!(Vec3(2.0, 2.0, 1.0), child.local_scale(&scene).unwrap());
+}
+
+#[test]
+fn should_update_cached_world_transform_when_ancestor_changes() {
+    let scene = Scene::new(scene_create_info()).unwrap();
+    let root = GameObjectHandle::new(&scene).unwrap();
+    let parent = GameObjectHandle::new(&scene).unwrap();
+    let child = GameObjectHandle::new(&scene).unwrap();
+    parent.set_parent(&scene, Some(root), 0, false).unwrap();
+    child.set_parent(&scene, Some(parent), 0, false).unwrap();
+    child
+        .set_local_position(&scene, Vec3(1.0, 0.0, 0.0))
+        .unwrap();
+
+    assert_vec3_eq!(Vec3(1.0, 0.0, 0.0), child.world_position(&scene).unwrap());
+
+    root.set_local_position(&scene, Vec3(0.0, 2.0, 0.0))
+        .unwrap();
+    assert_vec3_eq!(Vec3(1.0, 2.0, 0.0), child.world_position(&scene).unwrap());
+
+    parent.set_local_scale(&scene, Vec3(3.0, 1.0, 1.0)).unwrap();
+    assert_vec3_eq!(Vec3(3.0, 2.0, 0.0), child.world_position(&scene).unwrap());
+
+    let rotation = Quat::angle_axis(90.0f32.to_radians(), Vec3::up());
+    root.set_local_rotation(&scene, rotation).unwrap();
+    let expected = Vec3(0.0, 2.0, 0.0) + rotation.rotate(Vec3(3.0, 0.0, 0.0));
+    assert_vec3_eq!(expected, child.world_position(&scene).unwrap());
+}
+
+#[test]
+fn should_update_cached_world_transform_when_parent_changes() {
+    let scene = Scene::new(scene_create_info()).unwrap();
+    let parent1 = GameObjectHandle::new(&scene).unwrap();
+    let parent2 = GameObjectHandle::new(&scene).unwrap();
+    let child = GameObjectHandle::new(&scene).unwrap();
+    parent1
+        .set_local_position(&scene, Vec3(1.0, 0.0, 0.0))
+        .unwrap();
+    parent2
+        .set_local_position(&scene, Vec3(0.0, 0.0, 5.0))
+        .unwrap();
+
+    child.set_parent(&scene, Some(parent1), 0, false).unwrap();
+    assert_vec3_eq!(Vec3(1.0, 0.0, 0.0), child.world_position(&scene).unwrap());
+
+    child.set_parent(&scene, Some(parent2), 0, false).unwrap();
+    assert_vec3_eq!(Vec3(0.0, 0.0, 5.0), child.world_position(&scene).unwrap());
+
+    child.set_parent(&scene, None, 0, false).unwrap();
+    assert_vec3_eq!(Vec3::default(), child.world_position(&scene).unwrap());
+
+    child.set_parent(&scene, Some(parent1), 0, false).unwrap();
+    assert_vec3_eq!(Vec3(1.0, 0.0, 0.0), child.world_position(&scene).unwrap());
+}
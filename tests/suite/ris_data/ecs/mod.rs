@@ -1,4 +1,8 @@
+pub mod command_buffer;
 pub mod component;
 pub mod game_object;
 pub mod handle;
+pub mod pool;
+pub mod query;
+pub mod registry;
 pub mod script;
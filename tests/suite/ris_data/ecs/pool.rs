@@ -0,0 +1,95 @@
+use ris_data::ecs::game_object::GameObject;
+use ris_data::ecs::id::SceneKind;
+use ris_data::ecs::pool::Pool;
+use ris_data::ecs::pool::MIN_GROWTH;
+
+#[test]
+fn should_allocate_lowest_index_first() {
+    let pool = Pool::<GameObject>::new(SceneKind::DynamicGameObject, 4).unwrap();
+
+    for i in 0..4 {
+        let ptr = pool.allocate().unwrap();
+        assert_eq!(ptr.borrow().handle.scene_id().index, i);
+    }
+
+    assert_eq!(pool.free_count(), 0);
+}
+
+#[test]
+fn should_grow_when_exhausted() {
+    let pool = Pool::<GameObject>::new(SceneKind::DynamicGameObject, 2).unwrap();
+    let first = pool.allocate().unwrap();
+    pool.allocate().unwrap();
+
+    let ptr = pool.allocate().unwrap();
+
+    assert_eq!(ptr.borrow().handle.scene_id().index, 2);
+    assert_eq!(pool.len(), 2 + MIN_GROWTH);
+    assert!(first.borrow().is_alive);
+}
+
+#[test]
+fn should_grow_empty_pool() {
+    let pool = Pool::<GameObject>::new(SceneKind::DynamicGameObject, 0).unwrap();
+    assert!(pool.is_empty());
+
+    let ptr = pool.allocate().unwrap();
+
+    assert_eq!(ptr.borrow().handle.scene_id().index, 0);
+    assert_eq!(pool.len(), MIN_GROWTH);
+}
+
+#[test]
+fn should_reuse_freed_slot_with_new_generation() {
+    let pool = Pool::<GameObject>::new(SceneKind::DynamicGameObject, 4).unwrap();
+    pool.allocate().unwrap();
+    let ptr = pool.allocate().unwrap();
+    pool.allocate().unwrap();
+    let old_handle = ptr.borrow().handle;
+
+    pool.free(old_handle.scene_id().index).unwrap();
+    assert!(!ptr.borrow().is_alive);
+
+    let reused = pool.allocate().unwrap();
+    let new_handle = reused.borrow().handle;
+
+    assert_eq!(new_handle.scene_id(), old_handle.scene_id());
+    assert_eq!(new_handle.generation(), old_handle.generation() + 1);
+}
+
+#[test]
+fn should_not_free_slot_twice() {
+    let pool = Pool::<GameObject>::new(SceneKind::DynamicGameObject, 4).unwrap();
+    let ptr = pool.allocate().unwrap();
+    let index = ptr.borrow().handle.scene_id().index;
+
+    pool.free(index).unwrap();
+    pool.free(index).unwrap();
+    assert_eq!(pool.free_count(), 4);
+
+    let a = pool.allocate().unwrap();
+    let b = pool.allocate().unwrap();
+    assert_ne!(
+        a.borrow().handle.scene_id().index,
+        b.borrow().handle.scene_id().index,
+    );
+}
+
+#[test]
+fn should_not_free_out_of_bounds() {
+    let pool = Pool::<GameObject>::new(SceneKind::DynamicGameObject, 4).unwrap();
+    assert!(pool.free(4).is_err());
+    assert!(pool.get(4).is_none());
+}
+
+#[test]
+fn should_iterate_all_slots() {
+    let pool = Pool::<GameObject>::new(SceneKind::DynamicGameObject, 4).unwrap();
+    pool.allocate().unwrap();
+    pool.allocate().unwrap();
+
+    let alive = pool.iter().filter(|x| x.borrow().is_alive).count();
+
+    assert_eq!(pool.iter().count(), 4);
+    assert_eq!(alive, 2);
+}
@@ -0,0 +1,159 @@
+use ris_data::ecs::components::mesh_renderer::MeshRendererComponent;
+use ris_data::ecs::decl::declare;
+use ris_data::ecs::decl::GameObjectHandle;
+use ris_data::ecs::decl::MeshRendererComponentHandle;
+use ris_data::ecs::handle::ComponentHandle;
+use ris_data::ecs::id::Component;
+use ris_data::ecs::id::GameObjectKind;
+use ris_data::ecs::registry::Registry;
+use ris_data::ecs::scene::Scene;
+use ris_data::ecs::scene::SceneCreateInfo;
+use ris_data::ecs::scene_stream::SceneReader;
+use ris_data::ecs::scene_stream::SceneWriter;
+use ris_error::RisResult;
+
+#[derive(Debug, Default)]
+pub struct TestLight {
+    game_object: GameObjectHandle,
+}
+
+declare::component!(TestLightHandle, TestLight);
+
+impl Component for TestLight {
+    fn destroy(&mut self, _scene: &Scene) {}
+
+    fn game_object(&self) -> GameObjectHandle {
+        self.game_object
+    }
+
+    fn game_object_mut(&mut self) -> &mut GameObjectHandle {
+        &mut self.game_object
+    }
+
+    fn serialize(&mut self, _stream: &mut SceneWriter) -> RisResult<()> {
+        Ok(())
+    }
+
+    fn deserialize(&mut self, _stream: &mut SceneReader, _version: u32) -> RisResult<()> {
+        Ok(())
+    }
+}
+
+fn scene() -> Scene {
+    let mut registry = Registry::new(Vec::new()).unwrap();
+    registry
+        .register_component(Registry::component::<TestLight>().unwrap())
+        .unwrap();
+
+    let mut info = SceneCreateInfo::empty();
+    info.dynamic_game_objects = 8;
+    info.components = 8;
+    info.registry = Some(registry);
+    Scene::new(info).unwrap()
+}
+
+#[test]
+fn should_query_alive_components() {
+    let scene = scene();
+    let g0 = GameObjectHandle::new(&scene).unwrap();
+    let g1 = GameObjectHandle::new(&scene).unwrap();
+    let m0: MeshRendererComponentHandle = g0.add_component(&scene).unwrap().into();
+    let m1: MeshRendererComponentHandle = g1.add_component(&scene).unwrap().into();
+    let m2: MeshRendererComponentHandle = g1.add_component(&scene).unwrap().into();
+    m1.destroy(&scene);
+
+    let actual = scene
+        .query::<MeshRendererComponent>()
+        .unwrap()
+        .map(|(g, m)| (g, MeshRendererComponentHandle::from(m)))
+        .collect::<Vec<_>>();
+
+    assert_eq!(actual, vec![(g0, m0), (g1, m2)]);
+}
+
+#[test]
+fn should_query_only_active_components() {
+    let scene = scene();
+    let parent = GameObjectHandle::new(&scene).unwrap();
+    let child = GameObjectHandle::new(&scene).unwrap();
+    let other = GameObjectHandle::new(&scene).unwrap();
+    child.set_parent(&scene, Some(parent), 0, false).unwrap();
+
+    let _: MeshRendererComponentHandle = child.add_component(&scene).unwrap().into();
+    let m: MeshRendererComponentHandle = other.add_component(&scene).unwrap().into();
+
+    parent.set_active(&scene, false).unwrap();
+
+    let all = scene.query::<MeshRendererComponent>().unwrap().count();
+    let active = scene
+        .query::<MeshRendererComponent>()
+        .unwrap()
+        .active_only()
+        .map(|(_, x)| MeshRendererComponentHandle::from(x))
+        .collect::<Vec<_>>();
+
+    assert_eq!(all, 2);
+    assert_eq!(active, vec![m]);
+}
+
+#[test]
+fn should_query_multiple_component_types() {
+    let scene = scene();
+    let g0 = GameObjectHandle::new(&scene).unwrap();
+    let g1 = GameObjectHandle::new(&scene).unwrap();
+    let g2 = GameObjectHandle::new(&scene).unwrap();
+
+    let m0: MeshRendererComponentHandle = g0.add_component(&scene).unwrap().into();
+    let l0: TestLightHandle = g0.add_component(&scene).unwrap().into();
+    let _: MeshRendererComponentHandle = g1.add_component(&scene).unwrap().into();
+    let _: TestLightHandle = g2.add_component(&scene).unwrap().into();
+
+    let actual = scene
+        .query::<(MeshRendererComponent, TestLight)>()
+        .unwrap()
+        .map(|(g, (m, l))| {
+            (
+                g,
+                MeshRendererComponentHandle::from(m),
+                TestLightHandle::from(l),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(actual, vec![(g0, m0, l0)]);
+}
+
+#[test]
+fn should_not_query_unregistered_component() {
+    let mut info = SceneCreateInfo::empty();
+    info.registry = Some(Registry::new(Vec::new()).unwrap());
+    let scene = Scene::new(info).unwrap();
+
+    assert!(scene.query::<TestLight>().is_err());
+}
+
+#[test]
+fn should_query_game_objects() {
+    let scene = scene();
+    let g0 = GameObjectHandle::new(&scene).unwrap();
+    let g1 = GameObjectHandle::new(&scene).unwrap();
+    let g2 = GameObjectHandle::new(&scene).unwrap();
+    let g3 = GameObjectHandle::new(&scene).unwrap();
+    g1.set_parent(&scene, Some(g0), 0, false).unwrap();
+    g3.destroy(&scene);
+
+    let all = scene
+        .game_objects(GameObjectKind::Dynamic)
+        .collect::<Vec<_>>();
+    let roots = scene
+        .game_objects(GameObjectKind::Dynamic)
+        .roots_only()
+        .collect::<Vec<_>>();
+    let statics = scene
+        .game_objects(GameObjectKind::Static { chunk: 0 })
+        .count();
+
+    assert_eq!(all, vec![g0, g1, g2]);
+    assert_eq!(roots, vec![g0, g2]);
+    assert_eq!(statics, 0);
+}
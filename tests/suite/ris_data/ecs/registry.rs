@@ -0,0 +1,133 @@
+use ris_data::ecs::decl::declare;
+use ris_data::ecs::decl::GameObjectHandle;
+use ris_data::ecs::game_object::GetFrom;
+use ris_data::ecs::handle::ComponentHandle;
+use ris_data::ecs::id::Component;
+use ris_data::ecs::registry::Registry;
+use ris_data::ecs::scene::Scene;
+use ris_data::ecs::scene::SceneCreateInfo;
+use ris_data::ecs::scene_stream::SceneReader;
+use ris_data::ecs::scene_stream::SceneWriter;
+use ris_error::RisResult;
+
+#[derive(Debug, Default)]
+pub struct TestCollider {
+    game_object: GameObjectHandle,
+    radius: f32,
+}
+
+declare::component!(TestColliderHandle, TestCollider);
+
+impl Component for TestCollider {
+    fn destroy(&mut self, _scene: &Scene) {}
+
+    fn game_object(&self) -> GameObjectHandle {
+        self.game_object
+    }
+
+    fn game_object_mut(&mut self) -> &mut GameObjectHandle {
+        &mut self.game_object
+    }
+
+    fn serialize(&mut self, stream: &mut SceneWriter) -> RisResult<()> {
+        ris_io::write_f32(stream, self.radius)?;
+        Ok(())
+    }
+
+    fn deserialize(&mut self, stream: &mut SceneReader, _version: u32) -> RisResult<()> {
+        self.radius = ris_io::read_f32(stream)?;
+        Ok(())
+    }
+}
+
+fn scene_create_info() -> SceneCreateInfo {
+    let mut registry = Registry::new(Vec::new()).unwrap();
+    registry
+        .register_component(Registry::component::<TestCollider>().unwrap())
+        .unwrap();
+
+    let mut info = SceneCreateInfo::empty();
+    info.dynamic_game_objects = 4;
+    info.set_component_capacity::<TestCollider>(4);
+    info.registry = Some(registry);
+    info
+}
+
+#[test]
+fn should_add_registered_component() {
+    let scene = Scene::new(scene_create_info()).unwrap();
+    let g = GameObjectHandle::new(&scene).unwrap();
+
+    let collider: TestColliderHandle = g.add_component(&scene).unwrap().into();
+    let ptr = scene.deref(collider.0).unwrap();
+    ptr.borrow_mut().radius = 42.0;
+
+    let pool = scene.component_pool::<TestCollider>().unwrap();
+    assert_eq!(pool.len(), 4);
+    assert_eq!(
+        pool.get(collider.scene_id().index).unwrap().borrow().radius,
+        42.0
+    );
+
+    let found: TestColliderHandle = g
+        .get_component(&scene, GetFrom::This)
+        .unwrap()
+        .unwrap()
+        .into();
+    assert_eq!(found, collider);
+    assert_eq!(collider.game_object(&scene).unwrap(), g);
+}
+
+#[test]
+fn should_destroy_registered_component() {
+    let scene = Scene::new(scene_create_info()).unwrap();
+    let g = GameObjectHandle::new(&scene).unwrap();
+    let collider: TestColliderHandle = g.add_component(&scene).unwrap().into();
+
+    collider.destroy(&scene);
+
+    assert!(!collider.is_alive(&scene));
+    assert!(g.components(&scene).unwrap().is_empty());
+}
+
+#[test]
+fn should_make_registered_component_via_factory() {
+    let scene = Scene::new(scene_create_info()).unwrap();
+    let g = GameObjectHandle::new(&scene).unwrap();
+
+    let factory = scene
+        .registry
+        .component_factories()
+        .iter()
+        .find(|x| x.component_name() == "TestCollider")
+        .unwrap();
+    let component = factory.make(&scene, g).unwrap();
+
+    let game_object = scene
+        .deref_component(component, |x| x.game_object())
+        .unwrap();
+    assert_eq!(game_object, g);
+}
+
+#[test]
+fn should_not_add_unregistered_component() {
+    let mut info = SceneCreateInfo::empty();
+    info.dynamic_game_objects = 1;
+    info.registry = Some(Registry::new(Vec::new()).unwrap());
+    let scene = Scene::new(info).unwrap();
+    let g = GameObjectHandle::new(&scene).unwrap();
+
+    let result = g.add_component::<TestCollider>(&scene);
+    assert!(result.is_err());
+    assert!(scene.component_pool::<TestCollider>().is_err());
+}
+
+#[test]
+fn should_not_register_component_twice() {
+    let mut registry = Registry::new(Vec::new()).unwrap();
+    registry
+        .register_component(Registry::component::<TestCollider>().unwrap())
+        .unwrap();
+    let result = registry.register_component(Registry::component::<TestCollider>().unwrap());
+    assert!(result.is_err());
+}
@@ -1,3 +1,4 @@
+use ris_data::ecs::components::script::DynScriptComponent;
 use ris_data::ecs::decl::GameObjectHandle;
 use ris_data::ecs::game_object::GetFrom;
 use ris_data::ecs::registry::Registry;
@@ -8,11 +9,12 @@ use ris_data::ecs::script_prelude::*;
 fn scene_create_info() -> SceneCreateInfo {
     let mut info = SceneCreateInfo::empty();
     info.dynamic_game_objects = 5;
-    info.script_components = 5;
+    info.set_component_capacity::<DynScriptComponent>(5);
     info.registry = Some(
         Registry::new(vec![
             Registry::script::<TestScriptString>().unwrap(),
             Registry::script::<TestScriptISize>().unwrap(),
+            Registry::script::<TestScriptEnable>().unwrap(),
         ])
         .unwrap(),
     );
@@ -29,6 +31,12 @@ struct TestScriptISize {
     value: isize,
 }
 
+#[derive(Debug, Default)]
+struct TestScriptEnable {
+    enabled: usize,
+    disabled: usize,
+}
+
 impl Script for TestScriptString {
     fn start(&mut self, _data: ScriptStartEndData) -> RisResult<()> {
         Ok(())
@@ -48,7 +56,7 @@ impl Script for TestScriptString {
         ris_error::new_result!("not implemented")
     }
 
-    fn deserialize(&mut self, _stream: &mut SceneReader) -> RisResult<()> {
+    fn deserialize(&mut self, _stream: &mut SceneReader, _version: u32) -> RisResult<()> {
         ris_error::new_result!("not implemented")
     }
 
@@ -76,13 +84,49 @@ impl Script for TestScriptISize {
         ris_error::new_result!("not implemented")
     }
 
-    fn deserialize(&mut self, _stream: &mut SceneReader) -> RisResult<()> {
+    fn deserialize(&mut self, _stream: &mut SceneReader, _version: u32) -> RisResult<()> {
+        ris_error::new_result!("not implemented")
+    }
+
+    fn inspect(&mut self, _data: ScriptInspectData) -> RisResult<()> {
+        ris_error::new_result!("not implementd")
+    }
+}
+
+impl Script for TestScriptEnable {
+    fn start(&mut self, _data: ScriptStartEndData) -> RisResult<()> {
+        Ok(())
+    }
+
+    fn update(&mut self, _data: ScriptUpdateData) -> RisResult<()> {
+        Ok(())
+    }
+
+    fn end(&mut self, _data: ScriptStartEndData) -> RisResult<()> {
+        Ok(())
+    }
+
+    fn serialize(&mut self, _stream: &mut SceneWriter) -> RisResult<()> {
+        ris_error::new_result!("not implemented")
+    }
+
+    fn deserialize(&mut self, _stream: &mut SceneReader, _version: u32) -> RisResult<()> {
         ris_error::new_result!("not implemented")
     }
 
     fn inspect(&mut self, _data: ScriptInspectData) -> RisResult<()> {
         ris_error::new_result!("not implementd")
     }
+
+    fn on_enable(&mut self, _data: ScriptStartEndData) -> RisResult<()> {
+        self.enabled += 1;
+        Ok(())
+    }
+
+    fn on_disable(&mut self, _data: ScriptStartEndData) -> RisResult<()> {
+        self.disabled += 1;
+        Ok(())
+    }
 }
 
 #[test]
@@ -199,3 +243,90 @@ fn should_get_first_script() {
     assert_eq!(script1.script(&scene).unwrap().value, 1);
     assert!(script2.is_none());
 }
+
+#[test]
+fn should_call_on_enable_and_on_disable_when_active_changes() {
+    let scene = Scene::new(scene_create_info()).unwrap();
+    let g = GameObjectHandle::new(&scene).unwrap();
+    let script = g.add_script::<TestScriptEnable>(&scene).unwrap();
+
+    g.set_active(&scene, false).unwrap();
+    g.set_active(&scene, false).unwrap();
+    assert_eq!(script.script(&scene).unwrap().disabled, 0);
+    scene.notify_scripts();
+    assert_eq!(script.script(&scene).unwrap().enabled, 0);
+    assert_eq!(script.script(&scene).unwrap().disabled, 1);
+
+    g.set_active(&scene, true).unwrap();
+    g.set_active(&scene, true).unwrap();
+    scene.notify_scripts();
+    assert_eq!(script.script(&scene).unwrap().enabled, 1);
+    assert_eq!(script.script(&scene).unwrap().disabled, 1);
+}
+
+#[test]
+fn should_call_on_enable_and_on_disable_on_active_children() {
+    let scene = Scene::new(scene_create_info()).unwrap();
+    let parent = GameObjectHandle::new(&scene).unwrap();
+    let active_child = GameObjectHandle::new(&scene).unwrap();
+    let inactive_child = GameObjectHandle::new(&scene).unwrap();
+    active_child
+        .set_parent(&scene, Some(parent), 0, false)
+        .unwrap();
+    inactive_child
+        .set_parent(&scene, Some(parent), 1, false)
+        .unwrap();
+    inactive_child.set_active(&scene, false).unwrap();
+
+    let active_script = active_child.add_script::<TestScriptEnable>(&scene).unwrap();
+    let inactive_script = inactive_child
+        .add_script::<TestScriptEnable>(&scene)
+        .unwrap();
+
+    parent.set_active(&scene, false).unwrap();
+    scene.notify_scripts();
+    assert_eq!(active_script.script(&scene).unwrap().disabled, 1);
+    assert_eq!(inactive_script.script(&scene).unwrap().disabled, 0);
+
+    // the parent is inactive, thus the child stays inactive in the hierarchy
+    inactive_child.set_active(&scene, true).unwrap();
+    scene.notify_scripts();
+    assert_eq!(inactive_script.script(&scene).unwrap().enabled, 0);
+
+    parent.set_active(&scene, true).unwrap();
+    scene.notify_scripts();
+    assert_eq!(active_script.script(&scene).unwrap().enabled, 1);
+    assert_eq!(inactive_script.script(&scene).unwrap().enabled, 1);
+}
+
+#[test]
+fn should_not_borrow_script_that_deactivates_its_own_game_object() {
+    let scene = Scene::new(scene_create_info()).unwrap();
+    let g = GameObjectHandle::new(&scene).unwrap();
+    let script = g.add_script::<TestScriptEnable>(&scene).unwrap();
+
+    // the script is borrowed while it runs, like during an update
+    let script_mut = script.script_mut(&scene).unwrap();
+    g.set_active(&scene, false).unwrap();
+    drop(script_mut);
+
+    scene.notify_scripts();
+    assert_eq!(script.script(&scene).unwrap().disabled, 1);
+
+    // changes are delivered only once
+    scene.notify_scripts();
+    assert_eq!(script.script(&scene).unwrap().disabled, 1);
+}
+
+#[test]
+fn should_not_notify_destroyed_scripts() {
+    let scene = Scene::new(scene_create_info()).unwrap();
+    let g = GameObjectHandle::new(&scene).unwrap();
+    let script = g.add_script::<TestScriptEnable>(&scene).unwrap();
+
+    g.set_active(&scene, false).unwrap();
+    g.destroy(&scene);
+    scene.notify_scripts();
+
+    assert!(script.script(&scene).is_err());
+}
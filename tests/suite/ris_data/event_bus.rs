@@ -0,0 +1,166 @@
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use ris_data::ecs::decl::GameObjectHandle;
+use ris_data::ecs::registry::Registry;
+use ris_data::ecs::scene::SceneCreateInfo;
+use ris_data::event_bus::EventBus;
+use ris_data::god_state::GodState;
+use ris_data::settings::Settings;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Damage(f32);
+
+#[derive(Debug, Clone, PartialEq)]
+struct Click;
+
+fn create_state() -> GodState {
+    let mut info = SceneCreateInfo::empty();
+    info.dynamic_game_objects = 4;
+    info.registry = Some(Registry::new(Vec::new()).unwrap());
+    GodState::new(Settings::default(), info).unwrap()
+}
+
+fn record<T: Clone + Send + 'static>() -> Arc<Mutex<Vec<T>>> {
+    Arc::new(Mutex::new(Vec::new()))
+}
+
+#[test]
+fn should_deliver_events_by_type_when_dispatched() {
+    let state = create_state();
+    let received = record::<f32>();
+
+    let received_copy = received.clone();
+    state.events.subscribe::<Damage>(move |data| {
+        received_copy.lock().unwrap().push(data.event.0);
+        Ok(())
+    });
+
+    state.events.publish(Damage(1.0));
+    state.events.publish(Click);
+    state.events.publish(Damage(2.0));
+    assert!(received.lock().unwrap().is_empty());
+    assert_eq!(state.events.pending_count(), 3);
+
+    state.events.dispatch(&state).unwrap();
+
+    assert_eq!(*received.lock().unwrap(), vec![1.0, 2.0]);
+    assert_eq!(state.events.pending_count(), 0);
+}
+
+#[test]
+fn should_deliver_events_by_target() {
+    let state = create_state();
+    let g0 = GameObjectHandle::new(&state.scene).unwrap();
+    let g1 = GameObjectHandle::new(&state.scene).unwrap();
+    let received_0 = record::<f32>();
+    let received_1 = record::<f32>();
+    let received_all = record::<Option<GameObjectHandle>>();
+
+    let received_copy = received_0.clone();
+    state.events.subscribe_to::<Damage>(g0, move |data| {
+        received_copy.lock().unwrap().push(data.event.0);
+        Ok(())
+    });
+    let received_copy = received_1.clone();
+    state.events.subscribe_to::<Damage>(g1, move |data| {
+        received_copy.lock().unwrap().push(data.event.0);
+        Ok(())
+    });
+    let received_copy = received_all.clone();
+    state.events.subscribe::<Damage>(move |data| {
+        received_copy.lock().unwrap().push(data.target);
+        Ok(())
+    });
+
+    state.events.publish_to(g0, Damage(1.0));
+    state.events.publish_to(g1, Damage(2.0));
+    state.events.publish(Damage(3.0));
+    state.events.dispatch(&state).unwrap();
+
+    assert_eq!(*received_0.lock().unwrap(), vec![1.0]);
+    assert_eq!(*received_1.lock().unwrap(), vec![2.0]);
+    assert_eq!(
+        *received_all.lock().unwrap(),
+        vec![Some(g0), Some(g1), None]
+    );
+}
+
+#[test]
+fn should_not_deliver_after_unsubscribe() {
+    let state = create_state();
+    let received = record::<f32>();
+
+    let received_copy = received.clone();
+    let id = state.events.subscribe::<Damage>(move |data| {
+        received_copy.lock().unwrap().push(data.event.0);
+        Ok(())
+    });
+
+    state.events.publish(Damage(1.0));
+    state.events.unsubscribe(id);
+    state.events.dispatch(&state).unwrap();
+
+    assert!(received.lock().unwrap().is_empty());
+    assert_eq!(state.events.subscriber_count(), 0);
+}
+
+#[test]
+fn should_remove_subscriptions_of_destroyed_targets() {
+    let state = create_state();
+    let g = GameObjectHandle::new(&state.scene).unwrap();
+    state.events.subscribe_to::<Damage>(g, |_| Ok(()));
+
+    g.destroy(&state.scene);
+    state.events.publish(Damage(1.0));
+    state.events.dispatch(&state).unwrap();
+
+    assert_eq!(state.events.subscriber_count(), 0);
+}
+
+#[test]
+fn should_deliver_events_published_while_dispatching_on_next_dispatch() {
+    let state = create_state();
+    let received = record::<f32>();
+
+    state.events.subscribe::<Click>(|data| {
+        data.state.events.publish(Damage(1.0));
+        Ok(())
+    });
+    let received_copy = received.clone();
+    state.events.subscribe::<Damage>(move |data| {
+        received_copy.lock().unwrap().push(data.event.0);
+        Ok(())
+    });
+
+    state.events.publish(Click);
+    state.events.dispatch(&state).unwrap();
+    assert!(received.lock().unwrap().is_empty());
+
+    state.events.dispatch(&state).unwrap();
+    assert_eq!(*received.lock().unwrap(), vec![1.0]);
+}
+
+#[test]
+fn should_return_error_of_subscriber() {
+    let state = create_state();
+    state
+        .events
+        .subscribe::<Click>(|_| ris_error::new_result!("failed"));
+
+    state.events.publish(Click);
+    let result = state.events.dispatch(&state);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn should_share_events_between_clones_of_state() {
+    let state = create_state();
+    let clone = state.clone();
+    let bus: &EventBus = &clone.events;
+
+    bus.publish(Click);
+
+    assert_eq!(state.events.pending_count(), 1);
+}
@@ -0,0 +1,39 @@
+use std::time::Duration;
+
+use ris_data::gameloop::fixed_timestep::FixedTimestep;
+
+#[test]
+fn should_not_step_when_less_than_timestep_has_passed() {
+    let mut fixed_timestep = FixedTimestep::new(Duration::from_millis(20), 5);
+
+    assert_eq!(fixed_timestep.advance(Duration::from_millis(5)), 0);
+    assert_eq!(fixed_timestep.advance(Duration::from_millis(14)), 0);
+}
+
+#[test]
+fn should_carry_remaining_time_into_next_frame() {
+    let mut fixed_timestep = FixedTimestep::new(Duration::from_millis(20), 5);
+
+    assert_eq!(fixed_timestep.advance(Duration::from_millis(15)), 0);
+    assert_eq!(fixed_timestep.advance(Duration::from_millis(15)), 1);
+    assert_eq!(fixed_timestep.advance(Duration::from_millis(15)), 1);
+    assert_eq!(fixed_timestep.advance(Duration::from_millis(15)), 1);
+    assert_eq!(fixed_timestep.advance(Duration::from_millis(15)), 0);
+}
+
+#[test]
+fn should_take_multiple_steps_in_a_long_frame() {
+    let mut fixed_timestep = FixedTimestep::new(Duration::from_millis(20), 5);
+
+    assert_eq!(fixed_timestep.advance(Duration::from_millis(65)), 3);
+    assert!((fixed_timestep.alpha() - 0.25).abs() < 0.0001);
+}
+
+#[test]
+fn should_discard_time_exceeding_max_steps() {
+    let mut fixed_timestep = FixedTimestep::new(Duration::from_millis(20), 5);
+
+    assert_eq!(fixed_timestep.advance(Duration::from_millis(1000)), 5);
+    assert_eq!(fixed_timestep.advance(Duration::from_millis(10)), 0);
+    assert_eq!(fixed_timestep.alpha(), 0.5);
+}
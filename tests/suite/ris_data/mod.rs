@@ -2,7 +2,11 @@ pub mod ecs;
 
 pub mod buttons;
 pub mod cell;
+pub mod event_bus;
+pub mod fixed_timestep;
 pub mod keys;
 pub mod ptr;
 pub mod ris_map;
 pub mod ris_yaml;
+pub mod rumble_queue;
+pub mod settings;
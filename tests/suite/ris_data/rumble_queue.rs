@@ -0,0 +1,63 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use ris_data::ecs::registry::Registry;
+use ris_data::ecs::scene::SceneCreateInfo;
+use ris_data::god_state::GodState;
+use ris_data::input::player_data::Rumble;
+use ris_data::settings::Settings;
+
+fn create_state() -> GodState {
+    let mut info = SceneCreateInfo::empty();
+    info.registry = Some(Registry::new(Vec::new()).unwrap());
+    GodState::new(Settings::default(), info).unwrap()
+}
+
+fn rumble(low_frequency: f32, duration_ms: u64) -> Rumble {
+    Rumble {
+        low_frequency,
+        high_frequency: 0.0,
+        duration: Duration::from_millis(duration_ms),
+    }
+}
+
+#[test]
+fn should_pass_requests_from_scripts_to_the_poll() {
+    let state = create_state();
+
+    // scripts receive a copy of the state, like in `god_job`
+    let script_state = Arc::new(state.clone());
+    script_state
+        .rumble
+        .request(1, 0.5, 0.0, Duration::from_millis(100));
+    drop(script_state);
+
+    // the poll takes the requests of all players
+    let rumbles = state.rumble.take(2);
+    assert_eq!(rumbles, vec![None, Some(rumble(0.5, 100))]);
+    assert_eq!(state.rumble.take(2), vec![None, None]);
+}
+
+#[test]
+fn should_keep_the_latest_request_of_each_player() {
+    let state = create_state();
+    state
+        .rumble
+        .request(0, 0.25, 0.0, Duration::from_millis(100));
+    state
+        .rumble
+        .request(0, 1.0, 0.0, Duration::from_millis(200));
+
+    assert_eq!(state.rumble.take(1), vec![Some(rumble(1.0, 200))]);
+}
+
+#[test]
+fn should_discard_requests_of_players_that_do_not_exist() {
+    let state = create_state();
+    state
+        .rumble
+        .request(3, 1.0, 0.0, Duration::from_millis(100));
+
+    assert_eq!(state.rumble.take(2), vec![None, None]);
+    assert_eq!(state.rumble.take(4), vec![None, None, None, None]);
+}
@@ -0,0 +1,150 @@
+use sdl2::keyboard::Scancode;
+use sdl2::version::Version;
+
+use ris_data::info::app_info::AppInfo;
+use ris_data::info::args_info::ArgsInfo;
+use ris_data::info::build_info::BuildInfo;
+use ris_data::info::cpu_info::CpuInfo;
+use ris_data::info::file_info::FileInfo;
+use ris_data::info::package_info::PackageInfo;
+use ris_data::info::sdl_info::SdlInfo;
+use ris_data::input::action;
+use ris_data::input::keyboard_data;
+use ris_data::settings::serializer;
+use ris_data::settings::serializer::SettingsSerializer;
+use ris_data::settings::Settings;
+use ris_io::fallback_file::FallbackFileOverwrite;
+
+fn app_info(test_dir: &std::path::Path) -> AppInfo {
+    let cpu = CpuInfo {
+        cpu_count: 4,
+        ..Default::default()
+    };
+
+    let file = FileInfo {
+        pref_path: test_dir.to_str().unwrap().to_string(),
+        ..Default::default()
+    };
+
+    // not `SdlInfo::default()`, because that requires sdl to be initialized
+    let sdl = SdlInfo {
+        version: Version {
+            major: 0,
+            minor: 0,
+            patch: 0,
+        },
+        revision: String::new(),
+    };
+
+    AppInfo::new(
+        ArgsInfo::default(),
+        BuildInfo::default(),
+        cpu,
+        file,
+        PackageInfo::default(),
+        sdl,
+    )
+}
+
+#[test]
+fn should_format_and_parse_actions() {
+    let actions = action::OK | action::MOVE_LEFT | action::A31;
+
+    let formatted = action::format(actions);
+    assert_eq!(formatted, "ok | move_left | a31");
+    assert_eq!(action::parse(&formatted), Some(actions));
+
+    assert_eq!(action::format(action::NONE), "none");
+    assert_eq!(action::parse("none"), Some(action::NONE));
+    assert_eq!(action::parse("ok | jump"), None);
+}
+
+#[test]
+fn should_find_scancodes_by_name() {
+    for scancode in [
+        Scancode::A,
+        Scancode::Return,
+        Scancode::Kp8,
+        Scancode::NonUsHash,
+    ] {
+        let name = keyboard_data::scancode_name(scancode);
+        assert_eq!(keyboard_data::scancode_from_name(&name), Some(scancode));
+    }
+
+    assert_eq!(keyboard_data::scancode_from_name("not a key"), None);
+}
+
+#[test]
+fn should_serialize_and_deserialize_input_bindings() {
+    let test_dir = ris_util::prep_test_dir!();
+    let app_info = app_info(&test_dir);
+
+    let mut settings = Settings::new(&app_info);
+    let input = settings.input_mut();
+
+    let mut keymask = *input.get_keymask();
+    keymask[0] = Some(Scancode::Space);
+    keymask[15] = None;
+    input.set_keymask(keymask);
+
+    let mut rebind_matrix = input.get_gamepad_rebind_matrix().clone();
+    rebind_matrix.data[0] = action::OK | action::A1;
+    rebind_matrix.data[1] = action::NONE;
+    input.set_gamepad_rebind_matrix(rebind_matrix);
+
+    let serializer = SettingsSerializer::new(&app_info);
+    serializer.serialize(&settings).unwrap();
+    let deserialized = serializer.deserialize(&app_info).unwrap();
+
+    let expected = settings.input();
+    let actual = deserialized.input();
+    assert_eq!(actual.get_keymask(), expected.get_keymask());
+    assert_eq!(actual.get_keymask()[0], Some(Scancode::Space));
+    assert_eq!(actual.get_keymask()[15], None);
+    assert_eq!(
+        actual.get_gamepad_rebind_matrix().data,
+        expected.get_gamepad_rebind_matrix().data,
+    );
+    assert_eq!(
+        actual.get_mouse_rebind_matrix().data,
+        expected.get_mouse_rebind_matrix().data,
+    );
+    assert_eq!(
+        actual.get_keyboard_rebind_matrix().data,
+        expected.get_keyboard_rebind_matrix().data,
+    );
+}
+
+#[test]
+fn should_key_rebind_rows_by_button_names() {
+    let test_dir = ris_util::prep_test_dir!();
+    let app_info = app_info(&test_dir);
+
+    let mut settings = Settings::new(&app_info);
+    let input = settings.input_mut();
+
+    let mut mouse_rebind_matrix = input.get_mouse_rebind_matrix().clone();
+    mouse_rebind_matrix.data[2] = action::A2;
+    input.set_mouse_rebind_matrix(mouse_rebind_matrix);
+
+    let mut gamepad_rebind_matrix = input.get_gamepad_rebind_matrix().clone();
+    gamepad_rebind_matrix.data[23] = action::OK | action::A1;
+    input.set_gamepad_rebind_matrix(gamepad_rebind_matrix);
+
+    SettingsSerializer::new(&app_info)
+        .serialize(&settings)
+        .unwrap();
+
+    let directory = test_dir.join(serializer::DIRECTORY_NAME);
+    let fallback_file = FallbackFileOverwrite::new(&directory, serializer::EXTENSION, 10);
+    let bytes = fallback_file.get_by_index(0).unwrap();
+    let yaml = String::from_utf8(bytes).unwrap();
+
+    assert!(yaml.contains("input.mouse_rebind.right: a2"), "{}", yaml);
+    assert!(
+        yaml.contains("input.gamepad_rebind.left_trigger: ok | a1"),
+        "{}",
+        yaml
+    );
+    assert!(!yaml.contains("input.gamepad_rebind.move_up"), "{}", yaml);
+}
@@ -33,3 +33,13 @@ fn should_create_file_sids() {
     assert!(sid1 != sid3);
     assert!(sid2 != sid3);
 }
+
+#[test]
+fn should_create_sids_at_runtime() {
+    let value = String::from("hoi");
+    let sid1 = ris_debug::sid::Sid::new(value);
+    let sid2 = ris_debug::sid!("hoi");
+
+    assert_eq!(sid1.hash, sid2.hash);
+    assert!(sid1 == sid2);
+}
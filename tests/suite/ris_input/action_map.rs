@@ -0,0 +1,218 @@
+use sdl2::controller::Axis;
+use sdl2::keyboard::Scancode;
+
+use ris_data::input::action;
+use ris_data::input::Input;
+use ris_input::action_map;
+use ris_input::action_map::ActionMap;
+use ris_input::action_map::AnalogAction;
+use ris_input::action_map::AnalogBinding;
+use ris_input::action_map::AxisSource;
+use ris_input::action_map::DigitalAction;
+use ris_input::action_map::DigitalBinding;
+use ris_input::action_map::ResponseCurve;
+use ris_util::assert_feq;
+
+// gamepad axes are quantized to i16
+const AXIS_TOLERANCE: f32 = 0.0001;
+
+fn wasd() -> AnalogBinding {
+    AnalogBinding::Vec2 {
+        x: AxisSource::keys(Scancode::A, Scancode::D),
+        y: AxisSource::keys(Scancode::S, Scancode::W),
+    }
+}
+
+fn left_stick() -> AnalogBinding {
+    AnalogBinding::Vec2 {
+        x: AxisSource::GamepadAxis(Axis::LeftX),
+        y: AxisSource::GamepadAxis(Axis::LeftY).invert(),
+    }
+}
+
+#[test]
+fn should_map_keys_to_vec2() {
+    let mut action_map = ActionMap::default();
+    action_map
+        .add_analog(AnalogAction::new("move", vec![wasd()]))
+        .unwrap();
+
+    let mut input = Input::default();
+    input.keyboard.keys.set(Scancode::W);
+    input.keyboard.keys.set(Scancode::A);
+    action_map.update(&mut input);
+
+    let value = input.actions.vec2("move");
+    assert_feq!(value.0, -1.0 / f32::sqrt(2.0));
+    assert_feq!(value.1, 1.0 / f32::sqrt(2.0));
+
+    input.keyboard.keys.clear();
+    input.keyboard.keys.set(Scancode::A);
+    input.keyboard.keys.set(Scancode::D);
+    action_map.update(&mut input);
+
+    let value = input.actions.vec2("move");
+    assert_feq!(value.0, 0.0);
+    assert_feq!(value.1, 0.0);
+}
+
+#[test]
+fn should_map_gamepad_axes_proportionally() {
+    let mut action_map = ActionMap::default();
+    let mut move_action = AnalogAction::new("move", vec![wasd(), left_stick()]);
+    move_action.dead_zone = 0.2;
+    action_map.add_analog(move_action).unwrap();
+
+    let mut input = Input::default();
+
+    // inside the dead zone
+    input.gamepad.axis[0] = i16::MAX / 10;
+    action_map.update(&mut input);
+    assert_feq!(input.actions.vec2("move").0, 0.0);
+
+    // halfway between the dead zone and the maximum
+    input.gamepad.axis[0] = (0.6 * i16::MAX as f32) as i16;
+    action_map.update(&mut input);
+    assert_feq!(input.actions.vec2("move").0, 0.5, AXIS_TOLERANCE);
+
+    // y of the stick points down
+    input.gamepad.axis[0] = 0;
+    input.gamepad.axis[1] = i16::MAX;
+    action_map.update(&mut input);
+    assert_feq!(input.actions.vec2("move").1, -1.0);
+
+    // the binding with the largest magnitude wins
+    input.gamepad.axis[1] = (0.6 * i16::MAX as f32) as i16;
+    input.keyboard.keys.set(Scancode::W);
+    action_map.update(&mut input);
+    assert_feq!(input.actions.vec2("move").1, 1.0);
+}
+
+#[test]
+fn should_apply_response_curve() {
+    let mut action_map = ActionMap::default();
+    let mut throttle = AnalogAction::new(
+        "throttle",
+        vec![AnalogBinding::Axis(AxisSource::GamepadAxis(
+            Axis::TriggerRight,
+        ))],
+    );
+    throttle.dead_zone = 0.0;
+    throttle.curve = ResponseCurve::Power(2.0);
+    action_map.add_analog(throttle).unwrap();
+
+    let mut input = Input::default();
+    input.gamepad.axis[5] = i16::MAX / 2;
+    action_map.update(&mut input);
+
+    assert_feq!(input.actions.axis("throttle"), 0.25, AXIS_TOLERANCE);
+}
+
+#[test]
+fn should_scale_mouse_movement_without_clamping() {
+    let mut action_map = ActionMap::default();
+    let mut look = AnalogAction::new(
+        "look",
+        vec![AnalogBinding::Vec2 {
+            x: AxisSource::MouseX,
+            y: AxisSource::MouseY.invert(),
+        }],
+    );
+    look.sensitivity = 0.5;
+    action_map.add_analog(look).unwrap();
+
+    let mut input = Input::default();
+    input.mouse.xrel = 10;
+    input.mouse.yrel = 4;
+    action_map.update(&mut input);
+
+    let value = input.actions.vec2("look");
+    assert_feq!(value.0, 5.0);
+    assert_feq!(value.1, -2.0);
+}
+
+#[test]
+fn should_map_digital_actions() {
+    let mut action_map = ActionMap::default();
+    action_map
+        .add_digital(DigitalAction {
+            name: "jump".to_string(),
+            bindings: vec![
+                DigitalBinding::source(AxisSource::Key(Scancode::Space)),
+                DigitalBinding::source(AxisSource::GamepadAxis(Axis::TriggerLeft)),
+                DigitalBinding::General(action::A1),
+            ],
+        })
+        .unwrap();
+
+    let mut input = Input::default();
+    action_map.update(&mut input);
+    assert!(!input.actions.is_hold("jump"));
+
+    input.gamepad.axis[4] = i16::MAX;
+    action_map.update(&mut input);
+    assert!(input.actions.is_down("jump"));
+    assert!(input.actions.is_hold("jump"));
+
+    action_map.update(&mut input);
+    assert!(!input.actions.is_down("jump"));
+    assert!(input.actions.is_hold("jump"));
+
+    input.gamepad.axis[4] = 0;
+    input.general.buttons.update(action::A1);
+    action_map.update(&mut input);
+    assert!(input.actions.is_hold("jump"));
+
+    input.general.buttons.update(0);
+    action_map.update(&mut input);
+    assert!(input.actions.is_up("jump"));
+    assert!(!input.actions.is_hold("unknown"));
+}
+
+#[test]
+fn should_not_add_actions_with_the_same_name() {
+    let mut action_map = ActionMap::new_default();
+    let result = action_map.add_analog(AnalogAction::new(action_map::MOVE, vec![wasd()]));
+    assert!(result.is_err());
+
+    action_map.remove(action_map::MOVE);
+    let result = action_map.add_analog(AnalogAction::new(action_map::MOVE, vec![wasd()]));
+    assert!(result.is_ok());
+}
+
+#[test]
+fn should_map_general_buttons_by_default() {
+    let action_map = ActionMap::new_default();
+    let mut input = Input::default();
+
+    // general buttons respect the rebind matrices, thus rebound keys and buttons move as well
+    input
+        .general
+        .buttons
+        .update(action::MOVE_LEFT | action::CAMERA_UP);
+    action_map.update(&mut input);
+
+    let movement = input.actions.vec2(action_map::MOVE);
+    assert_feq!(movement.0, -1.0);
+    assert_feq!(movement.1, 0.0);
+
+    let look = input.actions.vec2(action_map::LOOK);
+    assert_feq!(look.0, 0.0);
+    assert_feq!(look.1, 1.0);
+
+    // the sticks are not quantized by the general buttons, but move proportionally
+    input.general.buttons.update(action::NONE);
+    input.gamepad.axis[0] = i16::MAX / 2;
+    input.gamepad.axis[2] = -i16::MAX / 2;
+    action_map.update(&mut input);
+
+    let dead_zone = action_map::DEFAULT_DEAD_ZONE;
+    let expected = (0.5 - dead_zone) / (1.0 - dead_zone);
+    let movement = input.actions.vec2(action_map::MOVE);
+    assert_feq!(movement.0, expected, AXIS_TOLERANCE);
+    assert_feq!(movement.1, 0.0);
+
+    let look = input.actions.vec2(action_map::LOOK);
+    assert_feq!(look.0, -expected, AXIS_TOLERANCE);
+    assert_feq!(look.1, 0.0);
+}
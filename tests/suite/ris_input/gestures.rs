@@ -0,0 +1,185 @@
+use std::time::Duration;
+
+use ris_data::gameloop::frame::Frame;
+use ris_data::input::action;
+use ris_data::input::general_data::GeneralData;
+use ris_input::gesture_logic::update_gestures;
+
+const FRAME_DURATION: Duration = Duration::from_millis(10);
+
+struct TestContext {
+    general: GeneralData,
+    frame_number: usize,
+}
+
+impl TestContext {
+    fn new() -> Self {
+        Self {
+            general: GeneralData::default(),
+            frame_number: 0,
+        }
+    }
+
+    fn step(&mut self, buttons: u32) {
+        self.frame_number += 1;
+        let frame = Frame::new(self.frame_number, FRAME_DURATION, FRAME_DURATION);
+
+        self.general.buttons.update(buttons);
+        update_gestures(&mut self.general, frame);
+    }
+
+    fn steps(&mut self, buttons: u32, count: usize) {
+        for _ in 0..count {
+            self.step(buttons);
+        }
+    }
+
+    fn tap(&mut self, buttons: u32) {
+        self.step(buttons);
+        self.step(0);
+    }
+}
+
+fn millis(value: u64) -> Duration {
+    Duration::from_millis(value)
+}
+
+#[test]
+fn should_measure_hold_duration() {
+    let mut context = TestContext::new();
+    context.step(action::OK);
+    assert_eq!(
+        context.general.gestures.hold_duration(action::OK),
+        Duration::ZERO
+    );
+
+    context.steps(action::OK, 5);
+    assert_eq!(
+        context.general.gestures.hold_duration(action::OK),
+        millis(50)
+    );
+    assert!(context.general.gestures.is_held_for(action::OK, millis(50)));
+    assert!(!context.general.gestures.is_held_for(action::OK, millis(60)));
+    assert_eq!(
+        context.general.gestures.hold_duration(action::A1),
+        Duration::ZERO
+    );
+
+    context.step(0);
+    assert_eq!(
+        context.general.gestures.hold_duration(action::OK),
+        Duration::ZERO
+    );
+    assert!(!context
+        .general
+        .gestures
+        .is_held_for(action::OK, Duration::ZERO));
+}
+
+#[test]
+fn should_buffer_presses_until_consumed() {
+    let mut context = TestContext::new();
+    let window = millis(100);
+
+    context.tap(action::A1);
+    context.steps(0, 3);
+    assert!(context.general.gestures.is_buffered(action::A1, window));
+    assert!(!context.general.gestures.is_buffered(action::A2, window));
+
+    assert!(context.general.gestures.consume(action::A1, window));
+    assert!(!context.general.gestures.is_buffered(action::A1, window));
+    assert!(!context.general.gestures.consume(action::A1, window));
+}
+
+#[test]
+fn should_not_buffer_presses_outside_of_window() {
+    let mut context = TestContext::new();
+    let window = millis(100);
+
+    context.tap(action::A1);
+    context.steps(0, 10);
+
+    assert!(!context.general.gestures.is_buffered(action::A1, window));
+    assert!(!context.general.gestures.consume(action::A1, window));
+}
+
+#[test]
+fn should_detect_double_tap() {
+    let mut context = TestContext::new();
+    let window = millis(100);
+
+    context.tap(action::A2);
+    context.steps(0, 3);
+    assert!(!context.general.gestures.is_double_tap(action::A2, window));
+
+    context.step(action::A2);
+    assert!(context.general.gestures.is_double_tap(action::A2, window));
+
+    // only in the frame of the second press
+    context.step(action::A2);
+    assert!(!context.general.gestures.is_double_tap(action::A2, window));
+}
+
+#[test]
+fn should_not_detect_slow_double_tap() {
+    let mut context = TestContext::new();
+    let window = millis(100);
+
+    context.tap(action::A2);
+    context.steps(0, 10);
+    context.step(action::A2);
+
+    assert!(!context.general.gestures.is_double_tap(action::A2, window));
+    assert!(!context.general.gestures.is_double_tap(action::A3, window));
+}
+
+#[test]
+fn should_detect_combo() {
+    let mut context = TestContext::new();
+    let window = millis(200);
+    let combo = [action::MOVE_DOWN, action::MOVE_RIGHT, action::A1];
+
+    context.tap(action::MOVE_DOWN);
+    context.tap(action::MOVE_RIGHT);
+    assert!(!context.general.gestures.is_combo(&combo, window));
+
+    context.step(action::A1);
+    assert!(context.general.gestures.is_combo(&combo, window));
+
+    context.step(0);
+    assert!(!context.general.gestures.is_combo(&combo, window));
+}
+
+#[test]
+fn should_not_detect_combo_in_wrong_order_or_too_slow() {
+    let mut context = TestContext::new();
+    let window = millis(200);
+    let combo = [action::MOVE_DOWN, action::MOVE_RIGHT, action::A1];
+
+    context.tap(action::MOVE_RIGHT);
+    context.tap(action::MOVE_DOWN);
+    context.step(action::A1);
+    assert!(!context.general.gestures.is_combo(&combo, window));
+    context.step(0);
+
+    context.tap(action::MOVE_DOWN);
+    context.steps(0, 20);
+    context.tap(action::MOVE_RIGHT);
+    context.step(action::A1);
+    assert!(!context.general.gestures.is_combo(&combo, window));
+}
+
+#[test]
+fn should_forget_presses_older_than_history() {
+    let mut context = TestContext::new();
+    let history = ris_data::input::gestures::DEFAULT_HISTORY;
+
+    context.tap(action::A3);
+    let frames = (history.as_millis() / FRAME_DURATION.as_millis()) as usize + 1;
+    context.steps(0, frames);
+
+    assert!(!context
+        .general
+        .gestures
+        .is_buffered(action::A3, history * 2));
+}
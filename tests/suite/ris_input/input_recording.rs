@@ -0,0 +1,153 @@
+use std::time::Duration;
+
+use sdl2::keyboard::Mod;
+use sdl2::keyboard::Scancode;
+
+use ris_data::gameloop::frame::Frame;
+use ris_data::input::player_data::ControllerInfo;
+use ris_data::input::player_data::ControllerType;
+use ris_data::input::player_data::PlayerData;
+use ris_data::input::Input;
+use ris_input::input_recording::InputRecorder;
+use ris_input::input_recording::InputReplay;
+use ris_input::input_recording::RecordedFrame;
+use ris_input::input_recording::MAGIC;
+
+fn generate_input(seed: i32) -> Input {
+    let mut input = Input::default();
+
+    input.mouse.buttons.update(0b101 ^ seed as u32);
+    input.mouse.x = 10 + seed;
+    input.mouse.y = -20 - seed;
+    input.mouse.xrel = seed;
+    input.mouse.yrel = -seed;
+    input.mouse.wheel_yrel = 1;
+
+    input.keyboard.keys.set(Scancode::W);
+    input.keyboard.keys.set(Scancode::LShift);
+    input.keyboard.buttons.update(0b11 << seed);
+    input.keyboard.mod_state = Mod::LSHIFTMOD;
+    input.keyboard.text_input = vec![format!("text {}", seed)];
+
+    input.gamepad.buttons.update(seed as u32);
+    input.gamepad.axis = [i16::MIN, i16::MAX, 0, 1, -1, seed as i16];
+
+    let mut joined = PlayerData {
+        controller: Some(ControllerInfo {
+            instance_id: 42 + seed as u32,
+            name: format!("controller {}", seed),
+            controller_type: ControllerType::PlayStation,
+        }),
+        joined: seed == 0,
+        ..Default::default()
+    };
+    joined.gamepad.buttons.update(0b110 << seed);
+    joined.gamepad.axis = [seed as i16, 0, -1, 1, i16::MAX, i16::MIN];
+
+    let mut removed = PlayerData {
+        left: true,
+        ..Default::default()
+    };
+    removed.gamepad.buttons.update(1);
+
+    input.players = vec![joined, removed];
+
+    input
+}
+
+#[test]
+fn should_replay_recorded_frames() {
+    let test_dir = ris_util::prep_test_dir!();
+    let path = test_dir.join("input.ris_input");
+
+    let frames = [
+        Frame::new(1, Duration::from_millis(16), Duration::from_millis(16)),
+        Frame::new(2, Duration::from_millis(20), Duration::from_millis(18)),
+        Frame::new(3, Duration::from_nanos(1), Duration::from_nanos(12_345)),
+    ];
+
+    let mut recorded = Vec::new();
+    let mut recorder = InputRecorder::new(&path).unwrap();
+    for (i, frame) in frames.iter().enumerate() {
+        let input = generate_input(i as i32);
+        recorder.record(*frame, &input).unwrap();
+        recorded.push(RecordedFrame::capture(*frame, &input));
+    }
+    drop(recorder);
+
+    let mut replay = InputReplay::new(&path).unwrap();
+    for (expected_frame, expected) in frames.iter().zip(recorded.iter()) {
+        let (frame, actual) = replay.next_frame().unwrap().unwrap();
+        assert_eq!(frame.number(), expected_frame.number());
+        assert_eq!(
+            frame.previous_duration(),
+            expected_frame.previous_duration()
+        );
+        assert_eq!(frame.average_duration(), expected_frame.average_duration());
+        assert_eq!(actual, *expected);
+    }
+
+    assert!(replay.next_frame().unwrap().is_none());
+}
+
+#[test]
+fn should_reproduce_input_when_applied() {
+    let frame = Frame::new(1, Duration::from_millis(16), Duration::from_millis(16));
+    let input = generate_input(3);
+    let recorded = RecordedFrame::capture(frame, &input);
+
+    let mut replayed = Input::default();
+    recorded.apply(&mut replayed);
+
+    assert_eq!(RecordedFrame::capture(frame, &replayed), recorded);
+    assert_eq!(replayed.keyboard.keys.hold(), input.keyboard.keys.hold());
+    assert_eq!(replayed.mouse.buttons.down(), input.mouse.buttons.down());
+    assert_eq!(
+        replayed.gamepad.buttons.hold(),
+        input.gamepad.buttons.hold()
+    );
+
+    assert_eq!(replayed.players.len(), 2);
+    for (actual, expected) in replayed.players.iter().zip(input.players.iter()) {
+        assert_eq!(actual.controller, expected.controller);
+        assert_eq!(actual.joined, expected.joined);
+        assert_eq!(actual.left, expected.left);
+        assert_eq!(
+            actual.gamepad.buttons.hold(),
+            expected.gamepad.buttons.hold()
+        );
+        assert_eq!(actual.gamepad.axis, expected.gamepad.axis);
+    }
+}
+
+#[test]
+fn should_replay_version_0_without_players() {
+    let test_dir = ris_util::prep_test_dir!();
+    let path = test_dir.join("input.ris_input");
+
+    let frame = Frame::new(1, Duration::from_millis(16), Duration::from_millis(16));
+    let mut input = generate_input(1);
+    input.players.clear();
+    let expected = RecordedFrame::capture(frame, &input);
+
+    // version 0 frames end before the player count
+    let mut bytes = MAGIC.to_vec();
+    bytes[15] = 0;
+    let frame_bytes = expected.serialize().unwrap();
+    bytes.extend_from_slice(&frame_bytes[..frame_bytes.len() - 4]);
+    std::fs::write(&path, bytes).unwrap();
+
+    let mut replay = InputReplay::new(&path).unwrap();
+    let (_, actual) = replay.next_frame().unwrap().unwrap();
+    assert_eq!(actual, expected);
+    assert!(replay.next_frame().unwrap().is_none());
+}
+
+#[test]
+fn should_fail_on_unknown_magic() {
+    let test_dir = ris_util::prep_test_dir!();
+    let path = test_dir.join("input.ris_input");
+    std::fs::write(&path, [0u8; 32]).unwrap();
+
+    assert!(InputReplay::new(&path).is_err());
+}
@@ -1 +1,5 @@
+pub mod action_map;
 pub mod general;
+pub mod gestures;
+pub mod input_recording;
+pub mod player_slots;
@@ -0,0 +1,68 @@
+use ris_data::input::player_data::ControllerType;
+use ris_input::player_slots::PlayerSlots;
+
+#[test]
+fn should_join_controllers_as_separate_players() {
+    let mut slots = PlayerSlots::new(4);
+
+    assert_eq!(slots.join(10), Some(0));
+    assert_eq!(slots.join(11), Some(1));
+    assert_eq!(slots.join(10), Some(0));
+
+    assert_eq!(slots.len(), 2);
+    assert_eq!(slots.player_of(11), Some(1));
+    assert_eq!(slots.controller_of(0), Some(10));
+    assert_eq!(slots.controller_of(2), None);
+}
+
+#[test]
+fn should_keep_the_player_when_its_controller_leaves() {
+    let mut slots = PlayerSlots::new(4);
+    slots.join(10);
+    slots.join(11);
+    slots.join(12);
+
+    assert_eq!(slots.leave(11), Some(1));
+    assert_eq!(slots.leave(11), None);
+    assert_eq!(slots.len(), 3);
+    assert_eq!(slots.controller_of(1), None);
+    assert_eq!(slots.player_of(12), Some(2));
+
+    // a reconnected controller gets a new instance id
+    assert_eq!(slots.join(13), Some(1));
+    assert_eq!(slots.join(14), Some(3));
+}
+
+#[test]
+fn should_not_join_more_than_max_players() {
+    let mut slots = PlayerSlots::new(2);
+
+    assert_eq!(slots.join(10), Some(0));
+    assert_eq!(slots.join(11), Some(1));
+    assert_eq!(slots.join(12), None);
+
+    slots.leave(10);
+    assert_eq!(slots.join(12), Some(0));
+}
+
+#[test]
+fn should_guess_controller_type_by_name() {
+    let cases = [
+        ("Xbox One S Controller", ControllerType::Xbox),
+        ("XInput Controller", ControllerType::Xbox),
+        ("PS4 Controller", ControllerType::PlayStation),
+        (
+            "Sony DualSense Wireless Controller",
+            ControllerType::PlayStation,
+        ),
+        (
+            "Nintendo Switch Pro Controller",
+            ControllerType::NintendoSwitch,
+        ),
+        ("Generic USB Joystick", ControllerType::Unknown),
+    ];
+
+    for (name, expected) in cases {
+        assert_eq!(ControllerType::from_name(name), expected, "{}", name);
+    }
+}
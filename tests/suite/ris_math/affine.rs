@@ -4,7 +4,6 @@ use std::rc::Rc;
 use ris_math::affine;
 use ris_rng::rng::Rng;
 use ris_rng::rng::Seed;
-use ris_util::assert_feq;
 use ris_util::assert_quat_eq;
 use ris_util::assert_vec3_eq;
 use ris_util::testing;
@@ -75,14 +74,18 @@ fn should_convert_trs() {
 
         let t = rng.next_pos_3();
         let r = rng.next_rot();
-        let s = rng.next_f32_between(0.000_001, 1.0);
+        let s = ris_math::vector::Vec3(
+            rng.next_f32_between(0.000_001, 1.0),
+            rng.next_f32_between(0.000_001, 1.0),
+            rng.next_f32_between(0.000_001, 1.0),
+        );
 
         let m = affine::trs_compose(t, r, s);
         let (t_, r_, s_) = affine::trs_decompose(m);
 
         assert_vec3_eq!(t, t_);
         assert_quat_eq!(r, r_);
-        assert_feq!(s, s_);
+        assert_vec3_eq!(s, s_);
     });
 }
 
@@ -96,7 +99,7 @@ fn should_panic_when_scale_is_negative() {
 
     let t = ris_math::vector::Vec3::default();
     let r = ris_math::quaternion::Quat::default();
-    let s = -1.0;
+    let s = ris_math::vector::Vec3(1.0, -1.0, 1.0);
 
     let _ = affine::trs_compose(t, r, s);
 }
@@ -111,7 +114,7 @@ fn should_panic_when_scale_is_zero() {
 
     let t = ris_math::vector::Vec3::default();
     let r = ris_math::quaternion::Quat::default();
-    let s = 0.0;
+    let s = ris_math::vector::Vec3(1.0, 1.0, 0.0);
 
     let _ = affine::trs_compose(t, r, s);
 }